@@ -39,12 +39,21 @@ pub enum Action {
     GoToMainMenu,
     ToggleCommandPalette,
     ClearAllToasts,
+    Undo,
+    Redo,
+    SearchCards,
+    FilterCards,
+    SortCards,
+    ArchiveCard,
+    ArchiveBoard,
+    OpenInEditor,
+    QuickAddCard,
 }
 
 impl Action {
     /// All available actions
     pub fn iterator() -> Iter<'static, Action> {
-        static ACTIONS: [Action; 28] = [
+        static ACTIONS: [Action; 37] = [
             Action::Quit,
             Action::NextFocus,
             Action::PrvFocus,
@@ -73,6 +82,15 @@ impl Action {
             Action::GoToMainMenu,
             Action::ToggleCommandPalette,
             Action::ClearAllToasts,
+            Action::Undo,
+            Action::Redo,
+            Action::SearchCards,
+            Action::FilterCards,
+            Action::SortCards,
+            Action::ArchiveCard,
+            Action::ArchiveBoard,
+            Action::OpenInEditor,
+            Action::QuickAddCard,
         ];
         ACTIONS.iter()
     }
@@ -108,6 +126,15 @@ impl Action {
             Action::GoToMainMenu => &[Key::Char('m')],
             Action::ToggleCommandPalette => &[Key::Ctrl('p')],
             Action::ClearAllToasts => &[Key::Char('t')],
+            Action::Undo => &[Key::Ctrl('z')],
+            Action::Redo => &[Key::Ctrl('y')],
+            Action::SearchCards => &[Key::Char('/')],
+            Action::FilterCards => &[Key::Char('f')],
+            Action::SortCards => &[Key::Char('s')],
+            Action::ArchiveCard => &[Key::Char('a')],
+            Action::ArchiveBoard => &[Key::Char('A')],
+            Action::OpenInEditor => &[Key::Ctrl('e')],
+            Action::QuickAddCard => &[Key::Char('N')],
         }
     }
 
@@ -148,6 +175,15 @@ impl Display for Action {
             Action::GoToMainMenu => "Go to main menu",
             Action::ToggleCommandPalette => "Open command palette",
             Action::ClearAllToasts => "Clear all toasts",
+            Action::Undo => "Undo last change",
+            Action::Redo => "Redo last undone change",
+            Action::SearchCards => "Search cards",
+            Action::FilterCards => "Filter cards",
+            Action::SortCards => "Sort cards",
+            Action::ArchiveCard => "Archive focused element",
+            Action::ArchiveBoard => "Archive board",
+            Action::OpenInEditor => "Open card in external editor",
+            Action::QuickAddCard => "Quick add card",
         };
         write!(f, "{}", str)
     }
@@ -1,38 +1,43 @@
-use chrono::{NaiveDate, NaiveDateTime, Utc};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info, warn};
 use ratatui::{style::Color, widgets::ListState};
-use std::{str::FromStr, time::Duration};
+use std::{path::PathBuf, str::FromStr, time::Duration};
 
 use crate::{
     app::{state::KeyBindings, AppConfig},
     constants::{
-        DEFAULT_DATE_FORMAT, DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME,
-        MOUSE_OUT_OF_BOUNDS_COORDINATES, NEW_BOARD_FORM_DEFAULT_STATE, NEW_CARD_FORM_DEFAULT_STATE,
+        DEFAULT_TOAST_DURATION, FIELD_NOT_SET, IO_EVENT_WAIT_TIME, MOUSE_OUT_OF_BOUNDS_COORDINATES,
+        NEW_BOARD_FORM_DEFAULT_STATE, NEW_CARD_FORM_DEFAULT_STATE,
     },
     inputs::{key::Key, mouse::Mouse},
     io::{
-        data_handler::{get_config, save_theme, write_config},
+        data_handler::{
+            get_config, get_latest_kanban_export, get_workspaces, save_theme, write_config,
+        },
         handler::refresh_visible_boards_and_cards,
         IoEvent,
     },
     ui::{
         ui_helper::get_config_items,
-        widgets::{CommandPaletteWidget, ToastType, ToastWidget},
+        widgets::{import_kanban_export, CommandPaletteWidget, ToastType, ToastWidget},
         TextColorOptions, TextModifierOptions, Theme,
     },
 };
 
 use super::{
     actions::Action,
-    kanban::{Board, Card, CardPriority, CardStatus},
+    kanban::{
+        find_card, Board, Card, CardDate, CardFilter, CardLinkKind, CardPriority, CardRecurrence,
+        CardSortKey, CardStatus, ChecklistItem, SwimlaneGrouping, WorkflowStatus,
+    },
     state::{AppStatus, Focus, UiMode},
-    App, AppReturn, AppState, MainMenu, MainMenuItem, PopupMode,
+    App, AppReturn, AppState, ArchivedItem, ContextMenuItem, DestructiveAction, MainMenu,
+    MainMenuItem, PopupMode,
 };
 
 pub fn go_right(app: &mut App) {
     let current_visible_boards = app.visible_boards_and_cards.clone();
-    let all_boards = app.boards.clone();
+    let all_boards = app.filtered_boards();
     let current_board_id = app.state.current_board_id;
     // check if current_board_id is set, if not assign to the first board
     // check if all_boards is empty, if so, return
@@ -94,11 +99,8 @@ pub fn go_right(app: &mut App) {
         app.visible_boards_and_cards.remove(&first_board_id);
         app.state.current_board_id = Some(next_board.id);
         // reset the current card id to first card of current board from visible_boards if there is any
-        if next_board_card_ids.is_empty() {
-            app.state.current_card_id = None;
-        } else {
-            app.state.current_card_id = Some(next_board_card_ids[0]);
-        }
+        app.state.current_card_id =
+            first_card_in_current_lane(app, &all_boards, &next_board_card_ids);
     } else {
         // we are not at the last board, we can go right
         let next_board_id = *current_visible_boards
@@ -114,17 +116,14 @@ pub fn go_right(app: &mut App) {
             .unwrap()
             .1
             .clone();
-        if current_board_cards.is_empty() {
-            app.state.current_card_id = None;
-        } else {
-            app.state.current_card_id = Some(current_board_cards[0]);
-        }
+        app.state.current_card_id =
+            first_card_in_current_lane(app, &all_boards, &current_board_cards);
     }
 }
 
 pub fn go_left(app: &mut App) {
     let current_visible_boards = app.visible_boards_and_cards.clone();
-    let all_boards = app.boards.clone();
+    let all_boards = app.filtered_boards();
     let current_board_id = app.state.current_board_id;
     // check if current_board_id is set, if not assign to the first board
     // check if all_boards is empty, if so, return
@@ -185,11 +184,8 @@ pub fn go_left(app: &mut App) {
         app.visible_boards_and_cards = new_visible_boards_and_cards;
         app.state.current_board_id = Some(previous_board.id);
         // reset the current card id to first card of current board from visible_boards if there is any
-        if previous_board_card_ids.is_empty() {
-            app.state.current_card_id = None;
-        } else {
-            app.state.current_card_id = Some(previous_board_card_ids[0]);
-        }
+        app.state.current_card_id =
+            first_card_in_current_lane(app, &all_boards, &previous_board_card_ids);
     } else {
         // we are not at the first board, we can go left
         let previous_board_id = *current_visible_boards
@@ -205,15 +201,35 @@ pub fn go_left(app: &mut App) {
             .unwrap()
             .1
             .clone();
-        if current_visible_cards.is_empty() {
-            app.state.current_card_id = None;
-        } else {
-            app.state.current_card_id = Some(current_visible_cards[0]);
-        }
+        app.state.current_card_id =
+            first_card_in_current_lane(app, &all_boards, &current_visible_cards);
     }
 }
 
+/// The first of card_ids, with swimlanes the first one in the lane of the current card if there is one
+fn first_card_in_current_lane(app: &App, boards: &[Board], card_ids: &[u128]) -> Option<u128> {
+    let cards = boards
+        .iter()
+        .flat_map(|board| board.cards.iter())
+        .collect::<Vec<&Card>>();
+    let card_by_id = |card_id: u128| cards.iter().find(|card| card.id == card_id);
+    let current_lane = app
+        .state
+        .current_card_id
+        .and_then(card_by_id)
+        .and_then(|card| app.config.swimlanes.lane(card));
+    current_lane
+        .and_then(|current_lane| {
+            card_ids.iter().copied().find(|card_id| {
+                card_by_id(*card_id).and_then(|card| app.config.swimlanes.lane(card))
+                    == Some(current_lane.clone())
+            })
+        })
+        .or_else(|| card_ids.first().copied())
+}
+
 pub fn go_up(app: &mut App) {
+    let boards = app.filtered_boards();
     let current_visible_boards = app.visible_boards_and_cards.clone();
     let current_board_id = app.state.current_board_id;
     let current_card_id = app.state.current_card_id;
@@ -224,13 +240,13 @@ pub fn go_up(app: &mut App) {
     let current_board_id = if let Some(current_board_id) = current_board_id {
         current_board_id
     } else {
-        app.boards[0].id
+        boards[0].id
     };
     let current_card_id = if let Some(current_card_id) = current_card_id {
         current_card_id
     } else {
         // get the first card of the current board
-        let current_board = app.boards.iter().find(|board| board.id == current_board_id);
+        let current_board = boards.iter().find(|board| board.id == current_board_id);
         if current_board.is_none() {
             debug!("Cannot go up: current board not found");
             app.send_error_toast("Cannot go up: Something went wrong", None);
@@ -258,8 +274,7 @@ pub fn go_up(app: &mut App) {
     }
     let current_card_index = current_card_index.unwrap();
     if current_card_index == 0 {
-        let current_card_index_in_all_cards = app
-            .boards
+        let current_card_index_in_all_cards = boards
             .iter()
             .find(|board| board.id == current_board_id)
             .unwrap()
@@ -280,15 +295,13 @@ pub fn go_up(app: &mut App) {
         // we are not at the first card, we can go up
         // get the previous NO_OF_CARDS_PER_PAGE cards
         let previous_card_index = current_card_index_in_all_cards - 1;
-        let previous_card_id = app
-            .boards
+        let previous_card_id = boards
             .iter()
             .find(|board| board.id == current_board_id)
             .unwrap()
             .cards[previous_card_index]
             .id;
-        let previous_cards = app
-            .boards
+        let previous_cards = boards
             .iter()
             .find(|board| board.id == current_board_id)
             .unwrap()
@@ -327,6 +340,7 @@ pub fn go_up(app: &mut App) {
 }
 
 pub fn go_down(app: &mut App) {
+    let boards = app.filtered_boards();
     let current_visible_boards = app.visible_boards_and_cards.clone();
     let current_board_id = app.state.current_board_id;
     let current_card_id = app.state.current_card_id;
@@ -337,13 +351,13 @@ pub fn go_down(app: &mut App) {
     let current_board_id = if let Some(current_board_id) = current_board_id {
         current_board_id
     } else {
-        app.boards[0].id
+        boards[0].id
     };
     let current_card_id = if let Some(current_card_id) = current_card_id {
         current_card_id
     } else {
         // get the first card of the current board
-        let current_board = app.boards.iter().find(|board| board.id == current_board_id);
+        let current_board = boards.iter().find(|board| board.id == current_board_id);
         if current_board.is_none() {
             debug!("Cannot go down: current board not found, trying to get the first board");
             // check if app.visible_boards_and_cards is empty, if so, return else select the first board and first card
@@ -380,8 +394,7 @@ pub fn go_down(app: &mut App) {
     }
     let current_card_index = current_card_index.unwrap();
     if current_card_index == app.config.no_of_cards_to_show as usize - 1 {
-        let current_card_index_in_all_cards = app
-            .boards
+        let current_card_index_in_all_cards = boards
             .iter()
             .find(|board| board.id == current_board_id)
             .unwrap()
@@ -395,8 +408,7 @@ pub fn go_down(app: &mut App) {
         }
         let current_card_index_in_all_cards = current_card_index_in_all_cards.unwrap();
         if current_card_index_in_all_cards
-            == app
-                .boards
+            == boards
                 .iter()
                 .find(|board| board.id == current_board_id)
                 .unwrap()
@@ -411,8 +423,7 @@ pub fn go_down(app: &mut App) {
         // we are not at the last card, we can go down
         // get the next NO_OF_CARDS_PER_PAGE cards
         let next_card_index = current_card_index_in_all_cards + 1;
-        let next_card_id = app
-            .boards
+        let next_card_id = boards
             .iter()
             .find(|board| board.id == current_board_id)
             .unwrap()
@@ -421,15 +432,14 @@ pub fn go_down(app: &mut App) {
         let start_index = next_card_index - 1;
         let end_index = next_card_index - 1 + app.config.no_of_cards_to_show as usize;
         let end_index = if end_index
-            > app
-                .boards
+            > boards
                 .iter()
                 .find(|board| board.id == current_board_id)
                 .unwrap()
                 .cards
                 .len()
         {
-            app.boards
+            boards
                 .iter()
                 .find(|board| board.id == current_board_id)
                 .unwrap()
@@ -438,8 +448,7 @@ pub fn go_down(app: &mut App) {
         } else {
             end_index
         };
-        let next_card_ids = app
-            .boards
+        let next_card_ids = boards
             .iter()
             .find(|board| board.id == current_board_id)
             .unwrap()
@@ -455,7 +464,7 @@ pub fn go_down(app: &mut App) {
                 start_index -= 1;
                 next_card_ids.insert(
                     0,
-                    app.boards
+                    boards
                         .iter()
                         .find(|board| board.id == current_board_id)
                         .unwrap()
@@ -558,7 +567,15 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
         }
         if app.state.popup_mode.is_some() {
             match app.state.popup_mode.unwrap() {
-                PopupMode::CommandPalette => {
+                PopupMode::CommandPalette
+                | PopupMode::CardSearch
+                | PopupMode::FilterCards
+                | PopupMode::SaveFilterPrompt
+                | PopupMode::SetWipLimitPrompt
+                | PopupMode::SetWorkflowPrompt
+                | PopupMode::ImportFromJSONPrompt(_)
+                | PopupMode::SwitchWorkspace
+                | PopupMode::QuickAddCard => {
                     app.state.popup_mode = None;
                     app.state.app_status = AppStatus::Initialized;
                 }
@@ -585,7 +602,7 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                         app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
                     }
                 }
-                PopupMode::CardPrioritySelector => {
+                PopupMode::CardPrioritySelector | PopupMode::CardRecurrenceSelector => {
                     if app.card_being_edited.is_some() {
                         app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges)
                     } else {
@@ -599,11 +616,20 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                         app.state.popup_mode = None;
                     }
                 }
+                PopupMode::LinkCard => {
+                    app.state.text_input.reset();
+                    // go back to editing the card the link was being added to
+                    if app.card_being_edited.is_some() {
+                        app.state.popup_mode = Some(PopupMode::ViewCard);
+                        return AppReturn::Continue;
+                    }
+                    app.state.popup_mode = None;
+                }
                 _ => {}
             }
         }
         app.state.app_status = AppStatus::Initialized;
-        app.state.current_cursor_position = None;
+        app.state.text_input.reset();
         info!("Exiting user input mode");
     } else {
         // parsing keys to account for actions
@@ -613,6 +639,11 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
             app.state.popup_mode = None;
             return AppReturn::Continue;
         }
+        if app.config.keybindings.open_in_editor.contains(&key)
+            && app.state.popup_mode == Some(PopupMode::ViewCard)
+        {
+            return handle_open_in_editor(app);
+        }
         if app.state.popup_mode.is_some() {
             match app.state.popup_mode.unwrap() {
                 PopupMode::CommandPalette => match key {
@@ -631,28 +662,86 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                     }
                     _ => {}
                 },
+                PopupMode::CardSearch => match key {
+                    Key::Up => {
+                        app.card_search_up();
+                        return AppReturn::Continue;
+                    }
+                    Key::Down => {
+                        app.card_search_down();
+                        return AppReturn::Continue;
+                    }
+                    Key::Enter => return handle_card_search_selection(app),
+                    _ => {}
+                },
+                PopupMode::LinkCard => match key {
+                    Key::Up => {
+                        app.card_search_up();
+                        return AppReturn::Continue;
+                    }
+                    Key::Down => {
+                        app.card_search_down();
+                        return AppReturn::Continue;
+                    }
+                    Key::Ctrl('t') => {
+                        app.state.card_link_kind = app.state.card_link_kind.toggle();
+                        return AppReturn::Continue;
+                    }
+                    Key::Enter => return handle_link_card_selection(app),
+                    _ => {}
+                },
+                PopupMode::FilterCards => match key {
+                    Key::Up => {
+                        app.saved_filter_up();
+                        return AppReturn::Continue;
+                    }
+                    Key::Down => {
+                        app.saved_filter_down();
+                        return AppReturn::Continue;
+                    }
+                    Key::Enter => return handle_filter_cards_submit(app),
+                    _ => {}
+                },
+                PopupMode::SaveFilterPrompt => {
+                    if let Key::Enter = key {
+                        return handle_save_filter(app);
+                    }
+                }
+                PopupMode::SetWipLimitPrompt => {
+                    if let Key::Enter = key {
+                        return handle_set_wip_limit(app);
+                    }
+                }
+                PopupMode::SetWorkflowPrompt => {
+                    if let Key::Enter = key {
+                        return handle_set_workflow(app);
+                    }
+                }
+                PopupMode::ImportFromJSONPrompt(replace) => {
+                    if let Key::Enter = key {
+                        return handle_import_from_json(app, replace);
+                    }
+                }
+                PopupMode::QuickAddCard => {
+                    if let Key::Enter = key {
+                        return handle_quick_add_card(app);
+                    }
+                }
+                PopupMode::SwitchWorkspace => match key {
+                    Key::Up => {
+                        app.workspace_up();
+                        return AppReturn::Continue;
+                    }
+                    Key::Down => {
+                        app.workspace_down();
+                        return AppReturn::Continue;
+                    }
+                    Key::Enter => return handle_switch_workspace(app).await,
+                    _ => {}
+                },
                 PopupMode::ViewCard => {
-                    if app.card_being_edited.is_none()
-                        && app.state.current_board_id.is_some()
-                        && app.state.current_card_id.is_some()
-                    {
-                        let board = app
-                            .boards
-                            .iter()
-                            .find(|board| board.id == app.state.current_board_id.unwrap());
-                        if board.is_some() {
-                            let card = board
-                                .unwrap()
-                                .cards
-                                .iter()
-                                .find(|card| card.id == app.state.current_card_id.unwrap());
-                            if card.is_some() {
-                                app.card_being_edited = Some((
-                                    app.state.current_board_id.unwrap(),
-                                    card.unwrap().clone(),
-                                ));
-                            }
-                        }
+                    if app.card_being_edited.is_none() {
+                        handle_edit_new_card(app);
                     }
                     if app.card_being_edited.is_none() {
                         app.state.popup_mode = None;
@@ -664,7 +753,7 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                             Focus::CardTags => {
                                 let card_being_edited = app.card_being_edited.as_mut().unwrap();
                                 card_being_edited.1.tags.push(String::new());
-                                app.state.current_cursor_position = Some(0);
+                                app.state.text_input.reset();
                                 app.state
                                     .card_view_tag_list_state
                                     .select(Some(card_being_edited.1.tags.len() - 1));
@@ -673,12 +762,22 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                             Focus::CardComments => {
                                 let card_being_edited = app.card_being_edited.as_mut().unwrap();
                                 card_being_edited.1.comments.push(String::new());
-                                app.state.current_cursor_position = Some(0);
+                                app.state.text_input.reset();
                                 app.state
                                     .card_view_comment_list_state
                                     .select(Some(card_being_edited.1.comments.len() - 1));
                                 return AppReturn::Continue;
                             }
+                            Focus::CardLinks => return open_link_card(app),
+                            Focus::CardChecklist => {
+                                let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                card_being_edited.1.checklist.push(ChecklistItem::default());
+                                app.state.text_input.reset();
+                                app.state
+                                    .card_view_checklist_list_state
+                                    .select(Some(card_being_edited.1.checklist.len() - 1));
+                                return AppReturn::Continue;
+                            }
                             Focus::CardDueDate => {
                                 return AppReturn::Continue;
                             }
@@ -696,621 +795,251 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                                 app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
                                 return AppReturn::Continue;
                             }
+                            Focus::CardRecurrence => return open_card_recurrence_selector(app),
                             Focus::SubmitButton => {
                                 return handle_edit_card_submit(app);
                             }
+                            Focus::CardHistory => return AppReturn::Continue,
                             _ => {}
                         },
                         Key::Tab => {
                             handle_next_focus(app);
                             app.state.card_view_comment_list_state.select(None);
                             app.state.card_view_tag_list_state.select(None);
-                            app.state.current_cursor_position = None;
+                            app.state.card_view_checklist_list_state.select(None);
+                            app.state.card_view_link_list_state.select(None);
+                            app.state.text_input.reset();
                             return AppReturn::Continue;
                         }
                         Key::BackTab => {
                             handle_prv_focus(app);
                             app.state.card_view_comment_list_state.select(None);
                             app.state.card_view_tag_list_state.select(None);
-                            app.state.current_cursor_position = None;
+                            app.state.card_view_checklist_list_state.select(None);
+                            app.state.card_view_link_list_state.select(None);
+                            app.state.text_input.reset();
                             return AppReturn::Continue;
                         }
-                        Key::Backspace => {
-                            match app.state.focus {
-                                Focus::CardDescription => {
-                                    if app.state.current_cursor_position.is_some() {
-                                        let current_cursor_position =
-                                            app.state.current_cursor_position.unwrap();
-                                        if current_cursor_position > 0 {
-                                            card_being_edited
-                                                .1
-                                                .description
-                                                .remove(current_cursor_position - 1);
-                                            app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
-                                        }
-                                    } else {
-                                        card_being_edited
-                                            .1
-                                            .description
-                                            .remove(card_being_edited.1.description.len() - 1);
-                                        app.state.current_cursor_position =
-                                            Some(card_being_edited.1.description.len());
-                                    }
-                                }
-                                Focus::CardDueDate => {
-                                    if app.state.current_cursor_position.is_some() {
-                                        let current_cursor_position =
-                                            app.state.current_cursor_position.unwrap();
-                                        if current_cursor_position > 0 {
-                                            card_being_edited
-                                                .1
-                                                .date_due
-                                                .remove(current_cursor_position - 1);
-                                            app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
-                                        }
-                                    } else {
-                                        card_being_edited
-                                            .1
-                                            .date_due
-                                            .remove(card_being_edited.1.date_due.len() - 1);
-                                        app.state.current_cursor_position =
-                                            Some(card_being_edited.1.date_due.len());
-                                    }
-                                }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected();
-                                        if selected_tag_index.is_some() {
-                                            // check if the card has a tag at the selected index
-                                            if card_being_edited
-                                                .1
-                                                .tags
-                                                .get(selected_tag_index.unwrap())
-                                                .is_none()
-                                            {
-                                                // select the last available tag
-                                                app.state.card_view_tag_list_state.select(Some(
-                                                    card_being_edited.1.tags.len() - 1,
-                                                ));
-                                                return AppReturn::Continue;
-                                            }
-                                        }
-                                        let selected_tag_index = selected_tag_index.unwrap();
-                                        let current_cursor_position =
-                                            if app.state.current_cursor_position.is_some() {
-                                                app.state.current_cursor_position.unwrap()
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                                0
-                                            };
-                                        if current_cursor_position > 0 {
-                                            // check if selected_tag_index is valid
-                                            if card_being_edited
-                                                .1
-                                                .tags
-                                                .get(selected_tag_index)
-                                                .is_none()
-                                            {
-                                                if card_being_edited.1.tags.is_empty() {
-                                                    app.state.card_view_tag_list_state.select(None);
-                                                    app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                                } else {
-                                                    app.state.card_view_tag_list_state.select(
-                                                        Some(card_being_edited.1.tags.len() - 1),
-                                                    );
-                                                }
-                                                return AppReturn::Continue;
-                                            }
-                                            card_being_edited
-                                                .1
-                                                .tags
-                                                .get_mut(selected_tag_index)
-                                                .unwrap()
-                                                .remove(current_cursor_position - 1);
-                                            app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                    }
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment_index =
-                                            app.state.card_view_comment_list_state.selected();
-                                        if selected_comment_index.is_some() {
-                                            // check if the card has a comment at the selected index
-                                            if card_being_edited
-                                                .1
-                                                .comments
-                                                .get(selected_comment_index.unwrap())
-                                                .is_none()
-                                            {
-                                                if card_being_edited.1.comments.is_empty() {
-                                                    app.state
-                                                        .card_view_comment_list_state
-                                                        .select(None);
-                                                    app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                                } else {
-                                                    app.state.card_view_comment_list_state.select(
-                                                        Some(
-                                                            card_being_edited.1.comments.len() - 1,
-                                                        ),
-                                                    );
-                                                }
-                                                return AppReturn::Continue;
-                                            }
-                                        }
-                                        let selected_comment_index =
-                                            selected_comment_index.unwrap();
-                                        let current_cursor_position =
-                                            if app.state.current_cursor_position.is_some() {
-                                                app.state.current_cursor_position.unwrap()
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                                0
-                                            };
-                                        if current_cursor_position > 0 {
-                                            // remove the char from the selected comment
-                                            card_being_edited
-                                                .1
-                                                .comments
-                                                .get_mut(selected_comment_index)
-                                                .unwrap()
-                                                .remove(current_cursor_position - 1);
-                                            app.state.current_cursor_position =
-                                                Some(current_cursor_position - 1);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                    }
-                                }
-                                _ => {}
+                        Key::Ins => {
+                            app.state.text_input.reset();
+                            if app.card_being_edited.is_some() {
+                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
+                                app.state.app_status = AppStatus::Initialized;
                             }
                             return AppReturn::Continue;
                         }
-                        Key::Left => {
-                            match app.state.focus {
-                                Focus::CardDescription => {
-                                    if !card_being_edited.1.description.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.description.len());
-                                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() - 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    }
-                                }
-                                Focus::CardDueDate => {
-                                    if !card_being_edited.1.date_due.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.date_due.len());
-                                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() - 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
+                        Key::Down => match app.state.focus {
+                            Focus::CardTags => {
+                                if app.state.card_view_tag_list_state.selected().is_some() {
+                                    let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                    if card_being_edited.1.tags.is_empty() {
+                                        return AppReturn::Continue;
                                     }
-                                }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get_mut(selected_tag);
-                                        if tag.is_some() {
-                                            let tag = tag.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position = Some(tag.len());
-                                            } else if app.state.current_cursor_position.unwrap() > 0
-                                            {
-                                                if app.state.current_cursor_position.unwrap()
-                                                    > tag.len()
-                                                {
-                                                    app.state.current_cursor_position =
-                                                        Some(tag.len());
-                                                }
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() - 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                            }
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                    let selected_tag_index =
+                                        app.state.card_view_tag_list_state.selected().unwrap();
+                                    if selected_tag_index < card_being_edited.1.tags.len() - 1 {
+                                        app.state
+                                            .card_view_tag_list_state
+                                            .select(Some(selected_tag_index + 1));
                                     }
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get_mut(selected_comment);
-                                        if comment.is_some() {
-                                            let comment = comment.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position =
-                                                    Some(comment.len());
-                                            } else if app.state.current_cursor_position.unwrap() > 0
-                                            {
-                                                if app.state.current_cursor_position.unwrap()
-                                                    > comment.len()
-                                                {
-                                                    app.state.current_cursor_position =
-                                                        Some(comment.len());
-                                                }
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() - 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position = Some(0);
-                                            }
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                } else {
+                                    let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                    if !card_being_edited.1.tags.is_empty() {
+                                        app.state.card_view_tag_list_state.select(Some(0));
                                     }
                                 }
-                                _ => {}
+                                app.state.text_input.reset();
+                                return AppReturn::Continue;
                             }
-                            return AppReturn::Continue;
-                        }
-                        Key::Right => {
-                            match app.state.focus {
-                                Focus::CardDescription => {
-                                    if !card_being_edited.1.description.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position = Some(0);
-                                        } else if app.state.current_cursor_position.unwrap()
-                                            < card_being_edited.1.description.len()
-                                        {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() + 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.description.len());
-                                        }
+                            Focus::CardComments => {
+                                if app.state.card_view_comment_list_state.selected().is_some() {
+                                    let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                    if card_being_edited.1.comments.is_empty() {
+                                        return AppReturn::Continue;
                                     }
-                                }
-                                Focus::CardDueDate => {
-                                    if !card_being_edited.1.date_due.is_empty() {
-                                        if app.state.current_cursor_position.is_none() {
-                                            app.state.current_cursor_position = Some(0);
-                                        } else if app.state.current_cursor_position.unwrap()
-                                            < card_being_edited.1.date_due.len()
-                                        {
-                                            app.state.current_cursor_position = Some(
-                                                app.state.current_cursor_position.unwrap() + 1,
-                                            );
-                                        } else {
-                                            app.state.current_cursor_position =
-                                                Some(card_being_edited.1.date_due.len());
-                                        }
+                                    let selected_comment_index =
+                                        app.state.card_view_comment_list_state.selected().unwrap();
+                                    if selected_comment_index
+                                        < card_being_edited.1.comments.len() - 1
+                                    {
+                                        app.state
+                                            .card_view_comment_list_state
+                                            .select(Some(selected_comment_index + 1));
                                     }
-                                }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get_mut(selected_tag);
-                                        if tag.is_some() {
-                                            let tag = tag.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position = Some(0);
-                                            } else if app.state.current_cursor_position.unwrap()
-                                                < tag.len()
-                                            {
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() + 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position = Some(tag.len());
-                                            }
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                                } else {
+                                    let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                    if !card_being_edited.1.comments.is_empty() {
+                                        app.state.card_view_comment_list_state.select(Some(0));
                                     }
                                 }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get_mut(selected_comment);
-                                        if comment.is_some() {
-                                            let comment = comment.unwrap();
-                                            if app.state.current_cursor_position.is_none() {
-                                                app.state.current_cursor_position = Some(0);
-                                            } else if app.state.current_cursor_position.unwrap()
-                                                < comment.len()
-                                            {
-                                                app.state.current_cursor_position = Some(
-                                                    app.state.current_cursor_position.unwrap() + 1,
-                                                );
-                                            } else {
-                                                app.state.current_cursor_position =
-                                                    Some(comment.len());
-                                            }
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                app.state.text_input.reset();
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardChecklist => {
+                                let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                if let Some(selected_item_index) =
+                                    app.state.card_view_checklist_list_state.selected()
+                                {
+                                    if selected_item_index + 1 < card_being_edited.1.checklist.len()
+                                    {
+                                        app.state
+                                            .card_view_checklist_list_state
+                                            .select(Some(selected_item_index + 1));
                                     }
+                                } else if !card_being_edited.1.checklist.is_empty() {
+                                    app.state.card_view_checklist_list_state.select(Some(0));
                                 }
-                                _ => {}
+                                app.state.text_input.reset();
+                                return AppReturn::Continue;
                             }
-                            return AppReturn::Continue;
-                        }
-                        Key::Home => {
-                            match app.state.focus {
-                                Focus::CardDescription | Focus::CardDueDate => {
-                                    app.state.current_cursor_position = Some(0);
-                                }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get(selected_tag);
-                                        if tag.is_some() {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                            Focus::CardLinks => {
+                                let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                if let Some(selected_link_index) =
+                                    app.state.card_view_link_list_state.selected()
+                                {
+                                    if selected_link_index + 1 < card_being_edited.1.links.len() {
+                                        app.state
+                                            .card_view_link_list_state
+                                            .select(Some(selected_link_index + 1));
                                     }
+                                } else if !card_being_edited.1.links.is_empty() {
+                                    app.state.card_view_link_list_state.select(Some(0));
                                 }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get(selected_comment);
-                                        if comment.is_some() {
-                                            app.state.current_cursor_position = Some(0);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                return AppReturn::Continue;
+                            }
+                            _ => {}
+                        },
+                        Key::Up => match app.state.focus {
+                            Focus::CardTags => {
+                                if app.state.card_view_tag_list_state.selected().is_some() {
+                                    let selected_tag_index =
+                                        app.state.card_view_tag_list_state.selected().unwrap();
+                                    if selected_tag_index > 0 {
+                                        app.state
+                                            .card_view_tag_list_state
+                                            .select(Some(selected_tag_index - 1));
+                                    }
+                                } else {
+                                    let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                    if !card_being_edited.1.tags.is_empty() {
+                                        app.state
+                                            .card_view_tag_list_state
+                                            .select(Some(card_being_edited.1.tags.len() - 1));
                                     }
                                 }
-                                _ => {}
+                                app.state.text_input.reset();
+                                return AppReturn::Continue;
                             }
-                            return AppReturn::Continue;
-                        }
-                        Key::End => {
-                            match app.state.focus {
-                                Focus::CardDescription => {
-                                    app.state.current_cursor_position =
-                                        Some(card_being_edited.1.description.len());
-                                }
-                                Focus::CardDueDate => {
-                                    app.state.current_cursor_position =
-                                        Some(card_being_edited.1.date_due.len());
-                                }
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        let tag = card_being_edited.1.tags.get(selected_tag);
-                                        if tag.is_some() {
-                                            app.state.current_cursor_position =
-                                                Some(tag.unwrap().len());
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
+                            Focus::CardComments => {
+                                if app.state.card_view_comment_list_state.selected().is_some() {
+                                    let selected_comment_index =
+                                        app.state.card_view_comment_list_state.selected().unwrap();
+                                    if selected_comment_index > 0 {
+                                        app.state
+                                            .card_view_comment_list_state
+                                            .select(Some(selected_comment_index - 1));
                                     }
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment = app
-                                            .state
+                                } else {
+                                    let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                    if !card_being_edited.1.comments.is_empty() {
+                                        app.state
                                             .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        let comment =
-                                            card_being_edited.1.comments.get(selected_comment);
-                                        if comment.is_some() {
-                                            app.state.current_cursor_position =
-                                                Some(comment.unwrap().len());
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
+                                            .select(Some(card_being_edited.1.comments.len() - 1));
                                     }
                                 }
-                                _ => {}
-                            }
-                            return AppReturn::Continue;
-                        }
-                        Key::Ins => {
-                            app.state.current_cursor_position = None;
-                            if app.card_being_edited.is_some() {
-                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
-                                app.state.app_status = AppStatus::Initialized;
+                                app.state.text_input.reset();
+                                return AppReturn::Continue;
                             }
-                            return AppReturn::Continue;
-                        }
-                        Key::ShiftRight => {
-                            match app.state.focus {
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if card_being_edited.1.tags.is_empty() {
-                                            return AppReturn::Continue;
-                                        }
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        if selected_tag_index < card_being_edited.1.tags.len() - 1 {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index + 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.tags.is_empty() {
-                                            app.state.card_view_tag_list_state.select(Some(0));
-                                        }
+                            Focus::CardChecklist => {
+                                let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                if let Some(selected_item_index) =
+                                    app.state.card_view_checklist_list_state.selected()
+                                {
+                                    if selected_item_index > 0 {
+                                        app.state
+                                            .card_view_checklist_list_state
+                                            .select(Some(selected_item_index - 1));
                                     }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
+                                } else if !card_being_edited.1.checklist.is_empty() {
+                                    app.state
+                                        .card_view_checklist_list_state
+                                        .select(Some(card_being_edited.1.checklist.len() - 1));
                                 }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if card_being_edited.1.comments.is_empty() {
-                                            return AppReturn::Continue;
-                                        }
-                                        let selected_comment_index = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        if selected_comment_index
-                                            < card_being_edited.1.comments.len() - 1
-                                        {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index + 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.comments.is_empty() {
-                                            app.state.card_view_comment_list_state.select(Some(0));
-                                        }
+                                app.state.text_input.reset();
+                                return AppReturn::Continue;
+                            }
+                            Focus::CardLinks => {
+                                let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                if let Some(selected_link_index) =
+                                    app.state.card_view_link_list_state.selected()
+                                {
+                                    if selected_link_index > 0 {
+                                        app.state
+                                            .card_view_link_list_state
+                                            .select(Some(selected_link_index - 1));
                                     }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
+                                } else if !card_being_edited.1.links.is_empty() {
+                                    app.state
+                                        .card_view_link_list_state
+                                        .select(Some(card_being_edited.1.links.len() - 1));
                                 }
-                                _ => {}
+                                return AppReturn::Continue;
+                            }
+                            _ => {}
+                        },
+                        Key::Delete | Key::Backspace
+                            if matches!(
+                                app.state.focus,
+                                Focus::CardTags | Focus::CardComments | Focus::CardChecklist
+                            ) =>
+                        {
+                            if remove_empty_card_view_item(app) {
+                                return AppReturn::Continue;
                             }
-                            return AppReturn::Continue;
                         }
-                        Key::ShiftLeft => {
-                            match app.state.focus {
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        if selected_tag_index > 0 {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index - 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.tags.is_empty() {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(card_being_edited.1.tags.len() - 1));
-                                        }
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
+                        Key::Delete if app.state.focus == Focus::CardLinks => {
+                            if let Some(selected_link_index) =
+                                app.state.card_view_link_list_state.selected()
+                            {
+                                let card_being_edited = app.card_being_edited.as_mut().unwrap();
+                                if selected_link_index < card_being_edited.1.links.len() {
+                                    card_being_edited.1.links.remove(selected_link_index);
                                 }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let selected_comment_index = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        if selected_comment_index > 0 {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index - 1));
-                                        }
-                                    } else {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        if !card_being_edited.1.comments.is_empty() {
-                                            app.state.card_view_comment_list_state.select(Some(
-                                                card_being_edited.1.comments.len() - 1,
-                                            ));
-                                        }
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
+                                if selected_link_index < card_being_edited.1.links.len() {
+                                    app.state
+                                        .card_view_link_list_state
+                                        .select(Some(selected_link_index));
+                                } else if !card_being_edited.1.links.is_empty() {
+                                    app.state
+                                        .card_view_link_list_state
+                                        .select(Some(card_being_edited.1.links.len() - 1));
+                                } else {
+                                    app.state.card_view_link_list_state.select(None);
                                 }
-                                _ => {}
+                            } else {
+                                app.send_warning_toast(
+                                    "No link selected press <Up> or <Down> to select a link",
+                                    None,
+                                );
                             }
                             return AppReturn::Continue;
                         }
-                        Key::Delete => {
-                            match app.state.focus {
-                                Focus::CardTags => {
-                                    if app.state.card_view_tag_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        let selected_tag_index =
-                                            app.state.card_view_tag_list_state.selected().unwrap();
-                                        card_being_edited.1.tags.remove(selected_tag_index);
-                                        if selected_tag_index < card_being_edited.1.tags.len() {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index));
-                                        } else if selected_tag_index > 0 {
-                                            app.state
-                                                .card_view_tag_list_state
-                                                .select(Some(selected_tag_index - 1));
-                                        } else {
-                                            app.state.card_view_tag_list_state.select(None);
-                                        }
-                                    } else {
-                                        app.send_warning_toast("No tag selected, press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                    }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
-                                }
-                                Focus::CardComments => {
-                                    if app.state.card_view_comment_list_state.selected().is_some() {
-                                        let card_being_edited =
-                                            app.card_being_edited.as_mut().unwrap();
-                                        let selected_comment_index = app
-                                            .state
-                                            .card_view_comment_list_state
-                                            .selected()
-                                            .unwrap();
-                                        card_being_edited.1.comments.remove(selected_comment_index);
-                                        if selected_comment_index
-                                            < card_being_edited.1.comments.len()
+                        Key::Ctrl('t') => {
+                            if app.state.focus == Focus::CardChecklist {
+                                match app.state.card_view_checklist_list_state.selected() {
+                                    Some(selected_item_index) => {
+                                        if let Some(item) = card_being_edited
+                                            .1
+                                            .checklist
+                                            .get_mut(selected_item_index)
                                         {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index));
-                                        } else if selected_comment_index > 0 {
-                                            app.state
-                                                .card_view_comment_list_state
-                                                .select(Some(selected_comment_index - 1));
-                                        } else {
-                                            app.state.card_view_comment_list_state.select(None);
+                                            item.done = !item.done;
                                         }
-                                    } else {
-                                        app.send_warning_toast("No comment selected, press <Shift+Right> or <Shift+Left> to select a comment", None);
                                     }
-                                    app.state.current_cursor_position = None;
-                                    return AppReturn::Continue;
+                                    None => {
+                                        app.send_warning_toast("No checklist item selected press <Up> or <Down> to select a checklist item", None);
+                                    }
                                 }
-                                _ => {}
                             }
                             return AppReturn::Continue;
                         }
@@ -1361,7 +1090,19 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                     }
                     _ => {}
                 },
-                PopupMode::CardStatusSelector => match key {
+                PopupMode::CardRecurrenceSelector => match key {
+                    Key::Up => {
+                        app.select_card_recurrence_prev();
+                    }
+                    Key::Down => {
+                        app.select_card_recurrence_next();
+                    }
+                    Key::Enter => {
+                        handle_change_card_recurrence(app);
+                    }
+                    _ => {}
+                },
+                PopupMode::CardStatusSelector => match key {
                     Key::Up => {
                         app.select_card_status_prev();
                     }
@@ -1376,519 +1117,168 @@ pub async fn handle_user_input_mode(app: &mut App, key: Key) -> AppReturn {
                 _ => {}
             }
         }
-        let mut current_key = key.to_string();
-        match key {
-            Key::Char(' ') => current_key = " ".to_string(),
-            Key::Enter => current_key = "\n".to_string(),
-            Key::Tab => current_key = "  ".to_string(),
-            Key::BackTab => current_key = "".to_string(),
-            Key::Backspace => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_board_form[0].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.new_board_form[0].pop();
-                            }
-                        }
-                        Focus::NewBoardDescription => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_board_form[1].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.new_board_form[1].pop();
-                            }
-                        }
-                        _ => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state
-                                        .current_user_input
-                                        .remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.current_user_input.pop();
-                            }
-                        }
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::NewCardName => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_card_form[0].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.new_card_form[0].pop();
-                            }
-                        }
-                        Focus::CardDescription => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_card_form[1].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.new_card_form[1].pop();
-                            }
-                        }
-                        Focus::CardDueDate => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state.new_card_form[2].remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.new_card_form[2].pop();
-                            }
-                        }
-                        _ => {
-                            if app.state.current_cursor_position.is_some() {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap();
-                                if current_cursor_position > 0 {
-                                    app.state
-                                        .current_user_input
-                                        .remove(current_cursor_position - 1);
-                                    app.state.current_cursor_position =
-                                        Some(current_cursor_position - 1);
-                                }
-                            } else {
-                                app.state.current_user_input.pop();
-                            }
-                        }
-                    },
-                    _ => {
-                        if app.state.current_cursor_position.is_some() {
-                            let current_cursor_position =
-                                app.state.current_cursor_position.unwrap();
-                            if current_cursor_position > 0 {
-                                app.state
-                                    .current_user_input
-                                    .remove(current_cursor_position - 1);
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position - 1);
-                            }
-                        } else {
-                            app.state.current_user_input.pop();
-                        }
-                    }
-                };
-                current_key = "".to_string();
-            }
-            Key::Left => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[0].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
-                            }
-                        }
-                        Focus::NewBoardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[1].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
-                            }
-                        }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::NewCardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[0].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
-                            }
-                        }
-                        Focus::CardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[1].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
-                            }
-                        }
-                        Focus::CardDueDate => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[2].len());
-                            } else if app.state.current_cursor_position.unwrap() > 0 {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() - 1);
-                            } else {
-                                app.state.current_cursor_position = Some(0);
-                            }
-                        }
-                        _ => {}
-                    },
-                    _ => {
-                        if app.state.current_cursor_position.is_none() {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_user_input.len());
-                        } else if app.state.current_cursor_position.unwrap() > 0 {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_cursor_position.unwrap() - 1);
-                        } else {
-                            app.state.current_cursor_position = Some(0);
-                        }
-                    }
-                };
-                current_key = "".to_string();
-            }
-            Key::Right => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_board_form[0].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[0].len());
-                            }
-                        }
-                        Focus::NewBoardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_board_form[1].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_board_form[1].len());
-                            }
-                        }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::NewCardName => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_card_form[0].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[0].len());
-                            }
-                        }
-                        Focus::CardDescription => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_card_form[1].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[1].len());
-                            }
-                        }
-                        Focus::CardDueDate => {
-                            if app.state.current_cursor_position.is_none() {
-                                app.state.current_cursor_position = Some(0);
-                            } else if app.state.current_cursor_position.unwrap()
-                                < app.state.new_card_form[2].len()
-                            {
-                                app.state.current_cursor_position =
-                                    Some(app.state.current_cursor_position.unwrap() + 1);
-                            } else {
-                                app.state.current_cursor_position =
-                                    Some(app.state.new_card_form[2].len());
-                            }
-                        }
-                        _ => {}
-                    },
-                    _ => {
-                        if app.state.current_cursor_position.is_none() {
-                            app.state.current_cursor_position = Some(0);
-                        } else if app.state.current_cursor_position.unwrap()
-                            < app.state.current_user_input.len()
-                        {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_cursor_position.unwrap() + 1);
-                        } else {
-                            app.state.current_cursor_position =
-                                Some(app.state.current_user_input.len());
-                        }
-                    }
-                };
-                current_key = "".to_string();
-            }
-            Key::Home => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName | Focus::NewBoardDescription => {
-                            app.state.current_cursor_position = Some(0);
-                        }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::NewCardName | Focus::CardDescription | Focus::CardDueDate => {
-                            app.state.current_cursor_position = Some(0);
-                        }
-                        _ => {}
-                    },
-                    _ => {
-                        app.state.current_cursor_position = Some(0);
-                    }
-                };
-                current_key = "".to_string();
-            }
-            Key::End => {
-                match app.state.ui_mode {
-                    UiMode::NewBoard => match app.state.focus {
-                        Focus::NewBoardName => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_board_form[0].len());
-                        }
-                        Focus::NewBoardDescription => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_board_form[1].len());
-                        }
-                        _ => {}
-                    },
-                    UiMode::NewCard => match app.state.focus {
-                        Focus::NewCardName => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_card_form[0].len());
-                        }
-                        Focus::CardDescription => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_card_form[1].len());
-                        }
-                        Focus::CardDueDate => {
-                            app.state.current_cursor_position =
-                                Some(app.state.new_card_form[2].len());
-                        }
-                        _ => {}
-                    },
-                    _ => {
-                        app.state.current_cursor_position =
-                            Some(app.state.current_user_input.len());
-                    }
-                };
-                current_key = "".to_string();
+        if key == Key::Ins {
+            app.state.app_status = AppStatus::Initialized;
+            app.state.text_input.reset();
+            info!("Exiting User Input Mode");
+            return AppReturn::Continue;
+        }
+        handle_text_input_key(app, key);
+    }
+    AppReturn::Continue
+}
+
+/// Removes the selected tag, comment or checklist item of the card being edited if its text is
+/// empty, returns whether it was removed
+fn remove_empty_card_view_item(app: &mut App) -> bool {
+    let selected = match app.state.focus {
+        Focus::CardTags => app.state.card_view_tag_list_state.selected(),
+        Focus::CardComments => app.state.card_view_comment_list_state.selected(),
+        Focus::CardChecklist => app.state.card_view_checklist_list_state.selected(),
+        _ => None,
+    };
+    let (selected, card) = match (selected, app.card_being_edited.as_mut()) {
+        (Some(selected), Some((_, card))) => (selected, card),
+        _ => return false,
+    };
+    let remaining = match app.state.focus {
+        Focus::CardTags if card.tags.get(selected).is_some_and(|tag| tag.is_empty()) => {
+            card.tags.remove(selected);
+            card.tags.len()
+        }
+        Focus::CardComments
+            if card
+                .comments
+                .get(selected)
+                .is_some_and(|comment| comment.is_empty()) =>
+        {
+            card.comments.remove(selected);
+            card.comments.len()
+        }
+        Focus::CardChecklist
+            if card
+                .checklist
+                .get(selected)
+                .is_some_and(|item| item.name.is_empty()) =>
+        {
+            card.checklist.remove(selected);
+            card.checklist.len()
+        }
+        _ => return false,
+    };
+    let selection = remaining.checked_sub(1).map(|last| selected.min(last));
+    match app.state.focus {
+        Focus::CardTags => app.state.card_view_tag_list_state.select(selection),
+        Focus::CardComments => app.state.card_view_comment_list_state.select(selection),
+        _ => app.state.card_view_checklist_list_state.select(selection),
+    }
+    app.state.text_input.reset();
+    true
+}
+
+/// Passes an editing key on to the text input of the focused field
+fn handle_text_input_key(app: &mut App, key: Key) {
+    if app.state.popup_mode == Some(PopupMode::ViewCard) {
+        let warning = match app.state.focus {
+            Focus::CardTags if app.state.card_view_tag_list_state.selected().is_none() => {
+                Some("No tag selected press <Up> or <Down> to select a tag")
             }
-            Key::Ins => {
-                app.state.app_status = AppStatus::Initialized;
-                app.state.current_cursor_position = None;
-                info!("Exiting User Input Mode");
-                return AppReturn::Continue;
+            Focus::CardComments if app.state.card_view_comment_list_state.selected().is_none() => {
+                Some("No comment selected press <Up> or <Down> to select a comment")
             }
-            _ => {
-                if current_key.starts_with('<') && current_key.ends_with('>') {
-                    current_key = current_key[1..current_key.len() - 1].to_string();
-                }
-                if current_key.is_empty() {
-                    return AppReturn::Continue;
-                }
+            Focus::CardChecklist
+                if app
+                    .state
+                    .card_view_checklist_list_state
+                    .selected()
+                    .is_none() =>
+            {
+                Some("No checklist item selected press <Up> or <Down> to select a checklist item")
             }
+            _ => None,
+        };
+        if let Some(warning) = warning {
+            app.send_warning_toast(warning, None);
+            return;
         }
+    }
+    let multiline = matches!(
+        app.state.focus,
+        Focus::CardDescription | Focus::NewBoardDescription
+    );
+    let item = match (app.state.popup_mode, app.state.focus) {
+        (Some(PopupMode::ViewCard), Focus::CardTags) => {
+            app.state.card_view_tag_list_state.selected()
+        }
+        (Some(PopupMode::ViewCard), Focus::CardComments) => {
+            app.state.card_view_comment_list_state.selected()
+        }
+        (Some(PopupMode::ViewCard), Focus::CardChecklist) => {
+            app.state.card_view_checklist_list_state.selected()
+        }
+        _ => None,
+    };
+    let mut text_input = std::mem::take(&mut app.state.text_input);
+    text_input.edit_field(app.state.focus, item);
+    if let Some(text) = focused_text_field(app) {
+        if key == Key::Tab {
+            text_input.insert_str(text, "  ");
+        } else {
+            text_input.handle_key(text, key, multiline);
+        }
+    } else {
+        debug!(
+            "No text field to edit for focus '{}'",
+            app.state.focus.to_str()
+        );
+    }
+    app.state.text_input = text_input;
+}
 
-        // Actually appending input to the current user input* (depending on the current mode)
-
-        if current_key.chars().next().is_some() {
-            if app.state.popup_mode.is_some() {
-                match app.state.popup_mode.unwrap() {
-                    PopupMode::ViewCard => {
-                        let card_being_edited = app.card_being_edited.as_mut().unwrap();
-                        match app.state.focus {
-                            Focus::CardDescription => {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                for (i, char) in current_key.chars().enumerate() {
-                                    card_being_edited
-                                        .1
-                                        .description
-                                        .insert(current_cursor_position + i, char);
-                                }
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position + 1);
-                            }
-                            Focus::CardDueDate => {
-                                let current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                for (i, char) in current_key.chars().enumerate() {
-                                    card_being_edited
-                                        .1
-                                        .date_due
-                                        .insert(current_cursor_position + i, char);
-                                }
-                                app.state.current_cursor_position =
-                                    Some(current_cursor_position + 1);
-                            }
-                            Focus::CardTags => {
-                                let mut current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                if app.state.card_view_tag_list_state.selected().is_some() {
-                                    let selected_tag =
-                                        app.state.card_view_tag_list_state.selected().unwrap();
-                                    let mut tag = card_being_edited.1.tags.get_mut(selected_tag);
-                                    if tag.is_some() {
-                                        let tag = tag.as_mut().unwrap();
-                                        if current_cursor_position > tag.len() {
-                                            current_cursor_position = tag.len();
-                                        }
-                                        for (i, char) in current_key.chars().enumerate() {
-                                            tag.insert(current_cursor_position + i, char);
-                                        }
-                                        app.state.current_cursor_position =
-                                            Some(current_cursor_position + 1);
-                                    }
-                                } else {
-                                    app.send_warning_toast("No tag selected press <Shift+Right> or <Shift+Left> to select a tag", None);
-                                }
-                            }
-                            Focus::CardComments => {
-                                let mut current_cursor_position =
-                                    app.state.current_cursor_position.unwrap_or(0);
-                                if app.state.card_view_comment_list_state.selected().is_some() {
-                                    let selected_comment =
-                                        app.state.card_view_comment_list_state.selected().unwrap();
-                                    let mut comment =
-                                        card_being_edited.1.comments.get_mut(selected_comment);
-                                    if comment.is_some() {
-                                        let comment = comment.as_mut().unwrap();
-                                        if current_cursor_position > comment.len() {
-                                            current_cursor_position = comment.len();
-                                        }
-                                        for (i, char) in current_key.chars().enumerate() {
-                                            comment.insert(current_cursor_position + i, char);
-                                        }
-                                        app.state.current_cursor_position =
-                                            Some(current_cursor_position + 1);
-                                    }
-                                } else {
-                                    app.send_warning_toast("No comment selected press <Shift+Right> or <Shift+Left> to select a comment", None);
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                    PopupMode::CommandPalette
-                    | PopupMode::CustomRGBPromptFG
-                    | PopupMode::CustomRGBPromptBG
-                    | PopupMode::EditGeneralConfig => {
-                        let current_cursor_position =
-                            app.state.current_cursor_position.unwrap_or(0);
-                        for (i, char) in current_key.chars().enumerate() {
-                            app.state
-                                .current_user_input
-                                .insert(current_cursor_position + i, char);
-                        }
-                        app.state.current_cursor_position = Some(current_cursor_position + 1);
-                    }
-                    _ => {
-                        debug!(
-                            "Invalid popup mode '{}' for user input",
-                            app.state.popup_mode.unwrap()
-                        );
-                    }
-                }
-                return AppReturn::Continue;
-            }
+/// The text of the field that is currently being edited in user input mode
+pub fn focused_text_field(app: &mut App) -> Option<&mut String> {
+    match app.state.popup_mode {
+        Some(PopupMode::ViewCard) => {
+            let card = &mut app.card_being_edited.as_mut()?.1;
             match app.state.focus {
-                Focus::NewBoardName => {
-                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_board_form[0].insert(cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(cursor_position + 1);
-                }
-                Focus::NewBoardDescription => {
-                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_board_form[1].insert(cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(cursor_position + 1);
-                }
-                Focus::NewCardName => {
-                    let cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_card_form[0].insert(cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(cursor_position + 1);
-                }
-                Focus::CardDescription => {
-                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_card_form[1].insert(current_cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(current_cursor_position + 1);
-                }
-                Focus::CardDueDate => {
-                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state.new_card_form[2].insert(current_cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(current_cursor_position + 1);
-                }
-                _ => {
-                    let current_cursor_position = app.state.current_cursor_position.unwrap_or(0);
-                    for (i, char) in current_key.chars().enumerate() {
-                        app.state
-                            .current_user_input
-                            .insert(current_cursor_position + i, char);
-                    }
-                    app.state.current_cursor_position = Some(current_cursor_position + 1);
-                }
+                Focus::CardDescription => Some(&mut card.description),
+                Focus::CardDueDate => Some(&mut app.card_due_date_being_edited),
+                Focus::CardTags => card
+                    .tags
+                    .get_mut(app.state.card_view_tag_list_state.selected()?),
+                Focus::CardComments => card
+                    .comments
+                    .get_mut(app.state.card_view_comment_list_state.selected()?),
+                Focus::CardChecklist => card
+                    .checklist
+                    .get_mut(app.state.card_view_checklist_list_state.selected()?)
+                    .map(|item| &mut item.name),
+                _ => None,
             }
         }
+        Some(PopupMode::CommandPalette)
+        | Some(PopupMode::CardSearch)
+        | Some(PopupMode::LinkCard)
+        | Some(PopupMode::FilterCards)
+        | Some(PopupMode::SaveFilterPrompt)
+        | Some(PopupMode::SetWipLimitPrompt)
+        | Some(PopupMode::SetWorkflowPrompt)
+        | Some(PopupMode::ImportFromJSONPrompt(_))
+        | Some(PopupMode::SwitchWorkspace)
+        | Some(PopupMode::QuickAddCard)
+        | Some(PopupMode::CustomRGBPromptFG)
+        | Some(PopupMode::CustomRGBPromptBG)
+        | Some(PopupMode::EditGeneralConfig) => Some(&mut app.state.current_user_input),
+        Some(_) => None,
+        None => match app.state.focus {
+            Focus::NewBoardName => Some(&mut app.state.new_board_form[0]),
+            Focus::NewBoardDescription => Some(&mut app.state.new_board_form[1]),
+            Focus::NewCardName => Some(&mut app.state.new_card_form[0]),
+            Focus::CardDescription => Some(&mut app.state.new_card_form[1]),
+            Focus::CardDueDate => Some(&mut app.state.new_card_form[2]),
+            _ => Some(&mut app.state.current_user_input),
+        },
     }
-    AppReturn::Continue
 }
 
 pub async fn handle_keybind_mode(app: &mut App, key: Key) -> AppReturn {
@@ -2033,6 +1423,15 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         PopupMode::CardStatusSelector => {
                             app.select_card_status_prev();
                         }
+                        PopupMode::SortCards => {
+                            app.select_sort_option_prev();
+                        }
+                        PopupMode::ContextMenu => {
+                            app.context_menu_prev();
+                        }
+                        PopupMode::ViewCard if app.state.focus == Focus::CardHistory => {
+                            app.select_card_history_prev();
+                        }
                         PopupMode::SelectDefaultView => {
                             app.select_default_view_prev();
                         }
@@ -2101,6 +1500,9 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         app.load_save_previous();
                         app.dispatch(IoEvent::LoadPreview).await;
                     }
+                    UiMode::Archive => {
+                        app.archive_prev();
+                    }
                     UiMode::EditKeybindings => {
                         app.edit_keybindings_prev();
                     }
@@ -2146,6 +1548,15 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         PopupMode::CardStatusSelector => {
                             app.select_card_status_next();
                         }
+                        PopupMode::SortCards => {
+                            app.select_sort_option_next();
+                        }
+                        PopupMode::ContextMenu => {
+                            app.context_menu_next();
+                        }
+                        PopupMode::ViewCard if app.state.focus == Focus::CardHistory => {
+                            app.select_card_history_next();
+                        }
                         PopupMode::SelectDefaultView => {
                             app.select_default_view_next();
                         }
@@ -2214,6 +1625,9 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         app.load_save_next();
                         app.dispatch(IoEvent::LoadPreview).await;
                     }
+                    UiMode::Archive => {
+                        app.archive_next();
+                    }
                     UiMode::EditKeybindings => {
                         app.edit_keybindings_next();
                     }
@@ -2287,29 +1701,7 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                     app.state.app_status = AppStatus::KeyBindMode;
                                     info!("Taking user keybind input");
                                 }
-                                PopupMode::ViewCard => {
-                                    if app.state.current_board_id.is_some()
-                                        && app.state.current_card_id.is_some()
-                                    {
-                                        let board = app.boards.iter().find(|board| {
-                                            board.id == app.state.current_board_id.unwrap()
-                                        });
-                                        if board.is_some() {
-                                            let card = board.unwrap().cards.iter().find(|card| {
-                                                card.id == app.state.current_card_id.unwrap()
-                                            });
-                                            if card.is_some() {
-                                                app.card_being_edited = Some((
-                                                    app.state.current_board_id.unwrap(),
-                                                    card.unwrap().clone(),
-                                                ));
-                                            }
-                                        }
-                                        app.state.app_status = AppStatus::UserInput;
-                                    } else {
-                                        debug!("No current board or card id to edit card");
-                                    }
-                                }
+                                PopupMode::ViewCard => return handle_edit_new_card(app),
                                 _ => {}
                             }
                         }
@@ -2326,6 +1718,8 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         PopupMode::CardStatusSelector => {
                             return handle_change_card_status(app);
                         }
+                        PopupMode::SortCards => return handle_sort_cards(app),
+                        PopupMode::ContextMenu => return handle_context_menu_selection(app).await,
                         PopupMode::EditGeneralConfig => {
                             if app.state.ui_mode == UiMode::CreateTheme {
                                 handle_create_theme_action(app);
@@ -2350,6 +1744,7 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                 app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
                                 return AppReturn::Continue;
                             }
+                            Focus::CardRecurrence => return open_card_recurrence_selector(app),
                             Focus::CardStatus => {
                                 if app.card_being_edited.is_none() {
                                     handle_edit_new_card(app);
@@ -2360,14 +1755,26 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             Focus::CardDescription
                             | Focus::CardDueDate
                             | Focus::CardTags
-                            | Focus::CardComments => return handle_edit_new_card(app),
+                            | Focus::CardComments
+                            | Focus::CardChecklist => return handle_edit_new_card(app),
+                            Focus::CardLinks => return open_link_card(app),
                             Focus::SubmitButton => {
                                 return handle_edit_card_submit(app);
                             }
+                            Focus::CardHistory => return AppReturn::Continue,
                             _ => {}
                         },
-                        PopupMode::CommandPalette => {
-                            // not required to handle here as the command palette is handled in the user input mode
+                        PopupMode::CommandPalette
+                        | PopupMode::CardSearch
+                        | PopupMode::LinkCard
+                        | PopupMode::FilterCards
+                        | PopupMode::SaveFilterPrompt
+                        | PopupMode::SetWipLimitPrompt
+                        | PopupMode::SetWorkflowPrompt
+                        | PopupMode::ImportFromJSONPrompt(_)
+                        | PopupMode::SwitchWorkspace
+                        | PopupMode::QuickAddCard => {
+                            // not required to handle here as text input popups are handled in the user input mode
                         }
                         PopupMode::ConfirmDiscardCardChanges => match app.state.focus {
                             Focus::SubmitButton => {
@@ -2396,12 +1803,23 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         PopupMode::CardPrioritySelector => {
                             return handle_change_card_priority(app);
                         }
+                        PopupMode::CardRecurrenceSelector => {
+                            return handle_change_card_recurrence(app);
+                        }
+                        PopupMode::ConfirmDestructiveAction(action) => {
+                            return handle_destructive_action_confirmation(
+                                app,
+                                *action,
+                                app.state.focus == Focus::SubmitButton,
+                            )
+                            .await;
+                        }
                     }
                     app.state.popup_mode = None;
                     return AppReturn::Continue;
                 }
                 match app.state.ui_mode {
-                    UiMode::ConfigMenu => handle_config_menu_action(app),
+                    UiMode::ConfigMenu => handle_config_menu_action(app).await,
                     UiMode::MainMenu => match app.state.focus {
                         Focus::MainMenu => handle_main_menu_action(app),
                         Focus::MainMenuHelp => {
@@ -2423,6 +1841,7 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         app.dispatch(IoEvent::LoadSave).await;
                         AppReturn::Continue
                     }
+                    UiMode::Archive => handle_restore_archived_item(app),
                     UiMode::EditKeybindings => {
                         handle_edit_keybindings_action(app);
                         AppReturn::Continue
@@ -2615,142 +2034,68 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                 }
                 AppReturn::Continue
             }
-            Action::DeleteCard => {
-                match app.state.ui_mode {
-                    UiMode::LoadSave => {
-                        // run delete task in background
-                        app.dispatch(IoEvent::DeleteSave).await;
-                        tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
-                        app.dispatch(IoEvent::LoadPreview).await;
+            Action::DeleteCard => match app.state.ui_mode {
+                UiMode::LoadSave => {
+                    request_destructive_action(app, DestructiveAction::DeleteSave).await
+                }
+                _ => {
+                    if !UiMode::view_modes().contains(&app.state.ui_mode)
+                        || app.state.focus != Focus::Body
+                    {
+                        return AppReturn::Continue;
+                    }
+                    if app.state.current_card_id.is_some() {
+                        request_destructive_action(app, DestructiveAction::DeleteCard).await
+                    } else if app.state.current_board_id.is_some() {
+                        request_destructive_action(app, DestructiveAction::DeleteBoard).await
+                    } else {
                         AppReturn::Continue
                     }
-                    _ => {
-                        if !UiMode::view_modes().contains(&app.state.ui_mode) {
-                            return AppReturn::Continue;
-                        }
-                        match app.state.focus {
-                            Focus::Body => {
-                                // delete the current card
-                                if let Some(current_board) = app.state.current_board_id {
-                                    // find index of current board id in app.boards
-                                    let index = app
-                                        .boards
-                                        .iter()
-                                        .position(|board| board.id == current_board);
-                                    if let Some(current_card) = app.state.current_card_id {
-                                        let card_index = app.boards[index.unwrap()]
-                                            .cards
-                                            .iter()
-                                            .position(|card| card.id == current_card);
-                                        if let Some(card_index) = card_index {
-                                            let card_name = app.boards[index.unwrap()].cards
-                                                [card_index]
-                                                .name
-                                                .clone();
-                                            app.boards[index.unwrap()].cards.remove(card_index);
-                                            // if index is > 0, set current card to previous card, else set to next card, else set to None
-                                            if card_index > 0 {
-                                                app.state.current_card_id = Some(
-                                                    app.boards[index.unwrap()].cards
-                                                        [card_index - 1]
-                                                        .id,
-                                                );
-                                            } else if !app.boards[index.unwrap()].cards.is_empty() {
-                                                app.state.current_card_id =
-                                                    Some(app.boards[index.unwrap()].cards[0].id);
-                                            } else {
-                                                app.state.current_card_id = None;
-                                            }
-                                            warn!("Deleted card {}", card_name);
-                                            app.send_warning_toast(
-                                                &format!("Deleted card {}", card_name),
-                                                None,
-                                            );
-                                            // remove card_id from app.visible_boards_and_cards if it is there, where visible_boards_and_cards is a LinkedHashMap of board_id to a vector of card_ids
-                                            if let Some(visible_cards) =
-                                                app.visible_boards_and_cards.get_mut(&current_board)
-                                            {
-                                                if let Some(card_index) = visible_cards
-                                                    .iter()
-                                                    .position(|card_id| *card_id == current_card)
-                                                {
-                                                    visible_cards.remove(card_index);
-                                                }
-                                            }
-                                            app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
-                                        }
-                                    } else if let Some(current_board) = app.state.current_board_id {
-                                        // find index of current board id in app.boards
-                                        let index = app
-                                            .boards
-                                            .iter()
-                                            .position(|board| board.id == current_board);
-                                        if let Some(index) = index {
-                                            let board_name = app.boards[index].name.clone();
-                                            app.boards.remove(index);
-                                            // if index is > 0, set current board to previous board, else set to next board, else set to None
-                                            if index > 0 {
-                                                app.state.current_board_id =
-                                                    Some(app.boards[index - 1].id);
-                                            } else if app.boards.is_empty() {
-                                                app.state.current_board_id = Some(app.boards[0].id);
-                                            } else {
-                                                app.state.current_board_id = None;
-                                            }
-                                            warn!("Deleted board {}", board_name);
-                                            app.send_warning_toast(
-                                                &format!("Deleted board {}", board_name),
-                                                None,
-                                            );
-                                            // remove board_id from app.visible_boards_and_cards if it is there
-                                            app.visible_boards_and_cards.remove(&current_board);
-                                            app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
-                                        }
-                                    }
-                                }
-                                AppReturn::Continue
-                            }
-                            _ => AppReturn::Continue,
-                        }
-                    }
                 }
-            }
+            },
             Action::DeleteBoard => {
-                if !UiMode::view_modes().contains(&app.state.ui_mode) {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.focus != Focus::Body
+                    || app.state.current_board_id.is_none()
+                {
                     return AppReturn::Continue;
                 }
-                match app.state.focus {
-                    Focus::Body => {
-                        // delete the current board from app.boards
-                        if let Some(current_board) = app.state.current_board_id {
-                            // find index of current board id in app.boards
-                            let index = app
-                                .boards
-                                .iter()
-                                .position(|board| board.id == current_board);
-                            if let Some(index) = index {
-                                let board_name = app.boards[index].name.clone();
-                                app.boards.remove(index);
-                                // if index is > 0, set current board to previous board, else set to next board, else set to None
-                                if index > 0 {
-                                    app.state.current_board_id = Some(app.boards[index - 1].id);
-                                } else if index < app.boards.len() {
-                                    app.state.current_board_id = Some(app.boards[index].id);
-                                } else {
-                                    app.state.current_board_id = None;
-                                }
-                                app.visible_boards_and_cards.remove(&current_board);
-                                warn!("Deleted board: {}", board_name);
-                                app.send_warning_toast(
-                                    &format!("Deleted board: {}", board_name),
-                                    None,
-                                );
-                            }
-                        }
-                        AppReturn::Continue
-                    }
-                    _ => AppReturn::Continue,
+                request_destructive_action(app, DestructiveAction::DeleteBoard).await
+            }
+            Action::ArchiveCard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.focus != Focus::Body
+                    || app.state.popup_mode.is_some()
+                {
+                    return AppReturn::Continue;
+                }
+                if app.state.current_card_id.is_some() {
+                    handle_archive_card(app).await;
+                } else if app.state.current_board_id.is_some() {
+                    handle_archive_board(app).await;
+                }
+                AppReturn::Continue
+            }
+            Action::OpenInEditor => {
+                if app.state.popup_mode == Some(PopupMode::ViewCard)
+                    || (app.state.popup_mode.is_none()
+                        && UiMode::view_modes().contains(&app.state.ui_mode)
+                        && app.state.focus == Focus::Body)
+                {
+                    return handle_open_in_editor(app);
+                }
+                AppReturn::Continue
+            }
+            Action::ArchiveBoard => {
+                if !UiMode::view_modes().contains(&app.state.ui_mode)
+                    || app.state.focus != Focus::Body
+                    || app.state.popup_mode.is_some()
+                    || app.state.current_board_id.is_none()
+                {
+                    return AppReturn::Continue;
                 }
+                handle_archive_board(app).await;
+                AppReturn::Continue
             }
             Action::ChangeCardStatusToCompleted => {
                 if !UiMode::view_modes().contains(&app.state.ui_mode) {
@@ -2773,21 +2118,39 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             .iter()
                             .position(|card| card.id == current_card);
                         if let Some(card_index) = card_index {
-                            app.boards[index.unwrap()].cards[card_index].card_status =
-                                CardStatus::Complete;
-                            app.boards[index.unwrap()].cards[card_index].date_completed =
-                                Utc::now().to_string();
-                            info!(
-                                "Changed status to Completed for card {}",
-                                app.boards[index.unwrap()].cards[card_index].name
-                            );
-                            app.send_info_toast(
-                                &format!(
-                                    "Changed status to Completed for card {}",
-                                    app.boards[index.unwrap()].cards[card_index].name
-                                ),
-                                None,
-                            );
+                            let board = &mut app.boards[index.unwrap()];
+                            if let Some(workflow_status) =
+                                board.workflow_status_of_kind(&CardStatus::Complete)
+                            {
+                                board.cards[card_index].set_status(&workflow_status);
+                                let card = board.cards[card_index].clone();
+                                info!(
+                                    "Changed status to {} for card {}",
+                                    workflow_status, card.name
+                                );
+                                app.send_info_toast(
+                                    &format!(
+                                        "Changed status to {} for card {}",
+                                        workflow_status, card.name
+                                    ),
+                                    None,
+                                );
+                                warn_about_open_blockers(app, &card);
+                                handle_recurring_cards(app);
+                            } else {
+                                let board_name = board.name.clone();
+                                warn!(
+                                    "Board {} has no Complete status in its workflow",
+                                    board_name
+                                );
+                                app.send_warning_toast(
+                                    &format!(
+                                        "Board {} has no Complete status in its workflow",
+                                        board_name
+                                    ),
+                                    None,
+                                );
+                            }
                         }
                     }
                 }
@@ -2814,21 +2177,34 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             .iter()
                             .position(|card| card.id == current_card);
                         if let Some(card_index) = card_index {
-                            app.boards[index.unwrap()].cards[card_index].card_status =
-                                CardStatus::Active;
-                            app.boards[index.unwrap()].cards[card_index].date_completed =
-                                "N/A".to_string();
-                            info!(
-                                "Changed status to Active for card {}",
-                                app.boards[index.unwrap()].cards[card_index].name
-                            );
-                            app.send_info_toast(
-                                &format!(
-                                    "Changed status to Active for card {}",
-                                    app.boards[index.unwrap()].cards[card_index].name
-                                ),
-                                None,
-                            );
+                            let board = &mut app.boards[index.unwrap()];
+                            if let Some(workflow_status) =
+                                board.workflow_status_of_kind(&CardStatus::Active)
+                            {
+                                board.cards[card_index].set_status(&workflow_status);
+                                let card_name = board.cards[card_index].name.clone();
+                                info!(
+                                    "Changed status to {} for card {}",
+                                    workflow_status, card_name
+                                );
+                                app.send_info_toast(
+                                    &format!(
+                                        "Changed status to {} for card {}",
+                                        workflow_status, card_name
+                                    ),
+                                    None,
+                                );
+                            } else {
+                                let board_name = board.name.clone();
+                                warn!("Board {} has no Active status in its workflow", board_name);
+                                app.send_warning_toast(
+                                    &format!(
+                                        "Board {} has no Active status in its workflow",
+                                        board_name
+                                    ),
+                                    None,
+                                );
+                            }
                         }
                     }
                 }
@@ -2855,21 +2231,34 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             .iter()
                             .position(|card| card.id == current_card);
                         if let Some(card_index) = card_index {
-                            app.boards[index.unwrap()].cards[card_index].card_status =
-                                CardStatus::Stale;
-                            app.boards[index.unwrap()].cards[card_index].date_completed =
-                                "N/A".to_string();
-                            info!(
-                                "Changed status to Stale for card {}",
-                                app.boards[index.unwrap()].cards[card_index].name
-                            );
-                            app.send_info_toast(
-                                &format!(
-                                    "Changed status to Stale for card {}",
-                                    app.boards[index.unwrap()].cards[card_index].name
-                                ),
-                                None,
-                            );
+                            let board = &mut app.boards[index.unwrap()];
+                            if let Some(workflow_status) =
+                                board.workflow_status_of_kind(&CardStatus::Stale)
+                            {
+                                board.cards[card_index].set_status(&workflow_status);
+                                let card_name = board.cards[card_index].name.clone();
+                                info!(
+                                    "Changed status to {} for card {}",
+                                    workflow_status, card_name
+                                );
+                                app.send_info_toast(
+                                    &format!(
+                                        "Changed status to {} for card {}",
+                                        workflow_status, card_name
+                                    ),
+                                    None,
+                                );
+                            } else {
+                                let board_name = board.name.clone();
+                                warn!("Board {} has no Stale status in its workflow", board_name);
+                                app.send_warning_toast(
+                                    &format!(
+                                        "Board {} has no Stale status in its workflow",
+                                        board_name
+                                    ),
+                                    None,
+                                );
+                            }
                         }
                     }
                 }
@@ -2911,22 +2300,29 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             debug!("Cannot move card up without a current board index");
                             return AppReturn::Continue;
                         }
-                        let current_card_index_in_all = app.boards
-                            [current_board_index_in_all_boards.unwrap()]
-                        .cards
-                        .iter()
-                        .position(|card| card.id == current_card_id);
-                        if current_card_index_in_all.is_none() {
-                            debug!("Cannot move card up without a current card index");
+                        let current_board_index_in_all_boards =
+                            current_board_index_in_all_boards.unwrap();
+                        // archived and filtered out cards are skipped, swap with the card shown above
+                        let card_above_id =
+                            neighbouring_card_id(app, current_board_id, current_card_id, true);
+                        if card_above_id.is_none() {
+                            let message = format!(
+                                "Cannot move card up, it is already at the top of the {}",
+                                card_move_boundary(app)
+                            );
+                            app.send_error_toast(&message, None);
+                            error!("{}", message);
                             return AppReturn::Continue;
                         }
-                        let current_card_index_in_all = current_card_index_in_all.unwrap();
-                        if current_card_index_in_all == 0 {
-                            app.send_error_toast(
-                                "Cannot move card up, it is already at the top of the board",
-                                None,
-                            );
-                            error!("Cannot move card up, it is already at the top of the board");
+                        let card_above_id = card_above_id.unwrap();
+                        let cards = &app.boards[current_board_index_in_all_boards].cards;
+                        let current_card_index_in_all =
+                            cards.iter().position(|card| card.id == current_card_id);
+                        let card_above_index_in_all =
+                            cards.iter().position(|card| card.id == card_above_id);
+                        if current_card_index_in_all.is_none() || card_above_index_in_all.is_none()
+                        {
+                            debug!("Cannot move card up without a current card index");
                             return AppReturn::Continue;
                         }
                         // update visible boards and cards
@@ -2943,10 +2339,6 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         }
                         let current_card_index_in_visible = current_card_index_in_visible.unwrap();
                         if current_card_index_in_visible == 0 {
-                            let card_above_id = app.boards
-                                [current_board_index_in_all_boards.unwrap()]
-                            .cards[current_card_index_in_all - 1]
-                                .id;
                             let mut visible_cards: Vec<u128> = vec![];
                             visible_cards.push(current_card_id);
                             visible_cards.push(card_above_id);
@@ -2970,9 +2362,10 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                     current_card_index_in_visible - 1,
                                 );
                         }
-                        app.boards[current_board_index_in_all_boards.unwrap()]
-                            .cards
-                            .swap(current_card_index_in_all, current_card_index_in_all - 1);
+                        app.boards[current_board_index_in_all_boards].cards.swap(
+                            current_card_index_in_all.unwrap(),
+                            card_above_index_in_all.unwrap(),
+                        );
                     }
                 }
                 AppReturn::Continue
@@ -3003,29 +2396,29 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             debug!("Cannot move card down without a current board index");
                             return AppReturn::Continue;
                         }
-                        let current_card_index_in_all = app.boards
-                            [current_board_index_in_all_boards.unwrap()]
-                        .cards
-                        .iter()
-                        .position(|card| card.id == current_card_id);
-                        if current_card_index_in_all.is_none() {
-                            debug!("Cannot move card down without a current card index");
+                        let current_board_index_in_all_boards =
+                            current_board_index_in_all_boards.unwrap();
+                        // archived and filtered out cards are skipped, swap with the card shown below
+                        let card_below_id =
+                            neighbouring_card_id(app, current_board_id, current_card_id, false);
+                        if card_below_id.is_none() {
+                            let message = format!(
+                                "Cannot move card down, it is already at the bottom of the {}",
+                                card_move_boundary(app)
+                            );
+                            app.send_error_toast(&message, None);
+                            error!("{}", message);
                             return AppReturn::Continue;
                         }
-                        let current_card_index_in_all = current_card_index_in_all.unwrap();
-                        if current_card_index_in_all
-                            == app.boards[current_board_index_in_all_boards.unwrap()]
-                                .cards
-                                .len()
-                                - 1
+                        let card_below_id = card_below_id.unwrap();
+                        let cards = &app.boards[current_board_index_in_all_boards].cards;
+                        let current_card_index_in_all =
+                            cards.iter().position(|card| card.id == current_card_id);
+                        let card_below_index_in_all =
+                            cards.iter().position(|card| card.id == card_below_id);
+                        if current_card_index_in_all.is_none() || card_below_index_in_all.is_none()
                         {
-                            app.send_error_toast(
-                                "Cannot move card down, it is already at the bottom of the board",
-                                None,
-                            );
-                            error!(
-                                "Cannot move card down, it is already at the bottom of the board"
-                            );
+                            debug!("Cannot move card down without a current card index");
                             return AppReturn::Continue;
                         }
                         // update visible boards and cards
@@ -3042,10 +2435,6 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         if current_card_index_in_visible
                             == app.visible_boards_and_cards[&current_board_id].len() - 1
                         {
-                            let card_below_id = app.boards
-                                [current_board_index_in_all_boards.unwrap()]
-                            .cards[current_card_index_in_all + 1]
-                                .id;
                             let mut visible_cards: Vec<u128> = vec![];
                             visible_cards.push(card_below_id);
                             visible_cards.push(current_card_id);
@@ -3071,9 +2460,10 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                     current_card_index_in_visible + 1,
                                 );
                         }
-                        app.boards[current_board_index_in_all_boards.unwrap()]
-                            .cards
-                            .swap(current_card_index_in_all, current_card_index_in_all + 1);
+                        app.boards[current_board_index_in_all_boards].cards.swap(
+                            current_card_index_in_all.unwrap(),
+                            card_below_index_in_all.unwrap(),
+                        );
                     }
                 }
                 AppReturn::Continue
@@ -3099,17 +2489,32 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             return AppReturn::Continue;
                         }
                         let moved_from_board_index = moved_from_board_index.unwrap();
-                        // check if board is the last board
-                        if moved_from_board_index < app.boards.len() - 1 {
-                            let moved_to_board_index = moved_from_board_index + 1;
+                        // archived boards are skipped, check if board is the last board
+                        let moved_to_board_index = app
+                            .boards
+                            .iter()
+                            .enumerate()
+                            .skip(moved_from_board_index + 1)
+                            .find(|(_, board)| !board.archived)
+                            .map(|(index, _)| index);
+                        if let Some(moved_to_board_index) = moved_to_board_index {
+                            if !check_wip_limit(app, moved_to_board_index) {
+                                return AppReturn::Continue;
+                            }
                             if let Some(current_card) = app.state.current_card_id {
                                 let card_index = app.boards[moved_from_board_index]
                                     .cards
                                     .iter()
                                     .position(|card| card.id == current_card);
                                 if let Some(card_index) = card_index {
-                                    let card =
+                                    let mut card =
                                         app.boards[moved_from_board_index].cards.remove(card_index);
+                                    card.log_activity(format!(
+                                        "Moved from board '{}' to board '{}'",
+                                        app.boards[moved_from_board_index].name,
+                                        app.boards[moved_to_board_index].name
+                                    ));
+                                    app.boards[moved_to_board_index].adopt_card_status(&mut card);
                                     let card_id = card.id;
                                     let card_name = card.name.clone();
                                     app.boards[moved_to_board_index].cards.push(card);
@@ -3130,7 +2535,11 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                     // set the visible cards to the last no_of_cards_to_show cards
                                     let mut moved_to_board_visible_cards: Vec<u128> = vec![];
                                     let mut moved_from_board_visible_cards: Vec<u128> = vec![];
-                                    for card in app.boards[moved_to_board_index].cards.iter().rev()
+                                    for card in app.boards[moved_to_board_index]
+                                        .cards
+                                        .iter()
+                                        .filter(|card| !card.archived)
+                                        .rev()
                                     {
                                         if moved_to_board_visible_cards.len()
                                             < app.config.no_of_cards_to_show as usize
@@ -3138,8 +2547,11 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                             moved_to_board_visible_cards.insert(0, card.id);
                                         }
                                     }
-                                    for card in
-                                        app.boards[moved_from_board_index].cards.iter().rev()
+                                    for card in app.boards[moved_from_board_index]
+                                        .cards
+                                        .iter()
+                                        .filter(|card| !card.archived)
+                                        .rev()
                                     {
                                         if moved_from_board_visible_cards.len()
                                             < app.config.no_of_cards_to_show as usize
@@ -3203,17 +2615,28 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                             return AppReturn::Continue;
                         }
                         let moved_from_board_index = moved_from_board_index.unwrap();
-                        let moved_to_board_index = moved_from_board_index - 1;
-                        // check if board is the first board
-                        if moved_from_board_index > 0 {
+                        // archived boards are skipped, check if board is the first board
+                        let moved_to_board_index = app.boards[..moved_from_board_index]
+                            .iter()
+                            .rposition(|board| !board.archived);
+                        if let Some(moved_to_board_index) = moved_to_board_index {
+                            if !check_wip_limit(app, moved_to_board_index) {
+                                return AppReturn::Continue;
+                            }
                             if let Some(current_card) = app.state.current_card_id {
                                 let card_index = app.boards[moved_from_board_index]
                                     .cards
                                     .iter()
                                     .position(|card| card.id == current_card);
                                 if let Some(card_index) = card_index {
-                                    let card =
+                                    let mut card =
                                         app.boards[moved_from_board_index].cards.remove(card_index);
+                                    card.log_activity(format!(
+                                        "Moved from board '{}' to board '{}'",
+                                        app.boards[moved_from_board_index].name,
+                                        app.boards[moved_to_board_index].name
+                                    ));
+                                    app.boards[moved_to_board_index].adopt_card_status(&mut card);
                                     let card_id = card.id;
                                     let card_name = card.name.clone();
                                     app.boards[moved_to_board_index].cards.push(card);
@@ -3234,7 +2657,11 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                     // set the visible cards to the last no_of_cards_to_show cards
                                     let mut moved_to_board_visible_cards: Vec<u128> = vec![];
                                     let mut moved_from_board_visible_cards: Vec<u128> = vec![];
-                                    for card in app.boards[moved_to_board_index].cards.iter().rev()
+                                    for card in app.boards[moved_to_board_index]
+                                        .cards
+                                        .iter()
+                                        .filter(|card| !card.archived)
+                                        .rev()
                                     {
                                         if moved_to_board_visible_cards.len()
                                             < app.config.no_of_cards_to_show as usize
@@ -3242,8 +2669,11 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                                             moved_to_board_visible_cards.insert(0, card.id);
                                         }
                                     }
-                                    for card in
-                                        app.boards[moved_from_board_index].cards.iter().rev()
+                                    for card in app.boards[moved_from_board_index]
+                                        .cards
+                                        .iter()
+                                        .filter(|card| !card.archived)
+                                        .rev()
                                     {
                                         if moved_from_board_visible_cards.len()
                                             < app.config.no_of_cards_to_show as usize
@@ -3294,7 +2724,7 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                         PopupMode::CommandPalette => {
                             app.state.popup_mode = None;
                             app.state.current_user_input = String::new();
-                            app.state.current_cursor_position = None;
+                            app.state.text_input.reset();
                             app.state.app_status = AppStatus::Initialized;
                         }
                         PopupMode::ViewCard => {
@@ -3335,6 +2765,50 @@ pub async fn handle_general_actions(app: &mut App, key: Key) -> AppReturn {
                 info!("Cleared toast messages");
                 AppReturn::Continue
             }
+            Action::SearchCards => {
+                if app.state.popup_mode.is_none()
+                    && UiMode::view_modes().contains(&app.state.ui_mode)
+                {
+                    open_card_search(app);
+                }
+                AppReturn::Continue
+            }
+            Action::FilterCards => {
+                if app.state.popup_mode.is_none()
+                    && UiMode::view_modes().contains(&app.state.ui_mode)
+                {
+                    open_filter_cards(app);
+                }
+                AppReturn::Continue
+            }
+            Action::SortCards => {
+                if app.state.popup_mode.is_none()
+                    && UiMode::view_modes().contains(&app.state.ui_mode)
+                {
+                    open_sort_cards(app);
+                }
+                AppReturn::Continue
+            }
+            Action::Undo => {
+                if app.state.popup_mode.is_none() {
+                    handle_undo(app);
+                }
+                AppReturn::Continue
+            }
+            Action::Redo => {
+                if app.state.popup_mode.is_none() {
+                    handle_redo(app);
+                }
+                AppReturn::Continue
+            }
+            Action::QuickAddCard => {
+                if app.state.popup_mode.is_none()
+                    && UiMode::view_modes().contains(&app.state.ui_mode)
+                {
+                    open_quick_add_card(app);
+                }
+                AppReturn::Continue
+            }
         }
     } else {
         warn!("No action accociated to {}", key);
@@ -3356,8 +2830,16 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
             app.state.current_mouse_coordinates = (x, y);
         }
         Mouse::Drag(x, y) => {
-            // TODO: handle mouse drag features
-            debug!("Mouse drag at {}, {}", x, y);
+            app.state.current_mouse_coordinates = (x, y);
+            if app.state.dragged_card.is_none()
+                && app.state.popup_mode.is_none()
+                && UiMode::view_modes().contains(&app.state.ui_mode)
+            {
+                if let Some((board_id, Some(card_id))) = app.state.hovered_board_and_card {
+                    debug!("Started dragging card {}", card_id);
+                    app.state.dragged_card = Some((board_id, card_id));
+                }
+            }
         }
         Mouse::LeftPress => left_button_pressed = true,
         Mouse::RightPress => right_button_pressed = true,
@@ -3371,7 +2853,22 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
     if let Mouse::Move(x, y) = mouse_action {
         app.state.current_mouse_coordinates = (x, y);
     }
+    if app.state.dragged_card.is_some() {
+        if left_button_pressed {
+            handle_card_drop(app);
+            return AppReturn::Continue;
+        } else if right_button_pressed {
+            app.state.dragged_card = None;
+            return AppReturn::Continue;
+        }
+    }
     if right_button_pressed {
+        if app.state.popup_mode.is_none() && UiMode::view_modes().contains(&app.state.ui_mode) {
+            if let Some((board_id, card_id)) = app.state.hovered_board_and_card {
+                open_context_menu(app, board_id, card_id);
+                return AppReturn::Continue;
+            }
+        }
         return handle_go_to_previous_ui_mode(app);
     }
 
@@ -3383,7 +2880,7 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                 PopupMode::CommandPalette => {
                     app.state.popup_mode = None;
                     app.state.current_user_input = String::new();
-                    app.state.current_cursor_position = None;
+                    app.state.text_input.reset();
                     app.state.app_status = AppStatus::Initialized;
                 }
                 PopupMode::ViewCard => {
@@ -3434,6 +2931,117 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                     }
                 }
             }
+            PopupMode::CardSearch => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CardSearch) {
+                        return handle_card_search_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::LinkCard => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CardSearch) {
+                        return handle_link_card_selection(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        if app.card_being_edited.is_some() {
+                            app.state.popup_mode = Some(PopupMode::ViewCard);
+                        } else {
+                            app.state.popup_mode = None;
+                            app.state.app_status = AppStatus::Initialized;
+                        }
+                    }
+                }
+            }
+            PopupMode::FilterCards => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SavedFilters) {
+                        return handle_filter_cards_submit(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SaveFilterPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        return handle_save_filter(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SwitchWorkspace => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::WorkspaceList) {
+                        return handle_switch_workspace(app).await;
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SetWipLimitPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        return handle_set_wip_limit(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::SetWorkflowPrompt => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        return handle_set_workflow(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::ImportFromJSONPrompt(replace) => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        return handle_import_from_json(app, replace);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::QuickAddCard => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SubmitButton) {
+                        return handle_quick_add_card(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                        app.state.app_status = AppStatus::Initialized;
+                    }
+                }
+            }
+            PopupMode::ContextMenu => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::ContextMenu) {
+                        return handle_context_menu_selection(app).await;
+                    }
+                    app.state.popup_mode = None;
+                }
+            }
+            PopupMode::SortCards => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::SortCardsPopup) {
+                        return handle_sort_cards(app);
+                    } else if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        app.state.popup_mode = None;
+                    }
+                }
+            }
             PopupMode::SelectDefaultView => {
                 if left_button_pressed {
                     if app.state.mouse_focus == Some(Focus::SelectDefaultView) {
@@ -3541,7 +3149,7 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                     } else if app.state.mouse_focus == Some(Focus::TextInput) {
                         app.state.app_status = AppStatus::UserInput;
                         app.state.current_user_input = String::new();
-                        app.state.current_cursor_position = None;
+                        app.state.text_input.reset();
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
                         app.state.popup_mode = None;
                     }
@@ -3554,7 +3162,7 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                     } else if app.state.mouse_focus == Some(Focus::TextInput) {
                         app.state.app_status = AppStatus::UserInput;
                         app.state.current_user_input = String::new();
-                        app.state.current_cursor_position = None;
+                        app.state.text_input.reset();
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
                         app.state.popup_mode = None;
                         app.state.app_status = AppStatus::Initialized;
@@ -3574,6 +3182,8 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                         Focus::CardDescription
                         | Focus::CardTags
                         | Focus::CardComments
+                        | Focus::CardChecklist
+                        | Focus::CardLinks
                         | Focus::CardDueDate => return handle_edit_new_card(app),
                         Focus::CardPriority => {
                             if app.card_being_edited.is_none() {
@@ -3582,6 +3192,7 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                             app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
                             return AppReturn::Continue;
                         }
+                        Focus::CardRecurrence => return open_card_recurrence_selector(app),
                         Focus::CardStatus => {
                             if app.card_being_edited.is_none() {
                                 handle_edit_new_card(app);
@@ -3608,6 +3219,22 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                     }
                 }
             }
+            PopupMode::CardRecurrenceSelector => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::CloseButton => {
+                            app.state.app_status = AppStatus::Initialized;
+                            if app.card_being_edited.is_some() {
+                                app.state.popup_mode = Some(PopupMode::ConfirmDiscardCardChanges);
+                            }
+                        }
+                        Focus::ChangeCardRecurrencePopup => {
+                            return handle_change_card_recurrence(app)
+                        }
+                        _ => {}
+                    }
+                }
+            }
             PopupMode::ConfirmDiscardCardChanges => {
                 if left_button_pressed && app.state.mouse_focus.is_some() {
                     match app.state.focus {
@@ -3659,12 +3286,26 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                     }
                 }
             }
-        }
-    } else {
-        match app.state.ui_mode {
-            UiMode::Zen => {
-                if left_button_pressed {
-                    if app.state.mouse_focus == Some(Focus::Body) {
+            PopupMode::ConfirmDestructiveAction(action) => {
+                if left_button_pressed && app.state.mouse_focus.is_some() {
+                    match app.state.mouse_focus.unwrap() {
+                        Focus::SubmitButton => {
+                            return handle_destructive_action_confirmation(app, action, true).await;
+                        }
+                        Focus::ExtraFocus | Focus::CloseButton => {
+                            return handle_destructive_action_confirmation(app, action, false)
+                                .await;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    } else {
+        match app.state.ui_mode {
+            UiMode::Zen => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::Body) {
                         app.state.popup_mode = Some(PopupMode::ViewCard);
                     } else if app.state.mouse_focus == Some(Focus::CloseButton) {
                         return AppReturn::Exit;
@@ -3858,7 +3499,7 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                         || app.state.mouse_focus == Some(Focus::SubmitButton)
                         || app.state.mouse_focus == Some(Focus::ExtraFocus)
                     {
-                        return handle_config_menu_action(app);
+                        return handle_config_menu_action(app).await;
                     } else if app.state.mouse_focus == Some(Focus::Log) {
                         app.state.ui_mode = UiMode::LogsOnly;
                         app.state.prev_ui_mode = Some(UiMode::ConfigMenu);
@@ -3986,6 +3627,17 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
                     }
                 }
             }
+            UiMode::Archive => {
+                if left_button_pressed {
+                    if app.state.mouse_focus == Some(Focus::CloseButton) {
+                        handle_go_to_prv_ui_mode(app);
+                    } else if app.state.mouse_focus == Some(Focus::ArchivedItems)
+                        && app.state.archive_list_state.selected().is_some()
+                    {
+                        return handle_restore_archived_item(app);
+                    }
+                }
+            }
             UiMode::LoadSave => {
                 if left_button_pressed {
                     if app.state.mouse_focus == Some(Focus::CloseButton) {
@@ -4012,55 +3664,11 @@ pub async fn handle_mouse_action(app: &mut App, mouse_action: Mouse) -> AppRetur
     AppReturn::Continue
 }
 
-fn handle_config_menu_action(app: &mut App) -> AppReturn {
+async fn handle_config_menu_action(app: &mut App) -> AppReturn {
     if app.state.focus == Focus::SubmitButton {
-        app.config = AppConfig::default();
-        app.state.focus = Focus::ConfigTable;
-        app.state.config_state.select(Some(0));
-        let write_config_status = write_config(&app.config);
-        if write_config_status.is_err() {
-            error!(
-                "Error writing config file: {}",
-                write_config_status.clone().unwrap_err()
-            );
-            app.send_error_toast(
-                &format!(
-                    "Error writing config file: {}",
-                    write_config_status.unwrap_err()
-                ),
-                None,
-            );
-        } else {
-            warn!("Reset Config and Keybinds to default");
-            app.send_warning_toast("Reset Config and Keybinds to default", None);
-        }
-        app.keybind_list_maker();
-        return AppReturn::Continue;
+        return request_destructive_action(app, DestructiveAction::ResetConfigAndKeybindings).await;
     } else if app.state.focus == Focus::ExtraFocus {
-        // make a copy of the keybinds and reset only config and then write the config
-        let keybinds = app.config.keybindings.clone();
-        app.config = AppConfig::default();
-        app.config.keybindings = keybinds;
-        app.state.focus = Focus::ConfigTable;
-        app.state.config_state.select(Some(0));
-        let write_config_status = write_config(&app.config);
-        if write_config_status.is_err() {
-            error!(
-                "Error writing config file: {}",
-                write_config_status.clone().unwrap_err()
-            );
-            app.send_error_toast(
-                &format!(
-                    "Error writing config file: {}",
-                    write_config_status.unwrap_err()
-                ),
-                None,
-            );
-        } else {
-            warn!("Reset Config to default");
-            app.send_warning_toast("Reset Config to default", None);
-        }
-        return AppReturn::Continue;
+        return request_destructive_action(app, DestructiveAction::ResetConfig).await;
     }
     app.config_item_being_edited = Some(app.state.config_state.selected().unwrap_or(0));
     // check if the config_item_being_edited index is in the AppConfig list and the value in the list is Edit Keybindings
@@ -4152,6 +3760,46 @@ fn handle_config_menu_action(app: &mut App) -> AppReturn {
             } else {
                 app.send_info_toast("Config updated Successfully", None);
             }
+        } else if *config_item == "Skip Confirmation Dialogs" {
+            let skip_confirmation_dialogs = app.config.skip_confirmation_dialogs;
+            app.config.skip_confirmation_dialogs = !skip_confirmation_dialogs;
+            let config_string = format!(
+                "{}: {}",
+                "Skip Confirmation Dialogs", app.config.skip_confirmation_dialogs
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            let write_config_status = write_config(&app_config);
+            if write_config_status.is_err() {
+                error!(
+                    "Error writing config file: {}",
+                    write_config_status.clone().unwrap_err()
+                );
+                app.send_error_toast(
+                    &format!(
+                        "Error writing config file: {}",
+                        write_config_status.unwrap_err()
+                    ),
+                    None,
+                );
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+            }
+        } else if *config_item == "Enforce WIP Limits" {
+            let enforce_wip_limits = app.config.enforce_wip_limits;
+            app.config.enforce_wip_limits = !enforce_wip_limits;
+            let config_string = format!(
+                "{}: {}",
+                "Enforce WIP Limits", app.config.enforce_wip_limits
+            );
+            let app_config = AppConfig::edit_with_string(&config_string, app);
+            app.config = app_config.clone();
+            if let Err(e) = write_config(&app_config) {
+                error!("Error writing config file: {}", e);
+                app.send_error_toast(&format!("Error writing config file: {}", e), None);
+            } else {
+                app.send_info_toast("Config updated Successfully", None);
+            }
         } else if *config_item == "Enable Mouse Support" {
             let enable_mouse_support = app.config.enable_mouse_support;
             app.config.enable_mouse_support = !enable_mouse_support;
@@ -4218,6 +3866,8 @@ fn handle_main_menu_action(app: &mut App) -> AppReturn {
                 app.state.prev_ui_mode = Some(UiMode::MainMenu);
                 app.state.ui_mode = UiMode::LoadSave;
             }
+            MainMenuItem::Archive => open_archive(app),
+            MainMenuItem::Workspaces => open_workspace_switcher(app),
         }
     }
     AppReturn::Continue
@@ -4318,7 +3968,7 @@ fn handle_go_to_previous_ui_mode(app: &mut App) -> AppReturn {
                     }
                 }
                 app.state.current_user_input = String::new();
-                app.state.current_cursor_position = None;
+                app.state.text_input.reset();
             }
             PopupMode::EditSpecificKeyBinding => {
                 app.state.ui_mode = UiMode::EditKeybindings;
@@ -4360,6 +4010,13 @@ fn handle_go_to_previous_ui_mode(app: &mut App) -> AppReturn {
                 app.state.popup_mode = None;
                 app.card_being_edited = None;
             }
+            PopupMode::ConfirmDestructiveAction(action) => {
+                info!("Cancelled {}", action);
+                app.send_info_toast(&format!("Cancelled {}", action), None);
+                if let Some(previous_focus) = app.state.previous_focus.take() {
+                    app.state.focus = previous_focus;
+                }
+            }
             _ => {}
         }
         app.state.popup_mode = None;
@@ -4414,7 +4071,7 @@ fn handle_go_to_previous_ui_mode(app: &mut App) -> AppReturn {
 
 fn handle_change_card_status(app: &mut App) -> AppReturn {
     let current_index = app.state.card_status_selector_state.selected().unwrap_or(0);
-    let all_statuses = CardStatus::all();
+    let all_statuses = app.current_board_workflow();
 
     let current_index = if current_index >= all_statuses.len() {
         all_statuses.len() - 1
@@ -4424,8 +4081,13 @@ fn handle_change_card_status(app: &mut App) -> AppReturn {
     let selected_status = all_statuses[current_index].clone();
 
     if app.card_being_edited.is_some() {
-        app.card_being_edited.as_mut().unwrap().1.card_status = selected_status;
+        let card_being_edited = &mut app.card_being_edited.as_mut().unwrap().1;
+        card_being_edited.set_status(&selected_status);
+        let card = card_being_edited.clone();
         app.state.popup_mode = Some(PopupMode::ViewCard);
+        if selected_status.kind == CardStatus::Complete {
+            warn_about_open_blockers(app, &card);
+        }
         return AppReturn::Continue;
     } else if let Some(current_board_id) = app.state.current_board_id {
         if let Some(current_board) = app.boards.iter_mut().find(|b| b.id == current_board_id) {
@@ -4435,8 +4097,13 @@ fn handle_change_card_status(app: &mut App) -> AppReturn {
                     .iter_mut()
                     .find(|c| c.id == current_card_id)
                 {
-                    current_card.card_status = selected_status;
+                    current_card.set_status(&selected_status);
+                    let card = current_card.clone();
                     app.state.popup_mode = None;
+                    if selected_status.kind == CardStatus::Complete {
+                        warn_about_open_blockers(app, &card);
+                        handle_recurring_cards(app);
+                    }
                     return AppReturn::Continue;
                 }
             }
@@ -4462,7 +4129,11 @@ fn handle_change_card_priority(app: &mut App) -> AppReturn {
     let selected_priority = all_priorities[current_index].clone();
 
     if app.card_being_edited.is_some() {
-        app.card_being_edited.as_mut().unwrap().1.priority = selected_priority;
+        app.card_being_edited
+            .as_mut()
+            .unwrap()
+            .1
+            .set_priority(selected_priority);
         app.state.popup_mode = Some(PopupMode::ViewCard);
         return AppReturn::Continue;
     } else if let Some(current_board_id) = app.state.current_board_id {
@@ -4473,7 +4144,7 @@ fn handle_change_card_priority(app: &mut App) -> AppReturn {
                     .iter_mut()
                     .find(|c| c.id == current_card_id)
                 {
-                    current_card.priority = selected_priority;
+                    current_card.set_priority(selected_priority);
                     app.state.popup_mode = None;
                     return AppReturn::Continue;
                 }
@@ -4484,6 +4155,47 @@ fn handle_change_card_priority(app: &mut App) -> AppReturn {
     AppReturn::Continue
 }
 
+fn open_card_recurrence_selector(app: &mut App) -> AppReturn {
+    if app.card_being_edited.is_none() {
+        handle_edit_new_card(app);
+    }
+    if let Some((_, card_being_edited)) = app.card_being_edited.as_ref() {
+        let current_index = CardRecurrence::options()
+            .iter()
+            .position(|recurrence| *recurrence == card_being_edited.recurrence);
+        app.state
+            .card_recurrence_selector_state
+            .select(current_index);
+        app.state.popup_mode = Some(PopupMode::CardRecurrenceSelector);
+    }
+    AppReturn::Continue
+}
+
+fn handle_change_card_recurrence(app: &mut App) -> AppReturn {
+    let all_options = CardRecurrence::options();
+    let current_index = app
+        .state
+        .card_recurrence_selector_state
+        .selected()
+        .unwrap_or(0)
+        .min(all_options.len() - 1);
+    let selected_recurrence = all_options[current_index];
+
+    if let Some((_, card_being_edited)) = app.card_being_edited.as_mut() {
+        card_being_edited.set_recurrence(selected_recurrence);
+        if selected_recurrence.is_some() && card_being_edited.date_due.is_none() {
+            app.send_info_toast(
+                "Recurring cards without a due date repeat from their completion date",
+                None,
+            );
+        }
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+        return AppReturn::Continue;
+    }
+    app.send_error_toast("Error Could not find current card", None);
+    AppReturn::Continue
+}
+
 fn handle_edit_general_config(app: &mut App) {
     let config_item_index = app.state.config_state.selected().unwrap_or(0);
     let config_item_list = AppConfig::to_list(&app.config);
@@ -4621,6 +4333,145 @@ fn handle_new_board_action(app: &mut App) {
         .collect();
 }
 
+/// Id of the card shown right above (or below) the given card, archived and filtered out cards are skipped
+/// and with swimlanes only cards of the same lane count
+fn neighbouring_card_id(app: &App, board_id: u128, card_id: u128, above: bool) -> Option<u128> {
+    let board = app
+        .filtered_boards()
+        .into_iter()
+        .find(|board| board.id == board_id)?;
+    let card_index = board.cards.iter().position(|card| card.id == card_id)?;
+    let neighbour_index = if above {
+        card_index.checked_sub(1)?
+    } else {
+        card_index + 1
+    };
+    let lane = app.config.swimlanes.lane(&board.cards[card_index]);
+    board
+        .cards
+        .get(neighbour_index)
+        .filter(|card| app.config.swimlanes.lane(card) == lane)
+        .map(|card| card.id)
+}
+
+/// What a card can not be moved out of with the move card up and down keys
+fn card_move_boundary(app: &App) -> &'static str {
+    if app.config.swimlanes == SwimlaneGrouping::None {
+        "board"
+    } else {
+        "lane"
+    }
+}
+
+/// Warns or refuses (with enforce_wip_limits) when one more card would go over the board's wip_limit,
+/// returns whether the card can be added
+fn check_wip_limit(app: &mut App, board_index: usize) -> bool {
+    let board = &app.boards[board_index];
+    if !board.is_at_wip_limit() {
+        return true;
+    }
+    let message = format!(
+        "Board \"{}\" has reached its WIP limit of {}",
+        board.name, board.wip_limit
+    );
+    if app.config.enforce_wip_limits {
+        error!("{}", message);
+        app.send_error_toast(&message, None);
+        false
+    } else {
+        warn!("{}", message);
+        app.send_warning_toast(&message, None);
+        true
+    }
+}
+
+/// Drops the card being dragged before the hovered card, or at the end of the hovered board
+fn handle_card_drop(app: &mut App) {
+    let (from_board_id, card_id) = if let Some(dragged_card) = app.state.dragged_card.take() {
+        dragged_card
+    } else {
+        return;
+    };
+    let (to_board_id, drop_card_id) =
+        if let Some(hovered_board_and_card) = app.state.hovered_board_and_card {
+            hovered_board_and_card
+        } else {
+            debug!("Card dropped outside of a board");
+            return;
+        };
+    if drop_card_id == Some(card_id) {
+        return;
+    }
+    move_card(app, from_board_id, card_id, to_board_id, drop_card_id);
+}
+
+/// Index to insert a dropped card at, cards must not contain the dropped card anymore
+fn drop_insert_index(cards: &[Card], before_card_id: Option<u128>) -> usize {
+    before_card_id
+        .and_then(|before_card_id| cards.iter().position(|c| c.id == before_card_id))
+        .unwrap_or(cards.len())
+}
+
+/// Moves a card before another card, or to the end of the board when before_card_id is None
+fn move_card(
+    app: &mut App,
+    from_board_id: u128,
+    card_id: u128,
+    to_board_id: u128,
+    before_card_id: Option<u128>,
+) {
+    let from_board_index = app.boards.iter().position(|b| b.id == from_board_id);
+    let to_board_index = app.boards.iter().position(|b| b.id == to_board_id);
+    let (from_board_index, to_board_index) = match (from_board_index, to_board_index) {
+        (Some(from), Some(to)) => (from, to),
+        _ => {
+            app.send_error_toast("Something went wrong, could not find the board", None);
+            debug!("Board of card {} not found", card_id);
+            return;
+        }
+    };
+    let card_index = if let Some(index) = app.boards[from_board_index]
+        .cards
+        .iter()
+        .position(|c| c.id == card_id)
+    {
+        index
+    } else {
+        debug!("Card {} not found", card_id);
+        return;
+    };
+    if from_board_index != to_board_index && !check_wip_limit(app, to_board_index) {
+        return;
+    }
+    let mut card = app.boards[from_board_index].cards.remove(card_index);
+    if from_board_index != to_board_index {
+        card.log_activity(format!(
+            "Moved from board '{}' to board '{}'",
+            app.boards[from_board_index].name, app.boards[to_board_index].name
+        ));
+        app.boards[to_board_index].adopt_card_status(&mut card);
+    }
+    let card_name = card.name.clone();
+    let to_board = &mut app.boards[to_board_index];
+    let insert_index = drop_insert_index(&to_board.cards, before_card_id);
+    to_board.cards.insert(insert_index, card);
+    if from_board_index != to_board_index {
+        info!(
+            "Moved card {} to board \"{}\"",
+            card_name, app.boards[to_board_index].name
+        );
+        app.send_info_toast(
+            &format!(
+                "Moved card {} to board \"{}\"",
+                card_name, app.boards[to_board_index].name
+            ),
+            None,
+        );
+    }
+    refresh_visible_boards_and_cards(app);
+    app.jump_to_card(to_board_id, card_id);
+}
+
 fn handle_new_card_action(app: &mut App) -> AppReturn {
     if app.state.focus == Focus::SubmitButton {
         // check if app.state.new_card_form[0] is not empty or is not the same as any of the existing cards
@@ -4648,56 +4499,39 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
             return AppReturn::Continue;
         }
         // check if due date is empty or is a valid date
-        let due_date = if new_card_due_date.is_empty() {
-            Some(FIELD_NOT_SET.to_string())
-        } else {
-            match NaiveDateTime::parse_from_str(&new_card_due_date, DEFAULT_DATE_FORMAT) {
-                Ok(due_date) => {
-                    debug!("Due date: {}", due_date);
-                    let new_due = due_date.to_string();
-                    // the date is in the format 2023-01-20 14:10:00 convert it to 20/01/2023-14:10:00
-                    let new_due = new_due.replace('-', "/");
-                    let new_due = new_due.replace(' ', "-");
-                    debug!("New due date: {}", new_due);
-                    Some(new_due)
-                }
-                Err(_) => {
-                    // cehck if the user has not put the time if not put the default time
-                    match NaiveDate::parse_from_str(&new_card_due_date, "%d/%m/%Y") {
-                        Ok(due_date) => {
-                            debug!("Due date: {}", due_date);
-                            let new_due = due_date.to_string();
-                            // the date is in the format 2023-01-20 14:10:00 convert it to 20/01/2023-14:10:00
-                            let new_due = new_due.replace('-', "/");
-                            let new_due = new_due.replace(' ', "-");
-                            let new_due = format!("{}-{}", new_due, "12:00:00");
-                            debug!("New due date: {}", new_due);
-                            Some(new_due)
-                        }
-                        Err(e) => {
-                            debug!("Invalid due date: {}", e);
-                            debug!("Due date: {}", new_card_due_date);
-                            None
-                        }
-                    }
-                }
+        let due_date = match CardDate::parse_optional(&new_card_due_date) {
+            Ok(due_date) => due_date,
+            Err(e) => {
+                debug!("{}", e);
+                warn!("Invalid due date");
+                app.send_warning_toast("Invalid due date", None);
+                app.state.ui_mode = *app
+                    .state
+                    .prev_ui_mode
+                    .as_ref()
+                    .unwrap_or(&app.config.default_view);
+                return AppReturn::Continue;
             }
         };
-        if due_date.is_none() {
-            warn!("Invalid due date");
-            app.send_warning_toast("Invalid due date", None);
-            app.state.ui_mode = *app
-                .state
-                .prev_ui_mode
-                .as_ref()
-                .unwrap_or(&app.config.default_view);
-            return AppReturn::Continue;
-        }
         if !new_card_name.is_empty() && !same_name_exists {
-            let new_card = Card::new(
+            let current_board_index = app
+                .boards
+                .iter()
+                .position(|board| board.id == current_board_id);
+            if let Some(current_board_index) = current_board_index {
+                if !check_wip_limit(app, current_board_index) {
+                    app.state.ui_mode = *app
+                        .state
+                        .prev_ui_mode
+                        .as_ref()
+                        .unwrap_or(&app.config.default_view);
+                    return AppReturn::Continue;
+                }
+            }
+            let mut new_card = Card::new(
                 new_card_name,
                 new_card_description,
-                due_date.unwrap(),
+                due_date,
                 CardPriority::Low,
                 vec![],
                 vec![],
@@ -4707,6 +4541,7 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
                 .iter_mut()
                 .find(|board| board.id == current_board_id);
             if let Some(current_board) = current_board {
+                current_board.adopt_card_status(&mut new_card);
                 current_board.cards.push(new_card.clone());
                 app.state.current_card_id = Some(new_card.id);
             } else {
@@ -4742,6 +4577,7 @@ fn handle_new_card_action(app: &mut App) -> AppReturn {
 }
 
 fn scroll_up(app: &mut App) {
+    let boards = app.filtered_boards();
     if app.visible_boards_and_cards.is_empty() {
         refresh_visible_boards_and_cards(app);
         return;
@@ -4751,7 +4587,7 @@ fn scroll_up(app: &mut App) {
         return;
     }
     let current_board_id = app.state.current_board_id.unwrap();
-    let current_board = app.boards.iter().find(|b| b.id == current_board_id);
+    let current_board = boards.iter().find(|b| b.id == current_board_id);
     if current_board.is_none() {
         debug!("No current board found in all boards");
         return;
@@ -4798,6 +4634,7 @@ fn scroll_up(app: &mut App) {
 }
 
 fn scroll_down(app: &mut App) {
+    let boards = app.filtered_boards();
     if app.visible_boards_and_cards.is_empty() {
         refresh_visible_boards_and_cards(app);
         return;
@@ -4807,7 +4644,7 @@ fn scroll_down(app: &mut App) {
         return;
     }
     let current_board_id = app.state.current_board_id.unwrap();
-    let current_board = app.boards.iter().find(|b| b.id == current_board_id);
+    let current_board = boards.iter().find(|b| b.id == current_board_id);
     if current_board.is_none() {
         debug!("No current board found in all boards");
         return;
@@ -4855,6 +4692,7 @@ fn scroll_down(app: &mut App) {
 }
 
 fn scroll_right(app: &mut App) {
+    let boards = app.filtered_boards();
     if app.state.current_board_id.is_none() {
         debug!("No current board id found");
         return;
@@ -4865,24 +4703,18 @@ fn scroll_right(app: &mut App) {
         return;
     }
     let last_board_in_visible = last_board_in_visible.unwrap();
-    let last_board_index = app
-        .boards
-        .iter()
-        .position(|b| b.id == *last_board_in_visible);
+    let last_board_index = boards.iter().position(|b| b.id == *last_board_in_visible);
     if last_board_index.is_none() {
         debug!("No last board index found");
         return;
     }
     let last_board_index = last_board_index.unwrap();
-    if last_board_index == app.boards.len() - 1 {
+    if last_board_index == boards.len() - 1 {
         return;
     }
     let next_board_index = last_board_index + 1;
     // get no_of_cards_to_show cards from the next board and add them to the visible boards
-    let next_board = app
-        .boards
-        .iter()
-        .find(|b| b.id == app.boards[next_board_index].id);
+    let next_board = boards.iter().find(|b| b.id == boards[next_board_index].id);
     if next_board.is_none() {
         debug!("No next board found");
         return;
@@ -4909,6 +4741,7 @@ fn scroll_right(app: &mut App) {
 }
 
 fn scroll_left(app: &mut App) {
+    let boards = app.filtered_boards();
     if app.state.current_board_id.is_none() {
         debug!("No current board id found");
         return;
@@ -4919,10 +4752,7 @@ fn scroll_left(app: &mut App) {
         return;
     }
     let first_board_in_visible = first_board_in_visible.unwrap();
-    let first_board_index = app
-        .boards
-        .iter()
-        .position(|b| b.id == *first_board_in_visible);
+    let first_board_index = boards.iter().position(|b| b.id == *first_board_in_visible);
     if first_board_index.is_none() {
         debug!("No first board index found");
         return;
@@ -4933,10 +4763,9 @@ fn scroll_left(app: &mut App) {
     }
     let previous_board_index = first_board_index - 1;
     // get no_of_cards_to_show cards from the previous board and add them to the visible boards
-    let previous_board = app
-        .boards
+    let previous_board = boards
         .iter()
-        .find(|b| b.id == app.boards[previous_board_index].id);
+        .find(|b| b.id == boards[previous_board_index].id);
     if previous_board.is_none() {
         debug!("No previous board found");
         return;
@@ -5119,7 +4948,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                         app.state.popup_mode = Some(PopupMode::CustomRGBPromptFG);
                         app.state.focus = Focus::TextInput;
                         app.state.current_user_input = String::new();
-                        app.state.current_cursor_position = None;
+                        app.state.text_input.reset();
                         return AppReturn::Continue;
                     }
                     if selected_color_option.to_color().is_some() {
@@ -5180,7 +5009,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
                         app.state.popup_mode = Some(PopupMode::CustomRGBPromptBG);
                         app.state.focus = Focus::TextInput;
                         app.state.current_user_input = String::new();
-                        app.state.current_cursor_position = None;
+                        app.state.text_input.reset();
                         return AppReturn::Continue;
                     }
                     if selected_color_option.to_color().is_some() {
@@ -5267,7 +5096,7 @@ fn handle_create_theme_action(app: &mut App) -> AppReturn {
         if selected_item_index == 0 {
             app.state.popup_mode = Some(PopupMode::EditGeneralConfig);
             app.state.current_user_input = String::new();
-            app.state.current_cursor_position = None;
+            app.state.text_input.reset();
         } else {
             app.state.popup_mode = Some(PopupMode::ThemeEditor);
         }
@@ -5350,7 +5179,7 @@ fn handle_save_theme_prompt(app: &mut App) {
 fn handle_custom_rgb_prompt(app: &mut App, fg: bool) {
     if app.state.focus == Focus::TextInput {
         app.state.current_user_input = String::new();
-        app.state.current_cursor_position = None;
+        app.state.text_input.reset();
         app.state.app_status = AppStatus::UserInput;
     } else if app.state.focus == Focus::SubmitButton {
         // check if the current_user_input is in the format x,y,z where x,y,z are three digit numbers from 0 to 255. use trim to remove whitespace if any
@@ -5633,19 +5462,11 @@ fn handle_edit_new_card(app: &mut App) -> AppReturn {
     if app.card_being_edited.is_some() && app.card_being_edited.as_ref().unwrap().1.id == card.id {
         return AppReturn::Continue;
     }
-    match app.state.focus {
-        Focus::CardDescription => {
-            if !card.description.is_empty() {
-                app.state.current_cursor_position = Some(0);
-            }
-        }
-        Focus::CardDueDate => {
-            if !card.date_due.is_empty() {
-                app.state.current_cursor_position = Some(0);
-            }
-        }
-        _ => {}
-    }
+    app.state.text_input.reset();
+    app.card_due_date_being_edited = card
+        .date_due
+        .map(|date_due| date_due.to_string())
+        .unwrap_or_default();
     app.card_being_edited = Some((app.state.current_board_id.unwrap(), card.clone()));
     info!("Editing Card '{}'", card.name);
     app.send_info_toast(&format!("Editing Card '{}'", card.name), None);
@@ -5679,37 +5500,15 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
     }
     let card = card.unwrap();
     let mut edited_card = app.card_being_edited.as_ref().unwrap().1.clone();
-    let parsed_date_ymd_t =
-        NaiveDateTime::parse_from_str(&edited_card.date_due, "%Y/%m/%d-%H:%M:%S");
-    let parsed_date_ymd = NaiveDate::parse_from_str(&edited_card.date_due, "%Y/%m/%d");
-    let parsed_date_dmy_t =
-        NaiveDateTime::parse_from_str(&edited_card.date_due, DEFAULT_DATE_FORMAT);
-    let parsed_date_dmy = NaiveDate::parse_from_str(&edited_card.date_due, "%d/%m/%Y");
-    let parsed_date = if let Ok(parsed_date_ymd_t) = parsed_date_ymd_t {
-        // convert to DD/MM/YYYY-HH:MM:SS
-        parsed_date_ymd_t.format(DEFAULT_DATE_FORMAT).to_string()
-    } else if let Ok(parsed_date_ymd) = parsed_date_ymd {
-        parsed_date_ymd
-            .and_hms_opt(12, 0, 0)
-            .unwrap()
-            .format(DEFAULT_DATE_FORMAT)
-            .to_string()
-    } else if let Ok(parsed_date_dmy_t) = parsed_date_dmy_t {
-        parsed_date_dmy_t.format(DEFAULT_DATE_FORMAT).to_string()
-    } else if let Ok(parsed_date_dmy) = parsed_date_dmy {
-        parsed_date_dmy
-            .and_hms_opt(12, 0, 0)
-            .unwrap()
-            .format(DEFAULT_DATE_FORMAT)
-            .to_string()
-    } else {
-        if edited_card.date_due != FIELD_NOT_SET {
+    match CardDate::parse_optional(&app.card_due_date_being_edited) {
+        Ok(date_due) => edited_card.date_due = date_due,
+        Err(_) => {
             send_warning_toast = true;
-            warning_date_due = edited_card.date_due.clone();
+            warning_date_due = app.card_due_date_being_edited.clone();
+            edited_card.date_due = None;
         }
-        FIELD_NOT_SET.to_string()
-    };
-    edited_card.date_due = parsed_date;
+    }
+    edited_card.log_edits(card);
     *card = edited_card;
 
     let card_name = card.name.clone();
@@ -5726,12 +5525,1380 @@ fn handle_edit_card_submit(app: &mut App) -> AppReturn {
     }
     app.send_info_toast(&format!("Changes to Card '{}' saved", card_name), None);
     app.state.app_status = AppStatus::Initialized;
+    handle_recurring_cards(app);
     AppReturn::Continue
 }
 
+/// Starts editing the current card if needed and asks the main loop to open it in an external editor
+fn handle_open_in_editor(app: &mut App) -> AppReturn {
+    if app.card_being_edited.is_none() {
+        if app.state.current_card_id.is_none() {
+            app.send_error_toast("No card selected to edit", None);
+            return AppReturn::Continue;
+        }
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+        handle_edit_new_card(app);
+        if app.card_being_edited.is_none() {
+            return AppReturn::Continue;
+        }
+    }
+    AppReturn::OpenInEditor
+}
+
+/// Text of the card being edited for the external editor, only the description when it is focused
+pub fn card_editor_text(app: &App) -> Option<String> {
+    let card = &app.card_being_edited.as_ref()?.1;
+    if app.state.focus == Focus::CardDescription {
+        if card.description == FIELD_NOT_SET {
+            Some(String::new())
+        } else {
+            Some(card.description.clone())
+        }
+    } else {
+        Some(card.to_editor_text(&app.card_due_date_being_edited))
+    }
+}
+
+/// Applies the text saved in the external editor to the card being edited, the changes still
+/// have to be submitted from the card view
+pub fn handle_card_editor_result(app: &mut App, result: Result<String, String>) {
+    let text = match result {
+        Ok(text) => text,
+        Err(e) => {
+            error!("Could not edit the card in the external editor: {}", e);
+            app.send_error_toast(
+                &format!("Could not edit the card in the external editor: {}", e),
+                None,
+            );
+            return;
+        }
+    };
+    let description_only = app.state.focus == Focus::CardDescription;
+    let card = if let Some((_, card)) = app.card_being_edited.as_mut() {
+        card
+    } else {
+        return;
+    };
+    if description_only {
+        let description = text.trim_end();
+        card.description = if description.is_empty() {
+            FIELD_NOT_SET.to_string()
+        } else {
+            description.to_string()
+        };
+    } else {
+        match card.apply_editor_text(&text) {
+            Ok(date_due) => app.card_due_date_being_edited = date_due,
+            Err(e) => {
+                error!("Could not read the edited card: {}", e);
+                app.send_error_toast(&format!("Could not read the edited card: {}", e), None);
+                return;
+            }
+        }
+    }
+    app.state.text_input.reset();
+    info!("Applied changes from the external editor");
+    app.send_info_toast(
+        "Applied changes from the external editor, submit the card to save them",
+        None,
+    );
+}
+
+/// Creates the next instances of completed recurring cards and shows them, keeping the current card focused
+fn handle_recurring_cards(app: &mut App) {
+    if app.create_next_recurring_cards() == 0 {
+        return;
+    }
+    let current_board_id = app.state.current_board_id;
+    let current_card_id = app.state.current_card_id;
+    refresh_visible_boards_and_cards(app);
+    if let (Some(board_id), Some(card_id)) = (current_board_id, current_card_id) {
+        app.jump_to_card(board_id, card_id);
+    }
+}
+
 fn open_command_palette(app: &mut App) {
     app.state.popup_mode = Some(PopupMode::CommandPalette);
     app.state.current_user_input = String::new();
-    app.state.current_cursor_position = None;
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+}
+
+pub fn open_card_search(app: &mut App) {
+    app.state.popup_mode = Some(PopupMode::CardSearch);
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
     app.state.app_status = AppStatus::UserInput;
+    app.state.card_search_list_state.select(None);
+    app.card_search.search_results.clear();
+    app.card_search.last_search_string = None;
+}
+
+pub fn open_link_card(app: &mut App) -> AppReturn {
+    if app.state.current_card_id.is_none() {
+        app.send_error_toast("No card selected to link", None);
+        return AppReturn::Continue;
+    }
+    app.state.popup_mode = Some(PopupMode::LinkCard);
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+    app.state.card_link_kind = CardLinkKind::BlockedBy;
+    app.state.card_search_list_state.select(None);
+    app.card_search.search_results.clear();
+    app.card_search.last_search_string = None;
+    AppReturn::Continue
+}
+
+pub fn open_filter_cards(app: &mut App) {
+    app.state.popup_mode = Some(PopupMode::FilterCards);
+    app.state.current_user_input = app
+        .state
+        .active_filter
+        .as_ref()
+        .map(|filter| filter.to_string())
+        .unwrap_or_default();
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+    app.state.saved_filters_list_state.select(None);
+}
+
+pub fn open_save_filter_prompt(app: &mut App) {
+    if app.state.active_filter.is_none() {
+        warn!("No active filter to save");
+        app.send_warning_toast("No active filter to save", None);
+        app.state.popup_mode = None;
+        return;
+    }
+    app.state.popup_mode = Some(PopupMode::SaveFilterPrompt);
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+}
+
+pub fn clear_filter(app: &mut App) {
+    if app.state.active_filter.take().is_some() {
+        info!("Cleared the active filter");
+        app.send_info_toast("Cleared the active filter", None);
+        refresh_visible_boards_and_cards(app);
+    }
+}
+
+/// Opens the right click menu for a card, or for the board when card_id is None
+fn open_context_menu(app: &mut App, board_id: u128, card_id: Option<u128>) {
+    app.state.current_board_id = Some(board_id);
+    app.state.current_card_id = card_id;
+    app.state.context_menu_items = ContextMenuItem::items_for(card_id);
+    app.state.context_menu_position = app.state.current_mouse_coordinates;
+    app.state.context_menu_state.select(Some(0));
+    app.state.popup_mode = Some(PopupMode::ContextMenu);
+}
+
+async fn handle_context_menu_selection(app: &mut App) -> AppReturn {
+    app.state.popup_mode = None;
+    app.state.focus = Focus::Body;
+    let item = app
+        .state
+        .context_menu_state
+        .selected()
+        .and_then(|index| app.state.context_menu_items.get(index).cloned());
+    let (item, board_id) = match (item, app.state.current_board_id) {
+        (Some(item), Some(board_id)) => (item, board_id),
+        _ => return AppReturn::Continue,
+    };
+    match item {
+        ContextMenuItem::EditCard => {
+            app.state.popup_mode = Some(PopupMode::ViewCard);
+            app.state.focus = Focus::CardDescription;
+            return handle_edit_new_card(app);
+        }
+        ContextMenuItem::ChangeCardStatus => {
+            app.state.popup_mode = Some(PopupMode::CardStatusSelector);
+            app.state.card_status_selector_state.select(Some(0));
+        }
+        ContextMenuItem::ChangeCardPriority => {
+            app.state.popup_mode = Some(PopupMode::CardPrioritySelector);
+            app.state.card_priority_selector_state.select(Some(0));
+        }
+        ContextMenuItem::MoveCardToBoard => {
+            let boards = ContextMenuItem::move_card_to_items(&app.boards, board_id);
+            if boards.is_empty() {
+                app.send_error_toast("There are no other boards to move the card to", None);
+                return AppReturn::Continue;
+            }
+            app.state.context_menu_items = boards;
+            app.state.context_menu_state.select(Some(0));
+            app.state.popup_mode = Some(PopupMode::ContextMenu);
+        }
+        ContextMenuItem::MoveCardTo(to_board_id, _) => {
+            if let Some(card_id) = app.state.current_card_id {
+                move_card(app, board_id, card_id, to_board_id, None);
+            }
+        }
+        ContextMenuItem::DuplicateCard => handle_duplicate_card(app),
+        ContextMenuItem::ArchiveCard => handle_archive_card(app).await,
+        ContextMenuItem::DeleteCard => {
+            return request_destructive_action(app, DestructiveAction::DeleteCard).await
+        }
+        ContextMenuItem::SetWipLimit => open_wip_limit_prompt(app),
+        ContextMenuItem::SetWorkflow => open_workflow_prompt(app),
+        ContextMenuItem::SortCards => open_sort_cards(app),
+        ContextMenuItem::ArchiveBoard => handle_archive_board(app).await,
+        ContextMenuItem::DeleteBoard => {
+            return request_destructive_action(app, DestructiveAction::DeleteBoard).await
+        }
+    }
+    AppReturn::Continue
+}
+
+/// Inserts a copy of the current card right below it
+fn handle_duplicate_card(app: &mut App) {
+    let (board_id, card_id) = match (app.state.current_board_id, app.state.current_card_id) {
+        (Some(board_id), Some(card_id)) => (board_id, card_id),
+        _ => return,
+    };
+    let board_index = if let Some(index) = app.boards.iter().position(|b| b.id == board_id) {
+        index
+    } else {
+        app.send_error_toast("Error Could not find current board", None);
+        return;
+    };
+    if !check_wip_limit(app, board_index) {
+        return;
+    }
+    let board = &mut app.boards[board_index];
+    let card_index = if let Some(index) = board.cards.iter().position(|c| c.id == card_id) {
+        index
+    } else {
+        app.send_error_toast("Error Could not find current card", None);
+        return;
+    };
+    let card = board.cards[card_index].duplicate();
+    let new_card_id = card.id;
+    let card_name = card.name.clone();
+    board.cards.insert(card_index + 1, card);
+    info!("Created card {}", card_name);
+    app.send_info_toast(&format!("Created card {}", card_name), None);
+    refresh_visible_boards_and_cards(app);
+    app.jump_to_card(board_id, new_card_id);
+}
+
+pub fn open_sort_cards(app: &mut App) {
+    if app.state.current_board_id.is_none() {
+        app.send_error_toast("No board selected to sort", None);
+        app.state.popup_mode = None;
+        return;
+    }
+    app.state.popup_mode = Some(PopupMode::SortCards);
+    app.state.sort_cards_list_state.select(Some(0));
+}
+
+fn handle_sort_cards(app: &mut App) -> AppReturn {
+    app.state.popup_mode = None;
+    let sort_options = CardSortKey::sort_options();
+    let selected_index = app
+        .state
+        .sort_cards_list_state
+        .selected()
+        .unwrap_or(0)
+        .min(sort_options.len() - 1);
+    let (key, descending) = sort_options[selected_index];
+    let board_id = if let Some(board_id) = app.state.current_board_id {
+        board_id
+    } else {
+        app.send_error_toast("No board selected to sort", None);
+        return AppReturn::Continue;
+    };
+    let board_name = if let Some(board) = app.boards.iter_mut().find(|b| b.id == board_id) {
+        board.sort_cards(key, descending);
+        board.name.clone()
+    } else {
+        app.send_error_toast("Error Could not find current board", None);
+        return AppReturn::Continue;
+    };
+    let sort_option = CardSortKey::sort_option_to_string(key, descending);
+    info!("Sorted cards of board '{}' by {}", board_name, sort_option);
+    app.send_info_toast(
+        &format!("Sorted cards of board '{}' by {}", board_name, sort_option),
+        None,
+    );
+    // scroll the board back to its new first card
+    let first_card_id = app
+        .filtered_boards()
+        .iter()
+        .find(|b| b.id == board_id)
+        .and_then(|b| b.cards.first().map(|c| c.id));
+    if let Some(visible_cards) = app.visible_boards_and_cards.get_mut(&board_id) {
+        visible_cards.clear();
+    }
+    if let Some(first_card_id) = first_card_id {
+        app.jump_to_card(board_id, first_card_id);
+    }
+    AppReturn::Continue
+}
+
+fn handle_filter_cards_submit(app: &mut App) -> AppReturn {
+    let expression = app.state.current_user_input.trim().to_string();
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    if expression.is_empty() {
+        clear_filter(app);
+        return AppReturn::Continue;
+    }
+    match CardFilter::parse(&expression) {
+        Ok(filter) => {
+            info!("Filtering cards by '{}'", filter);
+            app.send_info_toast(&format!("Filtering cards by '{}'", filter), None);
+            app.state.active_filter = Some(filter);
+            refresh_visible_boards_and_cards(app);
+        }
+        Err(e) => {
+            error!("Invalid filter: {}", e);
+            app.send_error_toast(&format!("Invalid filter: {}", e), None);
+        }
+    }
+    AppReturn::Continue
+}
+
+fn handle_save_filter(app: &mut App) -> AppReturn {
+    let name = app.state.current_user_input.trim().to_string();
+    if name.is_empty() {
+        app.send_error_toast("Filter name cannot be empty", None);
+        return AppReturn::Continue;
+    }
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    let expression = if let Some(filter) = &app.state.active_filter {
+        filter.to_string()
+    } else {
+        app.send_warning_toast("No active filter to save", None);
+        return AppReturn::Continue;
+    };
+    app.config.saved_filters.insert(name.clone(), expression);
+    if let Err(e) = write_config(&app.config) {
+        error!("Error writing config file: {}", e);
+        app.send_error_toast(&format!("Error writing config file: {}", e), None);
+    } else {
+        info!("Saved filter '{}'", name);
+        app.send_info_toast(&format!("Saved filter '{}'", name), None);
+    }
+    AppReturn::Continue
+}
+
+pub fn open_workspace_switcher(app: &mut App) {
+    app.state.workspaces = get_workspaces();
+    app.state.popup_mode = Some(PopupMode::SwitchWorkspace);
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+    let current_workspace = app.config.workspace_name().to_string();
+    let current_index = app
+        .state
+        .workspaces
+        .iter()
+        .position(|workspace| *workspace == current_workspace);
+    app.state.workspace_list_state.select(current_index);
+}
+
+async fn handle_switch_workspace(app: &mut App) -> AppReturn {
+    let workspace = app.state.current_user_input.trim().to_string();
+    if workspace.is_empty() {
+        app.send_error_toast("Workspace name cannot be empty", None);
+        return AppReturn::Continue;
+    }
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    if workspace == app.config.workspace_name() {
+        app.send_info_toast(&format!("Already in workspace {}", workspace), None);
+        return AppReturn::Continue;
+    }
+    app.dispatch(IoEvent::SwitchWorkspace(workspace)).await;
+    AppReturn::Continue
+}
+
+pub fn open_wip_limit_prompt(app: &mut App) {
+    let current_board = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id));
+    if let Some(current_board) = current_board {
+        app.state.current_user_input = if current_board.wip_limit > 0 {
+            current_board.wip_limit.to_string()
+        } else {
+            String::new()
+        };
+        app.state.popup_mode = Some(PopupMode::SetWipLimitPrompt);
+        app.state.text_input.reset();
+        app.state.app_status = AppStatus::UserInput;
+    } else {
+        warn!("No board selected to set the WIP limit of");
+        app.send_warning_toast("No board selected to set the WIP limit of", None);
+        app.state.popup_mode = None;
+    }
+}
+
+fn handle_set_wip_limit(app: &mut App) -> AppReturn {
+    let value = app.state.current_user_input.trim().to_string();
+    let wip_limit = if value.is_empty() {
+        0
+    } else if let Ok(wip_limit) = value.parse::<u16>() {
+        wip_limit
+    } else {
+        app.send_error_toast(
+            &format!("Expected a number of cards (0 to disable), got: {}", value),
+            None,
+        );
+        return AppReturn::Continue;
+    };
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    let current_board_id = app.state.current_board_id.unwrap_or(0);
+    if let Some(current_board) = app
+        .boards
+        .iter_mut()
+        .find(|board| board.id == current_board_id)
+    {
+        current_board.wip_limit = wip_limit;
+        let message = if wip_limit == 0 {
+            format!("Removed the WIP limit of board \"{}\"", current_board.name)
+        } else {
+            format!(
+                "Set the WIP limit of board \"{}\" to {}",
+                current_board.name, wip_limit
+            )
+        };
+        info!("{}", message);
+        app.send_info_toast(&message, None);
+    } else {
+        debug!("Current board not found");
+        app.send_error_toast("Something went wrong, could not find the board", None);
+    }
+    AppReturn::Continue
+}
+
+pub fn open_workflow_prompt(app: &mut App) {
+    let current_board = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id));
+    if let Some(current_board) = current_board {
+        app.state.current_user_input =
+            WorkflowStatus::workflow_to_string(&current_board.workflow());
+        app.state.popup_mode = Some(PopupMode::SetWorkflowPrompt);
+        app.state.text_input.reset();
+        app.state.app_status = AppStatus::UserInput;
+    } else {
+        warn!("No board selected to set the workflow of");
+        app.send_warning_toast("No board selected to set the workflow of", None);
+        app.state.popup_mode = None;
+    }
+}
+
+fn handle_set_workflow(app: &mut App) -> AppReturn {
+    let workflow = match WorkflowStatus::parse_workflow(&app.state.current_user_input) {
+        Ok(workflow) => workflow,
+        Err(e) => {
+            app.send_error_toast(&format!("Invalid workflow: {}", e), None);
+            return AppReturn::Continue;
+        }
+    };
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    let current_board_id = app.state.current_board_id.unwrap_or(0);
+    if let Some(current_board) = app
+        .boards
+        .iter_mut()
+        .find(|board| board.id == current_board_id)
+    {
+        current_board.set_workflow(workflow);
+        let message = format!(
+            "Set the workflow of board \"{}\" to {}",
+            current_board.name,
+            WorkflowStatus::workflow_to_string(&current_board.workflow)
+        );
+        info!("{}", message);
+        app.send_info_toast(&message, None);
+    } else {
+        debug!("Current board not found");
+        app.send_error_toast("Something went wrong, could not find the board", None);
+    }
+    AppReturn::Continue
+}
+
+/// Asks for the JSON export to import, prefilled with the latest export in the save directory
+pub fn open_import_prompt(app: &mut App, replace: bool) {
+    app.state.current_user_input = get_latest_kanban_export()
+        .map(|export_file| export_file.display().to_string())
+        .unwrap_or_default();
+    app.state.popup_mode = Some(PopupMode::ImportFromJSONPrompt(replace));
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+}
+
+fn handle_import_from_json(app: &mut App, replace: bool) -> AppReturn {
+    let export_file = PathBuf::from(app.state.current_user_input.trim());
+    if export_file.as_os_str().is_empty() {
+        app.send_error_toast("Enter the path of the JSON export to import", None);
+        return AppReturn::Continue;
+    }
+    // the prompt stays open on failure so the path can be corrected
+    if import_kanban_export(app, &export_file, replace) {
+        app.state.popup_mode = None;
+        app.state.app_status = AppStatus::Initialized;
+        app.state.current_user_input = String::new();
+        app.state.text_input.reset();
+    }
+    AppReturn::Continue
+}
+
+pub fn open_quick_add_card(app: &mut App) {
+    if app.state.current_board_id.is_none() {
+        warn!("No board available to add card to");
+        app.send_warning_toast("No board available to add card to", None);
+        return;
+    }
+    app.state.current_user_input = String::new();
+    app.state.popup_mode = Some(PopupMode::QuickAddCard);
+    app.state.text_input.reset();
+    app.state.app_status = AppStatus::UserInput;
+}
+
+fn handle_quick_add_card(app: &mut App) -> AppReturn {
+    let mut new_card = match Card::from_quick_add(&app.state.current_user_input, CardDate::now()) {
+        Ok(new_card) => new_card,
+        Err(e) => {
+            app.send_error_toast(&e, None);
+            return AppReturn::Continue;
+        }
+    };
+    let current_board_id = app.state.current_board_id.unwrap_or(0);
+    let current_board_index = app
+        .boards
+        .iter()
+        .position(|board| board.id == current_board_id);
+    let current_board_index = if let Some(current_board_index) = current_board_index {
+        current_board_index
+    } else {
+        debug!("Current board not found");
+        app.send_error_toast("Something went wrong, could not find the board", None);
+        return AppReturn::Continue;
+    };
+    if app.boards[current_board_index]
+        .cards
+        .iter()
+        .any(|card| card.name == new_card.name)
+    {
+        let message = format!("A card named \"{}\" already exists", new_card.name);
+        warn!("{}", message);
+        app.send_warning_toast(&message, None);
+        return AppReturn::Continue;
+    }
+    if !check_wip_limit(app, current_board_index) {
+        return AppReturn::Continue;
+    }
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    let current_board = &mut app.boards[current_board_index];
+    current_board.adopt_card_status(&mut new_card);
+    current_board.cards.push(new_card.clone());
+    let message = format!(
+        "Created card \"{}\" in board \"{}\"",
+        new_card.name, current_board.name
+    );
+    info!("{}", message);
+    app.send_info_toast(&message, None);
+    refresh_visible_boards_and_cards(app);
+    app.jump_to_card(current_board_id, new_card.id);
+    AppReturn::Continue
+}
+
+fn handle_card_search_selection(app: &mut App) -> AppReturn {
+    let selected_result = app
+        .state
+        .card_search_list_state
+        .selected()
+        .and_then(|index| app.card_search.search_results.get(index).copied());
+    app.state.popup_mode = None;
+    app.state.app_status = AppStatus::Initialized;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    if let Some((board_id, card_id)) = selected_result {
+        app.jump_to_card(board_id, card_id);
+        app.state.focus = Focus::Body;
+        debug!("Jumped to card {} on board {}", card_id, board_id);
+    } else {
+        warn!("No card selected from the search results");
+        app.send_warning_toast("No card selected from the search results", None);
+    }
+    AppReturn::Continue
+}
+
+/// Links the current card to the selected search result, a card being edited in the card view
+/// gets the link when its changes are saved
+fn handle_link_card_selection(app: &mut App) -> AppReturn {
+    let selected_result = app
+        .state
+        .card_search_list_state
+        .selected()
+        .and_then(|index| app.card_search.search_results.get(index).copied());
+    let link_kind = app.state.card_link_kind;
+    app.state.current_user_input = String::new();
+    app.state.text_input.reset();
+    if app.card_being_edited.is_some() {
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+    } else {
+        app.state.popup_mode = None;
+        app.state.app_status = AppStatus::Initialized;
+    }
+    let linked_card_id = selected_result.map(|(_, card_id)| card_id);
+    let linked_card_name = match linked_card_id.and_then(|card_id| find_card(&app.boards, card_id))
+    {
+        Some(linked_card) => linked_card.name.clone(),
+        None => {
+            warn!("No card selected to link");
+            app.send_warning_toast("No card selected to link", None);
+            return AppReturn::Continue;
+        }
+    };
+    let editing_card = app.card_being_edited.is_some();
+    let card = if let Some((_, card_being_edited)) = app.card_being_edited.as_mut() {
+        Some(card_being_edited)
+    } else {
+        let current_board_id = app.state.current_board_id;
+        let current_card_id = app.state.current_card_id;
+        app.boards
+            .iter_mut()
+            .find(|b| Some(b.id) == current_board_id)
+            .and_then(|board| {
+                board
+                    .cards
+                    .iter_mut()
+                    .find(|c| Some(c.id) == current_card_id)
+            })
+    };
+    let card = match card {
+        Some(card) => card,
+        None => {
+            app.send_error_toast("Error Could not find current card", None);
+            return AppReturn::Continue;
+        }
+    };
+    let card_name = card.name.clone();
+    if !card.add_link(link_kind, linked_card_id.unwrap()) {
+        warn!(
+            "Card '{}' is already linked to '{}'",
+            card_name, linked_card_name
+        );
+        app.send_warning_toast(
+            &format!(
+                "Card '{}' is already linked to '{}'",
+                card_name, linked_card_name
+            ),
+            None,
+        );
+        return AppReturn::Continue;
+    }
+    let link_description = format!("{} '{}'", link_kind, linked_card_name);
+    if !editing_card {
+        card.log_activity(link_description.clone());
+    }
+    info!("Card '{}': {}", card_name, link_description);
+    app.send_info_toast(&format!("Card '{}': {}", card_name, link_description), None);
+    AppReturn::Continue
+}
+
+/// Warns when a card is completed while the cards blocking it are still open
+fn warn_about_open_blockers(app: &mut App, card: &Card) {
+    let open_blockers = card
+        .open_blockers(&app.boards)
+        .iter()
+        .map(|blocker| format!("'{}'", blocker.name))
+        .collect::<Vec<String>>();
+    if open_blockers.is_empty() {
+        return;
+    }
+    let message = format!(
+        "Card '{}' is still blocked by {}",
+        card.name,
+        open_blockers.join(", ")
+    );
+    warn!("{}", message);
+    app.send_warning_toast(&message, None);
+}
+
+pub fn handle_undo(app: &mut App) {
+    if app.undo() {
+        refresh_visible_boards_and_cards(app);
+        app.send_info_toast("Undid last change", None);
+        info!("Undid last change");
+    } else {
+        app.send_warning_toast("Nothing to undo", None);
+        warn!("Nothing to undo");
+    }
+}
+
+pub fn handle_redo(app: &mut App) {
+    if app.redo() {
+        refresh_visible_boards_and_cards(app);
+        app.send_info_toast("Redid last undone change", None);
+        info!("Redid last undone change");
+    } else {
+        app.send_warning_toast("Nothing to redo", None);
+        warn!("Nothing to redo");
+    }
+}
+
+pub async fn request_destructive_action(app: &mut App, action: DestructiveAction) -> AppReturn {
+    if app.config.skip_confirmation_dialogs {
+        return perform_destructive_action(app, action).await;
+    }
+    app.state.previous_focus = Some(app.state.focus);
+    app.state.focus = Focus::ExtraFocus;
+    app.state.popup_mode = Some(PopupMode::ConfirmDestructiveAction(action));
+    AppReturn::Continue
+}
+
+async fn handle_destructive_action_confirmation(
+    app: &mut App,
+    action: DestructiveAction,
+    confirmed: bool,
+) -> AppReturn {
+    app.state.popup_mode = None;
+    if let Some(previous_focus) = app.state.previous_focus.take() {
+        app.state.focus = previous_focus;
+    }
+    if confirmed {
+        perform_destructive_action(app, action).await
+    } else {
+        info!("Cancelled {}", action);
+        app.send_info_toast(&format!("Cancelled {}", action), None);
+        AppReturn::Continue
+    }
+}
+
+async fn perform_destructive_action(app: &mut App, action: DestructiveAction) -> AppReturn {
+    match action {
+        DestructiveAction::DeleteCard => handle_delete_card(app).await,
+        DestructiveAction::DeleteBoard => handle_delete_board(app).await,
+        DestructiveAction::DeleteSave => {
+            // run delete task in background
+            app.dispatch(IoEvent::DeleteSave).await;
+            tokio::time::sleep(Duration::from_millis(IO_EVENT_WAIT_TIME)).await;
+            app.dispatch(IoEvent::LoadPreview).await;
+        }
+        DestructiveAction::ResetConfig => handle_reset_config(app, true),
+        DestructiveAction::ResetConfigAndKeybindings => handle_reset_config(app, false),
+        DestructiveAction::ReplaceBoardsWithImport => open_import_prompt(app, true),
+    }
+    AppReturn::Continue
+}
+
+async fn handle_delete_card(app: &mut App) {
+    let (current_board, current_card) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(current_board), Some(current_card)) => (current_board, current_card),
+            _ => return,
+        };
+    let board = if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board) {
+        board
+    } else {
+        return;
+    };
+    let card_index = if let Some(card_index) = board.cards.iter().position(|c| c.id == current_card)
+    {
+        card_index
+    } else {
+        return;
+    };
+    let card_name = board.cards.remove(card_index).name;
+    // if index is > 0, set current card to previous card, else set to next card, else set to None
+    app.state.current_card_id = if card_index > 0 {
+        Some(board.cards[card_index - 1].id)
+    } else {
+        board.cards.first().map(|c| c.id)
+    };
+    warn!("Deleted card {}", card_name);
+    app.send_warning_toast(&format!("Deleted card {}", card_name), None);
+    if let Some(visible_cards) = app.visible_boards_and_cards.get_mut(&current_board) {
+        visible_cards.retain(|card_id| *card_id != current_card);
+    }
+    app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+}
+
+async fn handle_delete_board(app: &mut App) {
+    let current_board = if let Some(current_board) = app.state.current_board_id {
+        current_board
+    } else {
+        return;
+    };
+    let index = if let Some(index) = app.boards.iter().position(|b| b.id == current_board) {
+        index
+    } else {
+        return;
+    };
+    let board_name = app.boards.remove(index).name;
+    // if index is > 0, set current board to previous board, else set to next board, else set to None
+    app.state.current_board_id = if index > 0 {
+        Some(app.boards[index - 1].id)
+    } else {
+        app.boards.first().map(|b| b.id)
+    };
+    app.state.current_card_id = None;
+    app.visible_boards_and_cards.remove(&current_board);
+    warn!("Deleted board {}", board_name);
+    app.send_warning_toast(&format!("Deleted board {}", board_name), None);
+    app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+}
+
+async fn handle_archive_card(app: &mut App) {
+    let (current_board, current_card) =
+        match (app.state.current_board_id, app.state.current_card_id) {
+            (Some(current_board), Some(current_card)) => (current_board, current_card),
+            _ => return,
+        };
+    let boards = app.filtered_boards();
+    let visible_cards = if let Some(board) = boards.iter().find(|b| b.id == current_board) {
+        &board.cards
+    } else {
+        return;
+    };
+    let card_index =
+        if let Some(card_index) = visible_cards.iter().position(|c| c.id == current_card) {
+            card_index
+        } else {
+            return;
+        };
+    // select the previous card, or the next one if the first card was archived
+    let next_card_id = if card_index > 0 {
+        Some(visible_cards[card_index - 1].id)
+    } else {
+        visible_cards.get(1).map(|c| c.id)
+    };
+    let card = if let Some(card) = app
+        .boards
+        .iter_mut()
+        .find(|b| b.id == current_board)
+        .and_then(|b| b.cards.iter_mut().find(|c| c.id == current_card))
+    {
+        card
+    } else {
+        return;
+    };
+    card.set_archived(true);
+    let card_name = card.name.clone();
+    app.state.current_card_id = next_card_id;
+    info!("Archived card {}", card_name);
+    app.send_info_toast(&format!("Archived card {}", card_name), None);
+    if let Some(visible_cards) = app.visible_boards_and_cards.get_mut(&current_board) {
+        visible_cards.retain(|card_id| *card_id != current_card);
+    }
+    app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+}
+
+async fn handle_archive_board(app: &mut App) {
+    let current_board = if let Some(current_board) = app.state.current_board_id {
+        current_board
+    } else {
+        return;
+    };
+    let boards = app.filtered_boards();
+    let index = if let Some(index) = boards.iter().position(|b| b.id == current_board) {
+        index
+    } else {
+        return;
+    };
+    let next_board_id = if index > 0 {
+        Some(boards[index - 1].id)
+    } else {
+        boards.get(1).map(|b| b.id)
+    };
+    let board = if let Some(board) = app.boards.iter_mut().find(|b| b.id == current_board) {
+        board
+    } else {
+        return;
+    };
+    board.archived = true;
+    let board_name = board.name.clone();
+    app.state.current_board_id = next_board_id;
+    app.state.current_card_id = None;
+    app.visible_boards_and_cards.remove(&current_board);
+    info!("Archived board {}", board_name);
+    app.send_info_toast(&format!("Archived board {}", board_name), None);
+    app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
+}
+
+pub fn open_archive(app: &mut App) {
+    app.state.prev_ui_mode = Some(app.state.ui_mode);
+    app.state.ui_mode = UiMode::Archive;
+    app.state.focus = Focus::ArchivedItems;
+    let first_item = if app.archived_items().is_empty() {
+        None
+    } else {
+        Some(0)
+    };
+    app.state.archive_list_state.select(first_item);
+}
+
+fn handle_restore_archived_item(app: &mut App) -> AppReturn {
+    let archived_items = app.archived_items();
+    let selected_item = app
+        .state
+        .archive_list_state
+        .selected()
+        .and_then(|index| archived_items.get(index).copied());
+    match selected_item {
+        Some(ArchivedItem::Board(board_id)) => {
+            if let Some(board) = app.boards.iter_mut().find(|b| b.id == board_id) {
+                board.archived = false;
+                info!("Restored board {}", board.name);
+                let message = format!("Restored board {}", board.name);
+                app.send_info_toast(&message, None);
+            }
+        }
+        Some(ArchivedItem::Card(board_id, card_id)) => {
+            if let Some(card) = app
+                .boards
+                .iter_mut()
+                .find(|b| b.id == board_id)
+                .and_then(|b| b.cards.iter_mut().find(|c| c.id == card_id))
+            {
+                card.set_archived(false);
+                info!("Restored card {}", card.name);
+                let message = format!("Restored card {}", card.name);
+                app.send_info_toast(&message, None);
+            }
+        }
+        None => {
+            app.send_warning_toast("No archived item selected", None);
+            return AppReturn::Continue;
+        }
+    }
+    // keep the selection in bounds now that the list is shorter
+    let no_of_archived_items = archived_items.len() - 1;
+    let selected_index = app.state.archive_list_state.selected().unwrap_or(0);
+    app.state
+        .archive_list_state
+        .select(if no_of_archived_items == 0 {
+            None
+        } else {
+            Some(selected_index.min(no_of_archived_items - 1))
+        });
+    refresh_visible_boards_and_cards(app);
+    AppReturn::Continue
+}
+
+fn handle_reset_config(app: &mut App, keep_keybindings: bool) {
+    let keybinds = app.config.keybindings.clone();
+    app.config = AppConfig::default();
+    if keep_keybindings {
+        app.config.keybindings = keybinds;
+    }
+    app.state.focus = Focus::ConfigTable;
+    app.state.config_state.select(Some(0));
+    let write_config_status = write_config(&app.config);
+    if write_config_status.is_err() {
+        error!(
+            "Error writing config file: {}",
+            write_config_status.clone().unwrap_err()
+        );
+        app.send_error_toast(
+            &format!(
+                "Error writing config file: {}",
+                write_config_status.unwrap_err()
+            ),
+            None,
+        );
+    } else if keep_keybindings {
+        warn!("Reset Config to default");
+        app.send_warning_toast("Reset Config to default", None);
+    } else {
+        warn!("Reset Config and Keybinds to default");
+        app.send_warning_toast("Reset Config and Keybinds to default", None);
+    }
+    if !keep_keybindings {
+        app.keybind_list_maker();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{io::data_handler::ExportStruct, ui::widgets::CommandPaletteActions};
+
+    fn test_app(boards: Vec<Board>) -> (App, tokio::sync::mpsc::Receiver<IoEvent>) {
+        let (io_tx, io_rx) = tokio::sync::mpsc::channel(100);
+        let mut app = App::new(io_tx);
+        app.config = AppConfig::default();
+        app.state.ui_mode = UiMode::TitleBodyHelpLog;
+        app.initialized();
+        app.set_boards(boards);
+        refresh_visible_boards_and_cards(&mut app);
+        app.state.focus = Focus::Body;
+        (app, io_rx)
+    }
+
+    fn board_with_cards(name: &str, card_names: &[&str]) -> Board {
+        let mut board = Board::new(name.to_string(), String::new());
+        for card_name in card_names {
+            board.cards.push(Card::new(
+                card_name.to_string(),
+                String::new(),
+                None,
+                CardPriority::Low,
+                vec![],
+                vec![],
+            ));
+        }
+        board
+    }
+
+    fn card_names(board: &Board) -> Vec<&str> {
+        board.cards.iter().map(|card| card.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn should_move_card_past_hidden_cards() {
+        let mut board = board_with_cards("Todo", &["a", "b", "c", "d"]);
+        board.cards[1].archived = true;
+        board.cards[3].set_status(&WorkflowStatus::new("Done", CardStatus::Complete));
+        let (mut app, _io_rx) = test_app(vec![board]);
+        app.state.active_filter = Some(CardFilter::parse("status:active").unwrap());
+        refresh_visible_boards_and_cards(&mut app);
+        let board_id = app.boards[0].id;
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = Some(app.boards[0].cards[2].id);
+
+        handle_general_actions(&mut app, Key::ShiftUp).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["c", "b", "a", "d"]);
+        assert_eq!(
+            card_names(&app.filtered_boards()[0]),
+            vec!["c", "a"],
+            "the swap should be visible"
+        );
+
+        handle_general_actions(&mut app, Key::ShiftDown).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["a", "b", "c", "d"]);
+        handle_general_actions(&mut app, Key::ShiftDown).await;
+        assert_eq!(
+            card_names(&app.boards[0]),
+            vec!["a", "b", "c", "d"],
+            "the filtered out card below should not be swapped with"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_keep_moved_cards_inside_their_swimlane() {
+        let mut board = board_with_cards("Todo", &["a", "b", "c"]);
+        board.cards[0].priority = CardPriority::High;
+        board.cards[1].priority = CardPriority::Low;
+        board.cards[2].priority = CardPriority::Low;
+        let (mut app, _io_rx) = test_app(vec![board]);
+        app.config.swimlanes = SwimlaneGrouping::Priority;
+        refresh_visible_boards_and_cards(&mut app);
+        app.state.current_board_id = Some(app.boards[0].id);
+        app.state.current_card_id = Some(app.boards[0].cards[1].id);
+
+        handle_general_actions(&mut app, Key::ShiftUp).await;
+        assert_eq!(
+            card_names(&app.boards[0]),
+            vec!["a", "b", "c"],
+            "the card at the top of its lane should not be swapped into the lane above"
+        );
+        handle_general_actions(&mut app, Key::ShiftDown).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["a", "c", "b"]);
+        handle_general_actions(&mut app, Key::ShiftDown).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn should_find_drop_insert_index() {
+        let board = board_with_cards("Todo", &["a", "b", "c"]);
+        let ids = board
+            .cards
+            .iter()
+            .map(|card| card.id)
+            .collect::<Vec<u128>>();
+        assert_eq!(drop_insert_index(&board.cards, Some(ids[0])), 0);
+        assert_eq!(drop_insert_index(&board.cards, Some(ids[2])), 2);
+        assert_eq!(drop_insert_index(&board.cards, None), 3);
+        assert_eq!(
+            drop_insert_index(&board.cards, Some(u128::MAX)),
+            3,
+            "a card that is not on the board should drop at the end"
+        );
+        assert_eq!(drop_insert_index(&[], None), 0);
+    }
+
+    #[test]
+    fn should_drop_dragged_cards_within_and_across_boards() {
+        let (mut app, _io_rx) = test_app(vec![
+            board_with_cards("Todo", &["a", "b", "c"]),
+            board_with_cards("Done", &["d"]),
+        ]);
+        let (todo_id, done_id) = (app.boards[0].id, app.boards[1].id);
+        let card_id = |app: &App, board_index: usize, card_index: usize| {
+            app.boards[board_index].cards[card_index].id
+        };
+
+        app.state.dragged_card = Some((todo_id, card_id(&app, 0, 0)));
+        app.state.hovered_board_and_card = Some((todo_id, Some(card_id(&app, 0, 2))));
+        handle_card_drop(&mut app);
+        assert_eq!(card_names(&app.boards[0]), vec!["b", "a", "c"]);
+
+        app.state.dragged_card = Some((todo_id, card_id(&app, 0, 2)));
+        app.state.hovered_board_and_card = Some((todo_id, Some(card_id(&app, 0, 0))));
+        handle_card_drop(&mut app);
+        assert_eq!(card_names(&app.boards[0]), vec!["c", "b", "a"]);
+
+        app.state.dragged_card = Some((todo_id, card_id(&app, 0, 1)));
+        app.state.hovered_board_and_card = Some((done_id, Some(card_id(&app, 1, 0))));
+        handle_card_drop(&mut app);
+        assert_eq!(card_names(&app.boards[0]), vec!["c", "a"]);
+        assert_eq!(card_names(&app.boards[1]), vec!["b", "d"]);
+
+        app.state.dragged_card = Some((todo_id, card_id(&app, 0, 0)));
+        app.state.hovered_board_and_card = Some((done_id, None));
+        handle_card_drop(&mut app);
+        assert_eq!(card_names(&app.boards[0]), vec!["a"]);
+        assert_eq!(card_names(&app.boards[1]), vec!["b", "d", "c"]);
+        assert_eq!(app.state.dragged_card, None);
+    }
+
+    #[test]
+    fn should_map_context_menu_items() {
+        assert_eq!(
+            ContextMenuItem::items_for(Some(1)),
+            ContextMenuItem::card_items()
+        );
+        assert_eq!(
+            ContextMenuItem::items_for(None),
+            ContextMenuItem::board_items()
+        );
+
+        let mut boards = vec![
+            board_with_cards("Todo", &[]),
+            board_with_cards("Doing", &[]),
+            board_with_cards("Done", &[]),
+        ];
+        boards[2].archived = true;
+        assert_eq!(
+            ContextMenuItem::move_card_to_items(&boards, boards[0].id),
+            vec![ContextMenuItem::MoveCardTo(
+                boards[1].id,
+                String::from("Doing")
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_confirm_before_replacing_boards_with_import() {
+        let (mut app, _io_rx) = test_app(vec![board_with_cards("Todo", &["a"])]);
+        app.state.popup_mode = Some(PopupMode::CommandPalette);
+        app.command_palette.search_results =
+            Some(vec![CommandPaletteActions::ImportFromJSONReplace]);
+        app.state.command_palette_list_state.select(Some(0));
+
+        CommandPaletteWidget::handle_command(&mut app).await;
+        assert_eq!(
+            app.state.popup_mode,
+            Some(PopupMode::ConfirmDestructiveAction(
+                DestructiveAction::ReplaceBoardsWithImport
+            ))
+        );
+        assert_eq!(card_names(&app.boards[0]), vec!["a"]);
+
+        handle_destructive_action_confirmation(
+            &mut app,
+            DestructiveAction::ReplaceBoardsWithImport,
+            false,
+        )
+        .await;
+        assert_eq!(app.state.popup_mode, None);
+        assert_eq!(card_names(&app.boards[0]), vec!["a"]);
+
+        handle_destructive_action_confirmation(
+            &mut app,
+            DestructiveAction::ReplaceBoardsWithImport,
+            true,
+        )
+        .await;
+        assert_eq!(
+            app.state.popup_mode,
+            Some(PopupMode::ImportFromJSONPrompt(true)),
+            "the export to replace the boards with should be asked for"
+        );
+        assert_eq!(card_names(&app.boards[0]), vec!["a"]);
+    }
+
+    #[tokio::test]
+    async fn should_import_boards_from_the_entered_path() {
+        let (mut app, _io_rx) = test_app(vec![board_with_cards("Todo", &["a"])]);
+        let export_file = std::env::temp_dir().join(format!(
+            "kanban_export_from_another_machine_{}.json",
+            std::process::id()
+        ));
+        let export = ExportStruct {
+            kanban_version: env!("CARGO_PKG_VERSION").to_string(),
+            export_date: String::from("01/05/2024-12:00:00"),
+            boards: vec![board_with_cards("Todo", &["b"])],
+        };
+        std::fs::write(&export_file, serde_json::to_string(&export).unwrap()).unwrap();
+
+        open_import_prompt(&mut app, false);
+        assert_eq!(
+            app.state.popup_mode,
+            Some(PopupMode::ImportFromJSONPrompt(false))
+        );
+        app.state.current_user_input = format!("{}.missing", export_file.display());
+        handle_user_input_mode(&mut app, Key::Enter).await;
+        assert_eq!(
+            app.state.popup_mode,
+            Some(PopupMode::ImportFromJSONPrompt(false)),
+            "a wrong path should keep the prompt open"
+        );
+        assert_eq!(card_names(&app.boards[0]), vec!["a"]);
+
+        app.state.current_user_input = export_file.display().to_string();
+        handle_user_input_mode(&mut app, Key::Enter).await;
+        std::fs::remove_file(&export_file).unwrap();
+        assert_eq!(app.state.popup_mode, None);
+        assert_eq!(app.boards.len(), 1);
+        assert_eq!(card_names(&app.boards[0]), vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn should_select_and_edit_card_tags_through_the_text_input() {
+        let (mut app, _io_rx) = test_app(vec![board_with_cards("Todo", &["a"])]);
+        let board_id = app.boards[0].id;
+        let card = Card {
+            tags: vec![String::from("backend"), String::from("ui")],
+            ..app.boards[0].cards[0].clone()
+        };
+        app.card_being_edited = Some((board_id, card));
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+        app.state.app_status = AppStatus::UserInput;
+        app.state.focus = Focus::CardTags;
+        let tags = |app: &App| app.card_being_edited.as_ref().unwrap().1.tags.clone();
+
+        handle_user_input_mode(&mut app, Key::Down).await;
+        assert_eq!(app.state.card_view_tag_list_state.selected(), Some(0));
+        handle_user_input_mode(&mut app, Key::ShiftLeft).await;
+        handle_user_input_mode(&mut app, Key::ShiftLeft).await;
+        assert_eq!(app.state.text_input.selection("backend"), Some((5, 7)));
+        handle_user_input_mode(&mut app, Key::Backspace).await;
+        assert_eq!(tags(&app), vec!["backe", "ui"]);
+
+        handle_user_input_mode(&mut app, Key::Down).await;
+        assert_eq!(app.state.card_view_tag_list_state.selected(), Some(1));
+        handle_user_input_mode(&mut app, Key::Backspace).await;
+        handle_user_input_mode(&mut app, Key::Backspace).await;
+        assert_eq!(tags(&app), vec!["backe", ""]);
+        handle_user_input_mode(&mut app, Key::Backspace).await;
+        assert_eq!(
+            tags(&app),
+            vec!["backe"],
+            "an emptied tag should be removed"
+        );
+        assert_eq!(app.state.card_view_tag_list_state.selected(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn should_keep_the_due_date_when_editing_a_viewed_card() {
+        let (mut app, _io_rx) = test_app(vec![board_with_cards("Todo", &["a"])]);
+        app.boards[0].cards[0].date_due = CardDate::parse("23/10/2026-12:00:00");
+        app.state.current_board_id = Some(app.boards[0].id);
+        app.state.current_card_id = Some(app.boards[0].cards[0].id);
+        app.state.popup_mode = Some(PopupMode::ViewCard);
+
+        handle_general_actions(&mut app, Key::Char('i')).await;
+        assert_eq!(app.state.app_status, AppStatus::UserInput);
+        assert_eq!(app.card_due_date_being_edited, "23/10/2026-12:00:00");
+        handle_edit_card_submit(&mut app);
+        assert_eq!(
+            app.boards[0].cards[0].date_due,
+            CardDate::parse("23/10/2026-12:00:00")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_undo_and_redo_board_changes() {
+        let (mut app, _io_rx) = test_app(vec![board_with_cards("Todo", &["a", "b", "c"])]);
+        app.config.skip_confirmation_dialogs = true;
+        let board_id = app.boards[0].id;
+        let card_a_id = app.boards[0].cards[0].id;
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = Some(card_a_id);
+
+        app.do_action(Key::Char('1')).await;
+        assert_eq!(app.boards[0].cards[0].card_status, CardStatus::Complete);
+        app.do_action(Key::ShiftDown).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["b", "a", "c"]);
+        app.do_action(Key::Char('d')).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["b", "c"]);
+        // navigating and moving the mouse are not recorded
+        app.do_action(Key::Down).await;
+        app.handle_mouse(Mouse::Move(1, 1)).await;
+        assert_eq!(app.undo_stack.len(), 3);
+
+        app.do_action(Key::Ctrl('z')).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["b", "a", "c"]);
+        app.do_action(Key::Ctrl('z')).await;
+        assert_eq!(card_names(&app.boards[0]), vec!["a", "b", "c"]);
+        app.do_action(Key::Ctrl('z')).await;
+        assert_eq!(app.boards[0].cards[0].card_status, CardStatus::Active);
+        app.do_action(Key::Ctrl('y')).await;
+        assert_eq!(app.boards[0].cards[0].card_status, CardStatus::Complete);
+        assert_eq!((app.undo_stack.len(), app.redo_stack.len()), (1, 2));
+
+        // a new change drops the undone changes
+        app.state.current_board_id = Some(board_id);
+        app.state.current_card_id = Some(card_a_id);
+        app.do_action(Key::Char('3')).await;
+        assert_eq!(app.boards[0].cards[0].card_status, CardStatus::Stale);
+        assert_eq!((app.undo_stack.len(), app.redo_stack.len()), (2, 0));
+        app.do_action(Key::Ctrl('y')).await;
+        assert_eq!(app.boards[0].cards[0].card_status, CardStatus::Stale);
+        assert_eq!(card_names(&app.boards[0]), vec!["a", "b", "c"]);
+
+        // undoing from the command palette is not recorded as a change of its own
+        app.state.popup_mode = Some(PopupMode::CommandPalette);
+        app.state.app_status = AppStatus::UserInput;
+        app.command_palette.search_results = Some(vec![CommandPaletteActions::Undo]);
+        app.state.command_palette_list_state.select(Some(0));
+        app.do_action(Key::Enter).await;
+        assert_eq!(app.boards[0].cards[0].card_status, CardStatus::Complete);
+        assert_eq!((app.undo_stack.len(), app.redo_stack.len()), (1, 1));
+    }
+
+    #[tokio::test]
+    async fn should_set_workflow_from_prompt() {
+        let (mut app, _io_rx) = test_app(vec![board_with_cards("Todo", &["a"])]);
+        app.state.current_board_id = Some(app.boards[0].id);
+        open_workflow_prompt(&mut app);
+        assert_eq!(app.state.popup_mode, Some(PopupMode::SetWorkflowPrompt));
+
+        app.state.current_user_input = "Backlog, Done:Complete, Backlog".to_string();
+        app.do_action(Key::Enter).await;
+        assert_eq!(app.state.popup_mode, Some(PopupMode::SetWorkflowPrompt));
+        app.state.current_user_input = "Backlog, Done:Complete".to_string();
+        app.do_action(Key::Enter).await;
+        assert_eq!(app.state.popup_mode, None);
+        assert_eq!(
+            app.boards[0].workflow,
+            WorkflowStatus::parse_workflow("Backlog:Active, Done:Complete").unwrap()
+        );
+        assert_eq!(app.boards[0].cards[0].status_name(), "Backlog");
+    }
 }
@@ -1,18 +1,47 @@
-use std::fmt;
+use std::{cmp::Ordering, fmt, ops::Deref};
 
-use chrono::Utc;
+use chrono::{
+    Datelike, Duration, Local, Months, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc, Weekday,
+};
+use savefile::prelude::{
+    Deserializer, Introspect, IntrospectItem, SavefileError, Schema, SchemaPrimitive, Serializer,
+    WithSchema,
+};
 use savefile_derive::Savefile;
-use serde::{Deserialize, Serialize};
+use serde::{
+    Deserialize, Deserializer as SerdeDeserializer, Serialize, Serializer as SerdeSerializer,
+};
 use uuid::Uuid;
 
-use crate::constants::FIELD_NOT_SET;
+use crate::constants::{DEFAULT_DATE_FORMAT, FIELD_NOT_SET};
 
-#[derive(Serialize, Deserialize, Debug, Savefile, Clone, Eq, PartialEq)]
-pub struct Board {
-    pub id: u128,
-    pub name: String,
-    pub description: String,
-    pub cards: Vec<Card>,
+pub use self::board::Board;
+
+// savefile_derive puts the Savefile impls inside a const block and checks field versions
+// with comparisons, which newer compilers and clippy warn about
+#[allow(non_local_definitions, clippy::manual_range_contains)]
+mod board {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, Savefile, Clone, Eq, PartialEq)]
+    pub struct Board {
+        pub id: u128,
+        pub name: String,
+        pub description: String,
+        pub cards: Vec<Card>,
+        /// Empty for boards saved before workflows existed, see Board::workflow
+        #[serde(default)]
+        #[savefile_versions = "2.."]
+        pub workflow: Vec<WorkflowStatus>,
+        /// Archived boards are hidden from the board views, see UiMode::Archive
+        #[serde(default)]
+        #[savefile_versions = "4.."]
+        pub archived: bool,
+        /// Maximum number of unarchived cards on the board, 0 disables it
+        #[serde(default)]
+        #[savefile_versions = "8.."]
+        pub wip_limit: u16,
+    }
 }
 
 impl Board {
@@ -22,12 +51,152 @@ impl Board {
             name,
             description,
             cards: Vec::new(),
+            workflow: WorkflowStatus::default_workflow(),
+            archived: false,
+            wip_limit: 0,
         }
     }
 
     pub fn get_card(&self, id: u128) -> Option<&Card> {
         self.cards.iter().find(|c| c.id == id)
     }
+
+    /// The statuses cards on this board can have, in column order
+    pub fn workflow(&self) -> Vec<WorkflowStatus> {
+        if self.workflow.is_empty() {
+            WorkflowStatus::default_workflow()
+        } else {
+            self.workflow.clone()
+        }
+    }
+
+    /// Replaces the workflow, cards whose status is not part of it anymore move to a status of the same kind
+    pub fn set_workflow(&mut self, workflow: Vec<WorkflowStatus>) {
+        self.workflow = workflow;
+        let mut cards = std::mem::take(&mut self.cards);
+        for card in cards.iter_mut() {
+            self.adopt_card_status(card);
+        }
+        self.cards = cards;
+    }
+
+    /// First status of the workflow with the given kind, used by the status keybindings
+    pub fn workflow_status_of_kind(&self, kind: &CardStatus) -> Option<WorkflowStatus> {
+        self.workflow()
+            .into_iter()
+            .find(|workflow_status| &workflow_status.kind == kind)
+    }
+
+    pub fn find_workflow_status(&self, name: &str) -> Option<WorkflowStatus> {
+        self.workflow()
+            .into_iter()
+            .find(|workflow_status| workflow_status.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Moves a card coming from another board onto a status of this board's workflow,
+    /// preferring the same name, then the same kind, then the first column
+    pub fn adopt_card_status(&self, card: &mut Card) {
+        if self.find_workflow_status(&card.status_name()).is_some() {
+            return;
+        }
+        let workflow = self.workflow();
+        let workflow_status = self
+            .workflow_status_of_kind(&card.card_status)
+            .unwrap_or_else(|| workflow[0].clone());
+        card.set_status(&workflow_status);
+    }
+
+    /// Number of cards counted against the wip_limit, completed cards are not in progress anymore
+    pub fn wip_count(&self) -> usize {
+        self.cards
+            .iter()
+            .filter(|card| !card.archived && card.card_status != CardStatus::Complete)
+            .count()
+    }
+
+    /// Whether one more card would go over the wip_limit
+    pub fn is_at_wip_limit(&self) -> bool {
+        self.wip_limit > 0 && self.wip_count() >= self.wip_limit as usize
+    }
+
+    pub fn is_over_wip_limit(&self) -> bool {
+        self.wip_limit > 0 && self.wip_count() > self.wip_limit as usize
+    }
+
+    /// A copy of the board without its archived cards
+    pub fn without_archived_cards(&self) -> Board {
+        let mut board = self.clone();
+        board.cards.retain(|card| !card.archived);
+        board
+    }
+
+    /// Archives the cards that were completed at least after_days days ago, returns how many were archived
+    pub fn auto_archive_completed_cards(&mut self, after_days: u16) -> usize {
+        self.auto_archive_completed_cards_at(after_days, CardDate::now())
+    }
+
+    fn auto_archive_completed_cards_at(&mut self, after_days: u16, now: CardDate) -> usize {
+        let mut archived_cards = 0;
+        for card in self.cards.iter_mut().filter(|card| !card.archived) {
+            let completed_long_ago = card.card_status == CardStatus::Complete
+                && card.date_completed.is_some_and(|date_completed| {
+                    now.signed_duration_since(*date_completed) >= Duration::days(after_days.into())
+                });
+            if completed_long_ago {
+                card.set_archived(true);
+                archived_cards += 1;
+            }
+        }
+        archived_cards
+    }
+
+    /// Adds the next instance of every completed recurring card, the completed card hands its
+    /// recurrence over to the new one, returns how many cards were added
+    pub fn create_next_recurring_cards(&mut self) -> usize {
+        self.create_next_recurring_cards_at(CardDate::now())
+    }
+
+    fn create_next_recurring_cards_at(&mut self, now: CardDate) -> usize {
+        let workflow_status = self
+            .workflow_status_of_kind(&CardStatus::Active)
+            .unwrap_or_else(|| self.workflow()[0].clone());
+        let mut next_cards = vec![];
+        for card in self.cards.iter_mut().filter(|card| !card.archived) {
+            if let Some(next_card) = card.next_occurrence(&workflow_status, now) {
+                card.recurrence = None;
+                card.log_activity(format!(
+                    "Next occurrence created, due {}",
+                    next_card.date_due.unwrap_or(now)
+                ));
+                next_cards.push(next_card);
+            }
+        }
+        let created_cards = next_cards.len();
+        self.cards.extend(next_cards);
+        created_cards
+    }
+
+    /// Reorders the cards permanently, cards without a due date always end up last when sorting by due date
+    pub fn sort_cards(&mut self, key: CardSortKey, descending: bool) {
+        self.cards.sort_by(|a, b| {
+            let ordering = match key {
+                CardSortKey::DueDate => match (a.date_due, b.date_due) {
+                    (Some(a_due), Some(b_due)) => a_due.cmp(&b_due),
+                    (Some(_), None) => return Ordering::Less,
+                    (None, Some(_)) => return Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                },
+                CardSortKey::Priority => a.priority.cmp(&b.priority),
+                CardSortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+                CardSortKey::DateCreated => a.date_created.cmp(&b.date_created),
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
 }
 
 impl Default for Board {
@@ -37,15 +206,25 @@ impl Default for Board {
             name: String::from("Default Board"),
             description: String::from("Default Board Description"),
             cards: vec![Card::default()],
+            workflow: WorkflowStatus::default_workflow(),
+            archived: false,
+            wip_limit: 0,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
-pub enum CardStatus {
-    Active,
-    Complete,
-    Stale,
+pub use self::card_status::CardStatus;
+
+#[allow(non_local_definitions)]
+mod card_status {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub enum CardStatus {
+        Active,
+        Complete,
+        Stale,
+    }
 }
 
 impl fmt::Display for CardStatus {
@@ -64,11 +243,107 @@ impl CardStatus {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
-pub enum CardPriority {
-    Low,
-    Medium,
-    High,
+pub use self::workflow_status::WorkflowStatus;
+
+#[allow(non_local_definitions)]
+mod workflow_status {
+    use super::*;
+
+    /// A column of a board's workflow, its kind decides how cards in it are completed and styled
+    #[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub struct WorkflowStatus {
+        pub name: String,
+        pub kind: CardStatus,
+    }
+}
+
+impl fmt::Display for WorkflowStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl WorkflowStatus {
+    pub fn new(name: &str, kind: CardStatus) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            kind,
+        }
+    }
+
+    pub fn default_workflow() -> Vec<WorkflowStatus> {
+        CardStatus::all()
+            .into_iter()
+            .map(|kind| Self::new(&kind.to_string(), kind))
+            .collect()
+    }
+
+    /// Parses a definition like "Done:Complete", the kind defaults to Active
+    pub fn parse(definition: &str) -> Result<Self, String> {
+        let (name, kind) = match definition.rsplit_once(':') {
+            Some((name, kind)) => {
+                let kind = CardStatus::all()
+                    .into_iter()
+                    .find(|status| status.to_string().eq_ignore_ascii_case(kind.trim()))
+                    .ok_or_else(|| {
+                        format!(
+                            "Invalid status kind '{}', expected Active, Complete or Stale",
+                            kind.trim()
+                        )
+                    })?;
+                (name, kind)
+            }
+            None => (definition, CardStatus::Active),
+        };
+        if name.trim().is_empty() {
+            return Err(format!("Missing status name in '{}'", definition));
+        }
+        Ok(Self::new(name, kind))
+    }
+
+    /// Parses a comma separated list of definitions into a workflow
+    pub fn parse_workflow(definitions: &str) -> Result<Vec<Self>, String> {
+        let workflow = definitions
+            .split(',')
+            .filter(|definition| !definition.trim().is_empty())
+            .map(Self::parse)
+            .collect::<Result<Vec<Self>, String>>()?;
+        if workflow.is_empty() {
+            return Err("A workflow needs at least one status".to_string());
+        }
+        for (index, workflow_status) in workflow.iter().enumerate() {
+            if workflow[..index]
+                .iter()
+                .any(|other| other.name.eq_ignore_ascii_case(&workflow_status.name))
+            {
+                return Err(format!("Duplicate status '{}'", workflow_status.name));
+            }
+        }
+        Ok(workflow)
+    }
+
+    /// Inverse of parse_workflow
+    pub fn workflow_to_string(workflow: &[Self]) -> String {
+        workflow
+            .iter()
+            .map(|workflow_status| format!("{}:{}", workflow_status.name, workflow_status.kind))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+pub use self::card_priority::CardPriority;
+
+#[allow(non_local_definitions)]
+mod card_priority {
+    use super::*;
+
+    #[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq, PartialOrd, Ord)]
+    pub enum CardPriority {
+        Low,
+        Medium,
+        High,
+    }
 }
 
 impl fmt::Display for CardPriority {
@@ -87,26 +362,501 @@ impl CardPriority {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Savefile, Clone, PartialEq, Eq)]
-pub struct Card {
-    pub id: u128,
-    pub name: String,
-    pub description: String,
-    pub date_created: String,
-    pub date_modified: String,
-    pub date_due: String,
-    pub date_completed: String,
-    pub priority: CardPriority,
-    pub card_status: CardStatus,
-    pub tags: Vec<String>,
-    pub comments: Vec<String>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardSortKey {
+    DueDate,
+    Priority,
+    Name,
+    DateCreated,
+}
+
+impl fmt::Display for CardSortKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardSortKey::DueDate => write!(f, "Due Date"),
+            CardSortKey::Priority => write!(f, "Priority"),
+            CardSortKey::Name => write!(f, "Name"),
+            CardSortKey::DateCreated => write!(f, "Date Created"),
+        }
+    }
+}
+
+impl CardSortKey {
+    pub fn all() -> Vec<CardSortKey> {
+        vec![
+            CardSortKey::DueDate,
+            CardSortKey::Priority,
+            CardSortKey::Name,
+            CardSortKey::DateCreated,
+        ]
+    }
+
+    /// Every key in ascending and descending order, as (key, descending)
+    pub fn sort_options() -> Vec<(CardSortKey, bool)> {
+        CardSortKey::all()
+            .into_iter()
+            .flat_map(|key| [(key, false), (key, true)])
+            .collect()
+    }
+
+    pub fn sort_option_to_string(key: CardSortKey, descending: bool) -> String {
+        format!(
+            "{} ({})",
+            key,
+            if descending {
+                "Descending"
+            } else {
+                "Ascending"
+            }
+        )
+    }
+}
+
+/// How the board view groups the cards of every board into horizontal lanes
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SwimlaneGrouping {
+    #[default]
+    None,
+    Priority,
+    /// Tags starting with the prefix, e.g. "@" to group by assignee tags like "@alice"
+    TagPrefix(String),
+}
+
+impl fmt::Display for SwimlaneGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SwimlaneGrouping::None => write!(f, "None"),
+            SwimlaneGrouping::Priority => write!(f, "Priority"),
+            SwimlaneGrouping::TagPrefix(prefix) => write!(f, "Tags starting with {}", prefix),
+        }
+    }
+}
+
+impl SwimlaneGrouping {
+    /// Parses "None", "Priority" or a tag prefix, optionally written as "Tags starting with <prefix>"
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        match value.to_lowercase().as_str() {
+            "" | "none" => SwimlaneGrouping::None,
+            "priority" => SwimlaneGrouping::Priority,
+            lowercase_value => {
+                let prefix = if lowercase_value.starts_with("tags starting with ") {
+                    value["tags starting with ".len()..].trim()
+                } else {
+                    value
+                };
+                SwimlaneGrouping::TagPrefix(prefix.to_string())
+            }
+        }
+    }
+
+    fn matching_tag<'a>(prefix: &str, card: &'a Card) -> Option<&'a String> {
+        let prefix = prefix.to_lowercase();
+        card.tags
+            .iter()
+            .find(|tag| tag.to_lowercase().starts_with(&prefix))
+    }
+
+    /// Name of the lane the card belongs to, None without swimlanes
+    pub fn lane(&self, card: &Card) -> Option<String> {
+        match self {
+            SwimlaneGrouping::None => None,
+            SwimlaneGrouping::Priority => Some(format!("{} Priority", card.priority)),
+            SwimlaneGrouping::TagPrefix(prefix) => Some(
+                SwimlaneGrouping::matching_tag(prefix, card)
+                    .cloned()
+                    .unwrap_or_else(|| String::from("Other")),
+            ),
+        }
+    }
+
+    /// Stable sorts the cards lane by lane, highest priority or alphabetical tag first, untagged cards last
+    pub fn group_cards(&self, cards: &mut [Card]) {
+        match self {
+            SwimlaneGrouping::None => {}
+            SwimlaneGrouping::Priority => cards.sort_by(|a, b| b.priority.cmp(&a.priority)),
+            SwimlaneGrouping::TagPrefix(prefix) => cards.sort_by_key(|card| {
+                let tag = SwimlaneGrouping::matching_tag(prefix, card);
+                (tag.is_none(), tag.map(|tag| tag.to_lowercase()))
+            }),
+        }
+    }
+}
+
+/// A local date and time, savefile does not support chrono types so it is saved as milliseconds since the unix epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardDate(pub NaiveDateTime);
+
+impl CardDate {
+    /// Current local time, truncated to the seconds shown in the UI
+    pub fn now() -> Self {
+        let now = Local::now().naive_local();
+        Self(now.with_nanosecond(0).unwrap_or(now))
+    }
+
+    /// Parses the date formats accepted by the app, including the ones older versions saved
+    pub fn parse(date: &str) -> Option<Self> {
+        let date = date.trim();
+        for format in [
+            DEFAULT_DATE_FORMAT,
+            "%Y/%m/%d-%H:%M:%S",
+            "%Y-%m-%dT%H:%M:%S%.f",
+            "%Y-%m-%d %H:%M:%S%.f",
+        ] {
+            if let Ok(parsed_date) = NaiveDateTime::parse_from_str(date, format) {
+                return Some(Self(parsed_date));
+            }
+        }
+        // dates without a time are due at noon
+        for format in ["%d/%m/%Y", "%Y/%m/%d", "%Y-%m-%d"] {
+            if let Ok(parsed_date) = NaiveDate::parse_from_str(date, format) {
+                return Some(Self(parsed_date.and_hms_opt(12, 0, 0).unwrap()));
+            }
+        }
+        // older versions saved Utc::now().to_string()
+        if let Some(utc_date) = date.strip_suffix(" UTC") {
+            if let Ok(parsed_date) = NaiveDateTime::parse_from_str(utc_date, "%Y-%m-%d %H:%M:%S%.f")
+            {
+                return Some(Self(
+                    Utc.from_utc_datetime(&parsed_date)
+                        .with_timezone(&Local)
+                        .naive_local(),
+                ));
+            }
+        }
+        None
+    }
+
+    /// Parses the short due dates of the quick-add syntax (today, tomorrow, a weekday, 3d, 2w)
+    /// relative to now, falling back to the regular formats. Relative dates are due at noon,
+    /// negative amounts and dates past the supported range give None
+    pub fn parse_relative(date: &str, now: CardDate) -> Option<Self> {
+        let date = date.trim().to_lowercase();
+        let today = now.date();
+        let days_ahead = match date.as_str() {
+            "today" => 0,
+            "tomorrow" | "tmr" => 1,
+            _ => {
+                if let Ok(weekday) = date.parse::<Weekday>() {
+                    // a weekday always means the next one, use today for the current day
+                    let days_ahead = (weekday.num_days_from_monday() + 7
+                        - today.weekday().num_days_from_monday())
+                        % 7;
+                    if days_ahead == 0 {
+                        7
+                    } else {
+                        days_ahead.into()
+                    }
+                } else if let Some(days) = date.strip_suffix('d') {
+                    i64::from(days.parse::<u32>().ok()?)
+                } else if let Some(weeks) = date.strip_suffix('w') {
+                    i64::from(weeks.parse::<u32>().ok()?) * 7
+                } else {
+                    return Self::parse(&date);
+                }
+            }
+        };
+        let due_date = today.checked_add_signed(Duration::try_days(days_ahead)?)?;
+        Some(Self(due_date.and_hms_opt(12, 0, 0)?))
+    }
+
+    /// Like parse but treats the placeholders older versions used for missing dates as None
+    pub fn parse_optional(date: &str) -> Result<Option<Self>, String> {
+        let date = date.trim();
+        if date.is_empty() || date == FIELD_NOT_SET || date == "N/A" {
+            return Ok(None);
+        }
+        Self::parse(date)
+            .map(Some)
+            .ok_or_else(|| format!("Invalid date '{}'", date))
+    }
+}
+
+impl Deref for CardDate {
+    type Target = NaiveDateTime;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<NaiveDateTime> for CardDate {
+    fn from(date: NaiveDateTime) -> Self {
+        Self(date)
+    }
+}
+
+impl fmt::Display for CardDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.format(DEFAULT_DATE_FORMAT))
+    }
+}
+
+impl Serialize for CardDate {
+    fn serialize<S: SerdeSerializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for CardDate {
+    fn deserialize<D: SerdeDeserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        CardDate::parse(&date)
+            .ok_or_else(|| serde::de::Error::custom(format!("Invalid date '{}'", date)))
+    }
+}
+
+fn deserialize_optional_card_date<'de, D: SerdeDeserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<CardDate>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(date) => CardDate::parse_optional(&date).map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
+}
+
+impl WithSchema for CardDate {
+    fn schema(_version: u32) -> Schema {
+        Schema::Primitive(SchemaPrimitive::schema_i64)
+    }
+}
+
+impl savefile::prelude::Serialize for CardDate {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), SavefileError> {
+        serializer.write_i64(Utc.from_utc_datetime(&self.0).timestamp_millis())
+    }
+}
+
+impl savefile::prelude::Deserialize for CardDate {
+    fn deserialize(deserializer: &mut Deserializer) -> Result<Self, SavefileError> {
+        let timestamp = deserializer.read_i64()?;
+        Utc.timestamp_millis_opt(timestamp)
+            .single()
+            .map(|date| Self(date.naive_utc()))
+            .ok_or_else(|| SavefileError::GeneralError {
+                msg: format!("Invalid timestamp {}", timestamp),
+            })
+    }
+}
+
+impl Introspect for CardDate {
+    fn introspect_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn introspect_child(&self, _index: usize) -> Option<Box<dyn IntrospectItem<'_> + '_>> {
+        None
+    }
+}
+
+pub use self::card::Card;
+
+#[allow(non_local_definitions, clippy::manual_range_contains)]
+mod card {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, Savefile, Clone, PartialEq, Eq)]
+    pub struct Card {
+        pub id: u128,
+        pub name: String,
+        pub description: String,
+        pub date_created: CardDate,
+        pub date_modified: CardDate,
+        #[serde(default, deserialize_with = "deserialize_optional_card_date")]
+        pub date_due: Option<CardDate>,
+        #[serde(default, deserialize_with = "deserialize_optional_card_date")]
+        pub date_completed: Option<CardDate>,
+        pub priority: CardPriority,
+        pub card_status: CardStatus,
+        pub tags: Vec<String>,
+        pub comments: Vec<String>,
+        /// Name of the board workflow status, card_status holds its kind
+        #[serde(default)]
+        #[savefile_versions = "2.."]
+        pub workflow_status: String,
+        #[serde(default)]
+        #[savefile_versions = "3.."]
+        pub history: Vec<CardActivity>,
+        #[serde(default)]
+        #[savefile_versions = "4.."]
+        pub archived: bool,
+        #[serde(default)]
+        #[savefile_versions = "5.."]
+        pub checklist: Vec<ChecklistItem>,
+        #[serde(default)]
+        #[savefile_versions = "6.."]
+        pub links: Vec<CardLink>,
+        /// Moves to the next occurrence once the card is completed
+        #[serde(default)]
+        #[savefile_versions = "7.."]
+        pub recurrence: Option<CardRecurrence>,
+        /// Day of the month a monthly recurrence falls on, kept while shorter months move the due date earlier
+        #[serde(default)]
+        #[savefile_versions = "9.."]
+        pub recurrence_day: Option<u32>,
+    }
+}
+
+pub use self::checklist_item::ChecklistItem;
+
+#[allow(non_local_definitions)]
+mod checklist_item {
+    use super::*;
+
+    /// A sub-task of a card
+    #[derive(Debug, Clone, Default, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub struct ChecklistItem {
+        pub name: String,
+        pub done: bool,
+    }
+}
+
+impl ChecklistItem {
+    pub fn new(name: String) -> Self {
+        Self { name, done: false }
+    }
+}
+
+impl fmt::Display for ChecklistItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}] {}", if self.done { "x" } else { " " }, self.name)
+    }
+}
+
+pub use self::card_recurrence::CardRecurrence;
+
+#[allow(non_local_definitions)]
+mod card_recurrence {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub enum CardRecurrence {
+        Daily,
+        Weekly,
+        Monthly,
+    }
+}
+
+impl fmt::Display for CardRecurrence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardRecurrence::Daily => write!(f, "Daily"),
+            CardRecurrence::Weekly => write!(f, "Weekly"),
+            CardRecurrence::Monthly => write!(f, "Monthly"),
+        }
+    }
+}
+
+impl CardRecurrence {
+    /// The choices offered when editing a card, None stops the recurrence
+    pub fn options() -> Vec<Option<CardRecurrence>> {
+        vec![
+            None,
+            Some(CardRecurrence::Daily),
+            Some(CardRecurrence::Weekly),
+            Some(CardRecurrence::Monthly),
+        ]
+    }
+
+    pub fn option_to_string(recurrence: Option<CardRecurrence>) -> String {
+        recurrence
+            .map(|recurrence| recurrence.to_string())
+            .unwrap_or_else(|| "Never".to_string())
+    }
+
+    /// The date one period after date, monthly recurrences fall on day_of_month (or the date's day)
+    /// and on the last day of months that are too short for it
+    pub fn next_date(&self, date: CardDate, day_of_month: Option<u32>) -> CardDate {
+        let next_date = match self {
+            CardRecurrence::Daily => *date + Duration::days(1),
+            CardRecurrence::Weekly => *date + Duration::weeks(1),
+            CardRecurrence::Monthly => {
+                let next_date = date
+                    .checked_add_months(Months::new(1))
+                    .unwrap_or(*date + Duration::days(30));
+                day_of_month
+                    .and_then(|day| {
+                        (next_date.day()..=day)
+                            .rev()
+                            .find_map(|day| next_date.with_day(day))
+                    })
+                    .unwrap_or(next_date)
+            }
+        };
+        CardDate(next_date)
+    }
+}
+
+pub use self::card_link_kind::CardLinkKind;
+
+#[allow(non_local_definitions)]
+mod card_link_kind {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub enum CardLinkKind {
+        BlockedBy,
+        RelatesTo,
+    }
+}
+
+impl fmt::Display for CardLinkKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardLinkKind::BlockedBy => write!(f, "Blocked by"),
+            CardLinkKind::RelatesTo => write!(f, "Relates to"),
+        }
+    }
+}
+
+impl CardLinkKind {
+    pub fn toggle(&self) -> Self {
+        match self {
+            CardLinkKind::BlockedBy => CardLinkKind::RelatesTo,
+            CardLinkKind::RelatesTo => CardLinkKind::BlockedBy,
+        }
+    }
+}
+
+pub use self::card_link::CardLink;
+
+#[allow(non_local_definitions)]
+mod card_link {
+    use super::*;
+
+    /// A reference from a card to another card by id
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub struct CardLink {
+        pub kind: CardLinkKind,
+        pub card_id: u128,
+    }
+}
+
+pub use self::card_activity::CardActivity;
+
+#[allow(non_local_definitions)]
+mod card_activity {
+    use super::*;
+
+    /// An entry of a card's activity log
+    #[derive(Debug, Clone, Serialize, Deserialize, Savefile, PartialEq, Eq)]
+    pub struct CardActivity {
+        pub date: CardDate,
+        pub description: String,
+    }
+}
+
+impl fmt::Display for CardActivity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.date, self.description)
+    }
 }
 
 impl Card {
     pub fn new(
         name: String,
         description: String,
-        date_due: String,
+        date_due: Option<CardDate>,
         priority: CardPriority,
         tags: Vec<String>,
         comments: Vec<String>,
@@ -121,11 +871,6 @@ impl Card {
         } else {
             &description
         };
-        let date_due = if date_due.is_empty() {
-            FIELD_NOT_SET
-        } else {
-            &date_due
-        };
         let priority = if priority.to_string().is_empty() {
             CardPriority::Low
         } else {
@@ -138,20 +883,407 @@ impl Card {
             comments
         };
 
+        let date_created = CardDate::now();
         Self {
             id: get_id(),
             name: name.to_string(),
             description: description.to_string(),
-            date_created: Utc::now().to_string(),
-            date_modified: Utc::now().to_string(),
-            date_due: date_due.to_string(),
-            date_completed: "N/A".to_string(),
+            date_created,
+            date_modified: date_created,
+            date_due,
+            date_completed: None,
             priority,
             card_status: CardStatus::Active,
             tags,
             comments,
+            workflow_status: CardStatus::Active.to_string(),
+            history: vec![CardActivity {
+                date: date_created,
+                description: "Card created".to_string(),
+            }],
+            archived: false,
+            checklist: Vec::new(),
+            links: Vec::new(),
+            recurrence: None,
+            recurrence_day: None,
+        }
+    }
+}
+
+impl Card {
+    /// Appends an entry to the activity log and stamps date_modified
+    pub fn log_activity(&mut self, description: String) {
+        let now = CardDate::now();
+        self.date_modified = now;
+        self.history.push(CardActivity {
+            date: now,
+            description,
+        });
+    }
+
+    pub fn set_recurrence(&mut self, recurrence: Option<CardRecurrence>) {
+        if self.recurrence != recurrence {
+            self.log_activity(format!(
+                "Recurrence changed from {} to {}",
+                CardRecurrence::option_to_string(self.recurrence),
+                CardRecurrence::option_to_string(recurrence)
+            ));
+        }
+        self.recurrence = recurrence;
+    }
+
+    /// The next instance of a completed recurring card, due one period after this card's due
+    /// date or completion date and never in the past
+    pub fn next_occurrence(&self, workflow_status: &WorkflowStatus, now: CardDate) -> Option<Card> {
+        let recurrence = self.recurrence?;
+        if self.card_status != CardStatus::Complete {
+            return None;
+        }
+        // a due date on the last day of a month keeps the later day it was moved back from
+        let recurrence_day = self.date_due.map(|date_due| match self.recurrence_day {
+            Some(day) if day > date_due.day() && (*date_due + Duration::days(1)).day() == 1 => day,
+            _ => date_due.day(),
+        });
+        let mut date_due = recurrence.next_date(
+            self.date_due.or(self.date_completed).unwrap_or(now),
+            recurrence_day,
+        );
+        while date_due < now {
+            date_due = recurrence.next_date(date_due, recurrence_day);
+        }
+        let mut card = self.clone();
+        card.id = get_id();
+        card.recurrence_day = recurrence_day;
+        card.date_created = now;
+        card.date_modified = now;
+        card.date_due = Some(date_due);
+        card.date_completed = None;
+        card.card_status = workflow_status.kind.clone();
+        card.workflow_status = workflow_status.name.clone();
+        card.comments = Vec::new();
+        card.checklist.iter_mut().for_each(|item| item.done = false);
+        card.archived = false;
+        card.history = vec![CardActivity {
+            date: now,
+            description: format!(
+                "Card created as the next {} occurrence",
+                recurrence.to_string().to_lowercase()
+            ),
+        }];
+        Some(card)
+    }
+
+    /// A copy of the card with a new id and a fresh activity log
+    pub fn duplicate(&self) -> Card {
+        let now = CardDate::now();
+        let mut card = self.clone();
+        card.id = get_id();
+        card.name = format!("{} (copy)", self.name);
+        card.date_created = now;
+        card.date_modified = now;
+        card.history = vec![CardActivity {
+            date: now,
+            description: format!("Card duplicated from '{}'", self.name),
+        }];
+        card
+    }
+
+    /// Changes the priority, logging the change
+    pub fn set_priority(&mut self, priority: CardPriority) {
+        if self.priority != priority {
+            self.log_activity(format!(
+                "Priority changed from {} to {}",
+                self.priority, priority
+            ));
+        }
+        self.priority = priority;
+    }
+
+    /// Logs the changes between this card and its edited version, status and priority are
+    /// logged as they change so they are left out here
+    pub fn log_edits(&mut self, original: &Card) {
+        if self.name != original.name {
+            self.log_activity(format!(
+                "Name changed from '{}' to '{}'",
+                original.name, self.name
+            ));
+        }
+        if self.description != original.description {
+            self.log_activity("Description edited".to_string());
+        }
+        if self.date_due != original.date_due {
+            let format_due = |date_due: Option<CardDate>| {
+                date_due
+                    .map(|date_due| date_due.to_string())
+                    .unwrap_or_else(|| FIELD_NOT_SET.to_string())
+            };
+            self.log_activity(format!(
+                "Due date changed from {} to {}",
+                format_due(original.date_due),
+                format_due(self.date_due)
+            ));
+        }
+        if self.tags != original.tags {
+            self.log_activity(format!("Tags changed to [{}]", self.tags.join(", ")));
+        }
+        if self.comments != original.comments {
+            self.log_activity("Comments edited".to_string());
+        }
+        if self.links != original.links {
+            self.log_activity("Links edited".to_string());
+        }
+        if self.checklist != original.checklist {
+            let (done, total) = self.checklist_progress();
+            self.log_activity(format!("Checklist edited ({}/{} done)", done, total));
+        }
+    }
+
+    /// The card as a structured text file for an external editor, read back with apply_editor_text.
+    /// The due date is passed as text as the card view keeps it unparsed while editing
+    pub fn to_editor_text(&self, date_due: &str) -> String {
+        let mut text = String::from(
+            "# Lines starting with # are ignored, everything below Description: is kept as is\n",
+        );
+        text.push_str(&format!("Name: {}\n", self.name));
+        text.push_str(&format!("Due: {}\n", date_due));
+        text.push_str(&format!("Priority: {}\n", self.priority));
+        text.push_str(&format!("Tags: {}\n", self.tags.join(", ")));
+        text.push_str("Checklist:\n");
+        for item in &self.checklist {
+            let check = if item.done { 'x' } else { ' ' };
+            text.push_str(&format!("- [{}] {}\n", check, item.name));
+        }
+        text.push_str("Description:\n");
+        if self.description != FIELD_NOT_SET {
+            text.push_str(&self.description);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Applies a file written by to_editor_text, returning the due date text. Nothing is changed
+    /// if the file cannot be parsed
+    pub fn apply_editor_text(&mut self, text: &str) -> Result<String, String> {
+        let mut name = None;
+        let mut date_due = String::new();
+        let mut priority = self.priority.clone();
+        let mut tags = Vec::new();
+        let mut checklist = Vec::new();
+        let mut in_checklist = false;
+        let mut lines = text.lines();
+        let mut description = None;
+        for line in lines.by_ref() {
+            let trimmed_line = line.trim();
+            if trimmed_line.is_empty() || trimmed_line.starts_with('#') {
+                continue;
+            }
+            if trimmed_line == "Description:" {
+                description = Some(String::new());
+                break;
+            }
+            if trimmed_line == "Checklist:" {
+                in_checklist = true;
+                continue;
+            }
+            if in_checklist {
+                let item = trimmed_line
+                    .strip_prefix("- [ ]")
+                    .map(|item| (item, false))
+                    .or_else(|| {
+                        trimmed_line
+                            .strip_prefix("- [x]")
+                            .or_else(|| trimmed_line.strip_prefix("- [X]"))
+                            .map(|item| (item, true))
+                    });
+                if let Some((item, done)) = item {
+                    checklist.push(ChecklistItem {
+                        name: item.trim().to_string(),
+                        done,
+                    });
+                    continue;
+                }
+                return Err(format!("Invalid checklist item '{}'", trimmed_line));
+            }
+            let (key, value) = trimmed_line
+                .split_once(':')
+                .ok_or_else(|| format!("Invalid line '{}'", trimmed_line))?;
+            let value = value.trim();
+            match key.trim().to_lowercase().as_str() {
+                "name" => name = Some(value.to_string()),
+                "due" => date_due = value.to_string(),
+                "priority" => {
+                    priority = CardPriority::all()
+                        .into_iter()
+                        .find(|p| p.to_string().to_lowercase() == value.to_lowercase())
+                        .ok_or_else(|| {
+                            format!("Invalid priority '{}', expected Low, Medium or High", value)
+                        })?
+                }
+                "tags" => {
+                    tags = value
+                        .split(',')
+                        .map(|tag| tag.trim().to_string())
+                        .filter(|tag| !tag.is_empty())
+                        .collect()
+                }
+                _ => return Err(format!("Unknown field '{}'", key.trim())),
+            }
+        }
+        let name = match name {
+            Some(name) if !name.is_empty() => name,
+            _ => return Err("The card name cannot be empty".to_string()),
+        };
+        let description = match description {
+            Some(_) => lines
+                .collect::<Vec<&str>>()
+                .join("\n")
+                .trim_end()
+                .to_string(),
+            None => return Err("Description: is missing".to_string()),
+        };
+        self.name = name;
+        self.priority = priority;
+        self.tags = tags;
+        self.checklist = checklist;
+        self.description = if description.is_empty() {
+            FIELD_NOT_SET.to_string()
+        } else {
+            description
+        };
+        Ok(date_due)
+    }
+
+    /// Creates a card from a single line like "Fix login bug #backend #urgent !high @fri", where
+    /// #word adds a tag, !low/!medium/!high sets the priority and @date sets the due date. Any other
+    /// @word (e.g. "@alice") is kept as a tag so it can be used for swimlanes
+    pub fn from_quick_add(text: &str, now: CardDate) -> Result<Card, String> {
+        let mut name = Vec::new();
+        let mut tags: Vec<String> = Vec::new();
+        let mut priority = CardPriority::Low;
+        let mut date_due = None;
+        for word in text.split_whitespace() {
+            if let Some(tag) = word.strip_prefix('#').filter(|tag| !tag.is_empty()) {
+                if !tags.iter().any(|existing_tag| existing_tag == tag) {
+                    tags.push(tag.to_string());
+                }
+            } else if let Some(value) = word.strip_prefix('!').filter(|value| !value.is_empty()) {
+                priority = CardPriority::all()
+                    .into_iter()
+                    .find(|p| p.to_string().to_lowercase() == value.to_lowercase())
+                    .ok_or_else(|| {
+                        format!("Invalid priority '{}', expected Low, Medium or High", value)
+                    })?;
+            } else if let Some(value) = word.strip_prefix('@').filter(|value| !value.is_empty()) {
+                if let Some(date) = CardDate::parse_relative(value, now) {
+                    date_due = Some(date);
+                } else if !tags.iter().any(|existing_tag| existing_tag == word) {
+                    tags.push(word.to_string());
+                }
+            } else {
+                name.push(word);
+            }
+        }
+        if name.is_empty() {
+            return Err("The card name cannot be empty".to_string());
+        }
+        Ok(Card::new(
+            name.join(" "),
+            String::new(),
+            date_due,
+            priority,
+            tags,
+            vec![],
+        ))
+    }
+
+    /// Links this card to another card, returns false if the link already exists or points to
+    /// the card itself
+    pub fn add_link(&mut self, kind: CardLinkKind, card_id: u128) -> bool {
+        let link = CardLink { kind, card_id };
+        if card_id == self.id || self.links.contains(&link) {
+            return false;
+        }
+        self.links.push(link);
+        true
+    }
+
+    /// Cards blocking this card that are not complete yet
+    pub fn open_blockers<'a>(&self, boards: &'a [Board]) -> Vec<&'a Card> {
+        self.links
+            .iter()
+            .filter(|link| link.kind == CardLinkKind::BlockedBy)
+            .filter_map(|link| find_card(boards, link.card_id))
+            .filter(|card| card.card_status != CardStatus::Complete)
+            .collect()
+    }
+
+    /// Number of done checklist items and the total number of items
+    pub fn checklist_progress(&self) -> (usize, usize) {
+        let done = self.checklist.iter().filter(|item| item.done).count();
+        (done, self.checklist.len())
+    }
+
+    /// Share of the checklist that is done between 0.0 and 1.0, None if the card has no checklist
+    pub fn completion_ratio(&self) -> Option<f64> {
+        let (done, total) = self.checklist_progress();
+        if total == 0 {
+            None
+        } else {
+            Some(done as f64 / total as f64)
+        }
+    }
+
+    pub fn set_archived(&mut self, archived: bool) {
+        if self.archived == archived {
+            return;
+        }
+        self.archived = archived;
+        self.log_activity(if archived {
+            "Archived".to_string()
+        } else {
+            "Restored from the archive".to_string()
+        });
+    }
+
+    /// Changes the status, stamping date_modified and recording or clearing date_completed
+    pub fn set_status(&mut self, workflow_status: &WorkflowStatus) {
+        if self.status_name() != workflow_status.name {
+            self.log_activity(format!(
+                "Status changed from {} to {}",
+                self.status_name(),
+                workflow_status.name
+            ));
+        }
+        let now = CardDate::now();
+        self.stamp_date_completed(&workflow_status.kind, now);
+        self.card_status = workflow_status.kind.clone();
+        self.workflow_status = workflow_status.name.clone();
+        self.date_modified = now;
+    }
+
+    /// Records when the card got completed, or clears it once the card is not complete anymore
+    pub fn stamp_date_completed(&mut self, kind: &CardStatus, now: CardDate) {
+        if *kind != CardStatus::Complete {
+            self.date_completed = None;
+        } else if self.date_completed.is_none() {
+            self.date_completed = Some(now);
+        }
+    }
+
+    /// Name of the workflow status, cards saved before workflows existed fall back to their kind
+    pub fn status_name(&self) -> String {
+        if self.workflow_status.is_empty() {
+            self.card_status.to_string()
+        } else {
+            self.workflow_status.clone()
         }
     }
+
+    /// Time taken from creation to completion, None if the card is not complete
+    pub fn time_to_complete(&self) -> Option<Duration> {
+        self.date_completed
+            .map(|date_completed| date_completed.signed_duration_since(*self.date_created))
+    }
 }
 
 impl Default for Card {
@@ -160,18 +1292,538 @@ impl Default for Card {
             id: get_id(),
             name: String::from("Default Card"),
             description: String::from("Default Card Description"),
-            date_created: Utc::now().to_string(),
-            date_modified: Utc::now().to_string(),
-            date_due: FIELD_NOT_SET.to_string(),
-            date_completed: FIELD_NOT_SET.to_string(),
+            date_created: CardDate::now(),
+            date_modified: CardDate::now(),
+            date_due: None,
+            date_completed: None,
             priority: CardPriority::Low,
             card_status: CardStatus::Active,
             tags: Vec::new(),
             comments: Vec::new(),
+            workflow_status: CardStatus::Active.to_string(),
+            history: Vec::new(),
+            archived: false,
+            checklist: Vec::new(),
+            links: Vec::new(),
+            recurrence: None,
+            recurrence_day: None,
         }
     }
 }
 
+/// A due date condition of a card filter, durations are counted from now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueFilter {
+    Within(Duration),
+    After(Duration),
+    Overdue,
+    NotSet,
+}
+
+impl DueFilter {
+    fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim().to_lowercase();
+        match value.as_str() {
+            "overdue" => return Ok(DueFilter::Overdue),
+            "none" => return Ok(DueFilter::NotSet),
+            _ => {}
+        }
+        let (within, duration) = if let Some(duration) = value.strip_prefix('<') {
+            (true, duration)
+        } else if let Some(duration) = value.strip_prefix('>') {
+            (false, duration)
+        } else {
+            return Err(format!(
+                "Invalid due filter '{}', expected <Nd, >Nd, <Nw, >Nw, overdue or none",
+                value
+            ));
+        };
+        let invalid_duration =
+            || format!("Invalid duration '{}', expected e.g. 7d or 2w", duration);
+        let duration = if let Some(amount) = duration.strip_suffix('d') {
+            Duration::try_days(amount.parse::<i64>().map_err(|_| invalid_duration())?)
+        } else if let Some(amount) = duration.strip_suffix('w') {
+            Duration::try_weeks(amount.parse::<i64>().map_err(|_| invalid_duration())?)
+        } else {
+            return Err(invalid_duration());
+        }
+        .ok_or_else(|| format!("Duration '{}' is out of range", duration))?;
+        if within {
+            Ok(DueFilter::Within(duration))
+        } else {
+            Ok(DueFilter::After(duration))
+        }
+    }
+
+    fn matches(&self, date_due: Option<CardDate>, now: CardDate) -> bool {
+        match (self, date_due) {
+            (DueFilter::NotSet, date_due) => date_due.is_none(),
+            (_, None) => false,
+            // a limit past the supported dates is later than any due date
+            (DueFilter::Within(duration), Some(date_due)) => now
+                .checked_add_signed(*duration)
+                .is_none_or(|limit| *date_due <= limit),
+            (DueFilter::After(duration), Some(date_due)) => now
+                .checked_add_signed(*duration)
+                .is_some_and(|limit| *date_due > limit),
+            (DueFilter::Overdue, Some(date_due)) => date_due < now,
+        }
+    }
+}
+
+/// Cards matching an expression like "tag:backend priority:high status:active due:<7d", comma
+/// separated values of a key are alternatives, every key and bare word must match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardFilter {
+    pub expression: String,
+    tags: Vec<String>,
+    priorities: Vec<CardPriority>,
+    statuses: Vec<String>,
+    due: Vec<DueFilter>,
+    words: Vec<String>,
+}
+
+impl CardFilter {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let mut filter = CardFilter {
+            expression: expression
+                .split_whitespace()
+                .collect::<Vec<&str>>()
+                .join(" "),
+            tags: Vec::new(),
+            priorities: Vec::new(),
+            statuses: Vec::new(),
+            due: Vec::new(),
+            words: Vec::new(),
+        };
+        if filter.expression.is_empty() {
+            return Err("Filter cannot be empty".to_string());
+        }
+        for token in expression.split_whitespace() {
+            let (key, values) = if let Some((key, values)) = token.split_once(':') {
+                (key.to_lowercase(), values)
+            } else {
+                filter.words.push(token.to_lowercase());
+                continue;
+            };
+            let values = values
+                .split(',')
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .collect::<Vec<&str>>();
+            if values.is_empty() {
+                return Err(format!("Missing value for filter '{}'", key));
+            }
+            for value in values {
+                match key.as_str() {
+                    "tag" => filter.tags.push(value.to_lowercase()),
+                    "priority" => filter.priorities.push(
+                        CardPriority::all()
+                            .into_iter()
+                            .find(|priority| priority.to_string().eq_ignore_ascii_case(value))
+                            .ok_or_else(|| {
+                                format!(
+                                    "Invalid priority '{}', expected Low, Medium or High",
+                                    value
+                                )
+                            })?,
+                    ),
+                    "status" => filter.statuses.push(value.to_lowercase()),
+                    "due" => filter.due.push(DueFilter::parse(value)?),
+                    _ => {
+                        return Err(format!(
+                            "Unknown filter '{}', expected tag, priority, status or due",
+                            key
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(filter)
+    }
+
+    pub fn matches(&self, card: &Card) -> bool {
+        self.matches_at(card, CardDate::now())
+    }
+
+    fn matches_at(&self, card: &Card, now: CardDate) -> bool {
+        let status_name = card.status_name().to_lowercase();
+        let card_status = card.card_status.to_string().to_lowercase();
+        (self.tags.is_empty()
+            || card
+                .tags
+                .iter()
+                .any(|tag| self.tags.contains(&tag.to_lowercase())))
+            && (self.priorities.is_empty() || self.priorities.contains(&card.priority))
+            && (self.statuses.is_empty()
+                || self
+                    .statuses
+                    .iter()
+                    .any(|status| *status == status_name || *status == card_status))
+            && (self.due.is_empty() || self.due.iter().any(|due| due.matches(card.date_due, now)))
+            && self.words.iter().all(|word| {
+                card.name.to_lowercase().contains(word)
+                    || card.description.to_lowercase().contains(word)
+            })
+    }
+}
+
+impl fmt::Display for CardFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+/// Looks a card up by id across all boards
+pub fn find_card(boards: &[Board], card_id: u128) -> Option<&Card> {
+    boards.iter().find_map(|board| board.get_card(card_id))
+}
+
 fn get_id() -> u128 {
     Uuid::new_v4().as_u128()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_stamp_and_clear_completion_date_on_status_change() {
+        let mut card = Card::default();
+        let done = WorkflowStatus::new("Done", CardStatus::Complete);
+        card.set_status(&done);
+        let date_completed = card.date_completed;
+        assert!(date_completed.is_some());
+        assert!(card.time_to_complete().is_some());
+
+        card.set_status(&done);
+        assert_eq!(card.date_completed, date_completed);
+
+        card.set_status(&WorkflowStatus::new("Review", CardStatus::Active));
+        assert_eq!(card.date_completed, None);
+        assert_eq!(card.time_to_complete(), None);
+        assert_eq!(card.status_name(), "Review");
+    }
+
+    #[test]
+    fn should_compute_checklist_completion_ratio() {
+        let mut card = Card::default();
+        assert_eq!(card.completion_ratio(), None);
+
+        card.checklist = vec![
+            ChecklistItem::new("Write tests".to_string()),
+            ChecklistItem::new("Update docs".to_string()),
+        ];
+        card.checklist[0].done = true;
+        assert_eq!(card.checklist_progress(), (1, 2));
+        assert_eq!(card.completion_ratio(), Some(0.5));
+        assert_eq!(card.checklist[0].to_string(), "[x] Write tests");
+    }
+
+    #[test]
+    fn should_find_open_blockers() {
+        let mut blocker = Card::default();
+        let related = Card::default();
+        let mut card = Card::default();
+        assert!(card.add_link(CardLinkKind::BlockedBy, blocker.id));
+        assert!(card.add_link(CardLinkKind::RelatesTo, related.id));
+        assert!(!card.add_link(CardLinkKind::BlockedBy, blocker.id));
+        assert!(!card.add_link(CardLinkKind::RelatesTo, card.id));
+
+        let boards = vec![Board {
+            cards: vec![blocker.clone(), related.clone()],
+            ..Default::default()
+        }];
+        assert_eq!(card.open_blockers(&boards), vec![&boards[0].cards[0]]);
+
+        blocker.set_status(&WorkflowStatus::new("Done", CardStatus::Complete));
+        let board = Board {
+            cards: vec![blocker, related],
+            ..Default::default()
+        };
+        assert!(card.open_blockers(&[board]).is_empty());
+    }
+
+    #[test]
+    fn should_parse_workflow_definitions() {
+        let workflow =
+            WorkflowStatus::parse_workflow("Backlog, In Progress, Review, Done:complete").unwrap();
+        assert_eq!(workflow.len(), 4);
+        assert_eq!(
+            workflow[1],
+            WorkflowStatus::new("In Progress", CardStatus::Active)
+        );
+        assert_eq!(workflow[3].kind, CardStatus::Complete);
+        assert_eq!(
+            WorkflowStatus::parse_workflow(&WorkflowStatus::workflow_to_string(&workflow)),
+            Ok(workflow)
+        );
+        assert!(WorkflowStatus::parse_workflow("Done, done").is_err());
+        assert!(WorkflowStatus::parse_workflow("Done:finished").is_err());
+    }
+
+    #[test]
+    fn should_log_card_activity() {
+        let original = Card::new(
+            "Fix login".to_string(),
+            String::new(),
+            None,
+            CardPriority::Low,
+            vec![],
+            vec![],
+        );
+        let mut card = original.clone();
+        card.set_priority(CardPriority::High);
+        card.set_priority(CardPriority::High);
+        card.name = "Fix logout".to_string();
+        card.log_edits(&original);
+
+        let history = card
+            .history
+            .iter()
+            .map(|activity| activity.description.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            history,
+            vec![
+                "Card created",
+                "Priority changed from Low to High",
+                "Name changed from 'Fix login' to 'Fix logout'"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_filter_cards() {
+        let now = CardDate::parse("10/01/2023-12:00:00").unwrap();
+        let mut card = Card::new(
+            "Fix login".to_string(),
+            "Users get logged out".to_string(),
+            CardDate::parse("14/01/2023-12:00:00"),
+            CardPriority::High,
+            vec!["Backend".to_string()],
+            vec![],
+        );
+        let matches = |expression: &str, card: &Card| {
+            CardFilter::parse(expression).unwrap().matches_at(card, now)
+        };
+        assert!(matches(
+            "tag:backend priority:high status:active due:<7d",
+            &card
+        ));
+        assert!(matches("tag:frontend,backend priority:low,high", &card));
+        assert!(matches("logged", &card));
+        assert!(!matches("due:<3d", &card));
+        assert!(!matches("due:overdue", &card));
+        assert!(!matches("priority:high logged tag:frontend", &card));
+        assert!(matches("due:<100000000d", &card));
+        assert!(!matches("due:>100000000w", &card));
+        card.set_status(&WorkflowStatus::new("Done", CardStatus::Complete));
+        assert!(matches("status:done", &card));
+        assert!(matches("status:complete", &card));
+
+        assert!(CardFilter::parse("").is_err());
+        assert!(CardFilter::parse("owner:me").is_err());
+        assert!(CardFilter::parse("priority:urgent").is_err());
+        assert!(CardFilter::parse("due:soon").is_err());
+        assert!(CardFilter::parse("tag:").is_err());
+        assert!(CardFilter::parse("due:<7é").is_err());
+        assert!(CardFilter::parse("due:>é").is_err());
+        assert!(CardFilter::parse("due:<").is_err());
+        assert!(CardFilter::parse("due:<99999999999999d").is_err());
+        assert!(CardFilter::parse("due:>9223372036854775807w").is_err());
+    }
+
+    #[test]
+    fn should_sort_cards() {
+        let mut board = Board::new("Todo".to_string(), String::new());
+        for (name, due, priority) in [
+            ("b", None, CardPriority::High),
+            ("C", CardDate::parse("12/01/2023"), CardPriority::Low),
+            ("a", CardDate::parse("11/01/2023"), CardPriority::Medium),
+        ] {
+            board.cards.push(Card::new(
+                name.to_string(),
+                String::new(),
+                due,
+                priority,
+                vec![],
+                vec![],
+            ));
+        }
+        let names = |board: &Board| {
+            board
+                .cards
+                .iter()
+                .map(|card| card.name.clone())
+                .collect::<Vec<String>>()
+        };
+        board.sort_cards(CardSortKey::Name, false);
+        assert_eq!(names(&board), vec!["a", "b", "C"]);
+        board.sort_cards(CardSortKey::Priority, true);
+        assert_eq!(names(&board), vec!["b", "a", "C"]);
+        board.sort_cards(CardSortKey::DueDate, true);
+        assert_eq!(names(&board), vec!["C", "a", "b"]);
+        board.sort_cards(CardSortKey::DueDate, false);
+        assert_eq!(names(&board), vec!["a", "C", "b"]);
+    }
+
+    #[test]
+    fn should_auto_archive_completed_cards() {
+        let mut board = Board::new("Todo".to_string(), String::new());
+        board.cards = vec![Card::default(), Card::default()];
+        board.cards[0].set_status(&WorkflowStatus::new("Done", CardStatus::Complete));
+        let completed = board.cards[0].date_completed.unwrap();
+        let days_later = |days| CardDate(*completed + Duration::days(days));
+
+        assert_eq!(board.auto_archive_completed_cards_at(7, days_later(6)), 0);
+        assert_eq!(board.auto_archive_completed_cards_at(7, days_later(7)), 1);
+        assert!(board.cards[0].archived);
+        assert!(!board.cards[1].archived);
+        assert_eq!(board.auto_archive_completed_cards_at(7, days_later(8)), 0);
+        assert_eq!(board.without_archived_cards().cards.len(), 1);
+    }
+
+    #[test]
+    fn should_respect_wip_limit() {
+        let mut board = Board::new("Doing".to_string(), String::new());
+        board.cards = vec![Card::default(), Card::default()];
+        assert!(!board.is_at_wip_limit());
+
+        board.wip_limit = 2;
+        assert!(board.is_at_wip_limit());
+        assert!(!board.is_over_wip_limit());
+        board.cards[0].set_archived(true);
+        assert!(!board.is_at_wip_limit());
+        board.cards.extend([Card::default(), Card::default()]);
+        assert!(board.is_over_wip_limit());
+        board.cards[1].set_status(&WorkflowStatus::new("Done", CardStatus::Complete));
+        assert!(board.is_at_wip_limit());
+        assert!(!board.is_over_wip_limit());
+    }
+
+    #[test]
+    fn should_parse_quick_add_text() {
+        // a Wednesday
+        let now = CardDate::parse("15/05/2024-09:30:00").unwrap();
+        let card = Card::from_quick_add("Fix login bug #backend #urgent !high @fri #backend", now)
+            .unwrap();
+        assert_eq!(card.name, "Fix login bug");
+        assert_eq!(card.tags, vec!["backend", "urgent"]);
+        assert_eq!(card.priority, CardPriority::High);
+        assert_eq!(card.date_due, CardDate::parse("17/05/2024-12:00:00"));
+        let due = |value| CardDate::parse_relative(value, now).map(|date| date.to_string());
+        assert_eq!(due("wed"), due("22/05/2024"));
+        assert_eq!(due("Tomorrow"), due("16/05/2024"));
+        assert_eq!(due("2w"), due("29/05/2024"));
+        assert!(Card::from_quick_add("#only !low", now).is_err());
+        assert!(Card::from_quick_add("Card !urgent", now).is_err());
+        let card = Card::from_quick_add("Review PR @alice @3d @", now).unwrap();
+        assert_eq!(card.name, "Review PR @");
+        assert_eq!(card.tags, vec!["@alice"]);
+        assert_eq!(card.date_due, CardDate::parse("18/05/2024-12:00:00"));
+        assert_eq!(due("-5d"), None);
+        assert_eq!(due("99999999999999d"), None);
+        assert_eq!(due("4294967295w"), None);
+        let card = Card::from_quick_add("Deploy @99999999999999d @-5d", now).unwrap();
+        assert_eq!(card.name, "Deploy");
+        assert_eq!(card.tags, vec!["@99999999999999d", "@-5d"]);
+        assert_eq!(card.date_due, None);
+    }
+
+    #[test]
+    fn should_round_trip_editor_text() {
+        let card = Card {
+            tags: vec![String::from("bug"), String::from("ui")],
+            checklist: vec![ChecklistItem::new(String::from("reproduce"))],
+            description: String::from("# Steps\n\n1. open the app"),
+            ..Default::default()
+        };
+        let text = card
+            .to_editor_text("2023-05-01")
+            .replace("Priority: Low", "Priority: high")
+            .replace("- [ ] reproduce", "- [x] reproduce");
+
+        let mut edited_card = card.clone();
+        assert_eq!(
+            edited_card.apply_editor_text(&text),
+            Ok(String::from("2023-05-01"))
+        );
+        assert_eq!(edited_card.priority, CardPriority::High);
+        assert_eq!(edited_card.tags, card.tags);
+        assert!(edited_card.checklist[0].done);
+        assert_eq!(edited_card.description, card.description);
+
+        let text = text.replace("Priority: high", "Priority: urgent");
+        assert!(edited_card.clone().apply_editor_text(&text).is_err());
+    }
+
+    #[test]
+    fn should_group_cards_into_swimlanes() {
+        let mut cards = vec![Card::default(), Card::default(), Card::default()];
+        cards[0].tags = vec![String::from("backend")];
+        cards[1].tags = vec![String::from("@Sam")];
+        cards[2].tags = vec![String::from("@alex"), String::from("frontend")];
+        let grouping = SwimlaneGrouping::parse("Tags starting with @");
+        assert_eq!(grouping, SwimlaneGrouping::TagPrefix(String::from("@")));
+
+        grouping.group_cards(&mut cards);
+        let lanes = cards
+            .iter()
+            .map(|card| grouping.lane(card).unwrap())
+            .collect::<Vec<String>>();
+        assert_eq!(lanes, vec!["@alex", "@Sam", "Other"]);
+        assert_eq!(SwimlaneGrouping::parse(" none "), SwimlaneGrouping::None);
+    }
+
+    #[test]
+    fn should_create_next_recurring_card() {
+        let mut board = Board::new("Chores".to_string(), String::new());
+        board.cards = vec![Card::default()];
+        let due = CardDate::parse("2023-01-31").unwrap();
+        board.cards[0].date_due = Some(due);
+        board.cards[0].set_recurrence(Some(CardRecurrence::Monthly));
+        assert_eq!(board.create_next_recurring_cards_at(due), 0);
+
+        board.cards[0].set_status(&WorkflowStatus::new("Done", CardStatus::Complete));
+        assert_eq!(board.create_next_recurring_cards_at(due), 1);
+        assert_eq!(board.cards[0].recurrence, None);
+        let next_card = &board.cards[1];
+        assert_eq!(next_card.date_due, CardDate::parse("2023-02-28"));
+        assert_eq!(next_card.recurrence, Some(CardRecurrence::Monthly));
+        assert_eq!(next_card.card_status, CardStatus::Active);
+        assert_eq!(board.create_next_recurring_cards_at(due), 0);
+
+        // monthly recurrences go back to the 31st after shorter months
+        let mut card = board.cards[1].clone();
+        let mut due_dates = vec![];
+        for _ in 0..4 {
+            card.card_status = CardStatus::Complete;
+            card = card
+                .next_occurrence(&WorkflowStatus::new("Todo", CardStatus::Active), due)
+                .unwrap();
+            due_dates.push(card.date_due.unwrap().to_string());
+        }
+        let expected_due_dates = ["2023-03-31", "2023-04-30", "2023-05-31", "2023-06-30"]
+            .iter()
+            .map(|date| CardDate::parse(date).unwrap().to_string())
+            .collect::<Vec<String>>();
+        assert_eq!(due_dates, expected_due_dates);
+
+        // missed occurrences are skipped
+        let card = Card {
+            recurrence: Some(CardRecurrence::Weekly),
+            date_due: Some(due),
+            card_status: CardStatus::Complete,
+            ..Default::default()
+        };
+        let now = CardDate(*due + Duration::days(10));
+        let next_card = card
+            .next_occurrence(&WorkflowStatus::new("Todo", CardStatus::Active), now)
+            .unwrap();
+        assert_eq!(
+            next_card.date_due,
+            Some(CardDate(*due + Duration::weeks(2)))
+        );
+    }
+}
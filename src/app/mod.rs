@@ -3,6 +3,7 @@ use log::{debug, error, info};
 use ratatui::widgets::{ListState, TableState};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::BTreeMap,
     fmt::{self, Display, Formatter},
     path::PathBuf,
     time::{Duration, Instant},
@@ -15,16 +16,20 @@ use self::{
         handle_general_actions, handle_keybind_mode, handle_mouse_action, handle_user_input_mode,
         prepare_config_for_new_app,
     },
-    kanban::{Board, Card, CardPriority},
+    kanban::{
+        Board, Card, CardFilter, CardLinkKind, CardPriority, CardRecurrence, CardSortKey,
+        SwimlaneGrouping,
+    },
     state::{AppStatus, Focus, KeyBindings, UiMode},
 };
 use crate::{
-    app::{actions::Action, kanban::CardStatus},
+    app::{actions::Action, kanban::WorkflowStatus},
     constants::{
         DEFAULT_CARD_WARNING_DUE_DATE_DAYS, DEFAULT_TICKRATE, DEFAULT_TOAST_DURATION,
-        IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD, MIN_NO_BOARDS_PER_PAGE,
-        MIN_NO_CARDS_PER_BOARD, MOUSE_OUT_OF_BOUNDS_COORDINATES, NO_OF_BOARDS_PER_PAGE,
-        NO_OF_CARDS_PER_BOARD,
+        DEFAULT_WORKSPACE_NAME, IO_EVENT_WAIT_TIME, MAX_NO_BOARDS_PER_PAGE, MAX_NO_CARDS_PER_BOARD,
+        MAX_UNDO_STATES, MIN_NO_BOARDS_PER_PAGE, MIN_NO_CARDS_PER_BOARD,
+        MOUSE_OUT_OF_BOUNDS_COORDINATES, NO_OF_BOARDS_PER_PAGE, NO_OF_CARDS_PER_BOARD,
+        WORKSPACES_DIR_NAME,
     },
     inputs::{key::Key, mouse::Mouse},
     io::{
@@ -36,7 +41,8 @@ use crate::{
         IoEvent,
     },
     ui::{
-        widgets::{CommandPaletteWidget, ToastType, ToastWidget},
+        text_input::TextInput,
+        widgets::{CardSearchWidget, CommandPaletteWidget, ToastType, ToastWidget},
         TextColorOptions, TextModifierOptions, Theme,
     },
 };
@@ -50,6 +56,8 @@ pub mod state;
 pub enum AppReturn {
     Exit,
     Continue,
+    /// Suspend the UI and edit the card being edited in $VISUAL or $EDITOR
+    OpenInEditor,
 }
 
 /// The main application, containing the state
@@ -62,11 +70,17 @@ pub struct App {
     pub config: AppConfig,
     pub config_item_being_edited: Option<usize>,
     pub card_being_edited: Option<(u128, Card)>, // (board_id, card)
+    pub card_due_date_being_edited: String,
     pub visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>>,
     pub command_palette: CommandPaletteWidget,
+    pub card_search: CardSearchWidget,
     pub last_io_event_time: Option<Instant>,
     pub all_themes: Vec<Theme>,
     pub theme: Theme,
+    pub undo_stack: Vec<Vec<Board>>,
+    pub redo_stack: Vec<Vec<Board>>,
+    /// The boards from before the event being handled, only taken for events that can change them
+    boards_before_event: Option<Vec<Board>>,
 }
 
 impl App {
@@ -95,24 +109,34 @@ impl App {
             config,
             config_item_being_edited: None,
             card_being_edited: None,
+            card_due_date_being_edited: String::new(),
             visible_boards_and_cards: LinkedHashMap::new(),
             command_palette: CommandPaletteWidget::new(),
+            card_search: CardSearchWidget::default(),
             last_io_event_time: None,
             all_themes,
             theme,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+            boards_before_event: None,
         }
     }
 
     /// Handle a user action
     pub async fn do_action(&mut self, key: Key) -> AppReturn {
+        if self.key_may_change_boards(key) {
+            self.boards_before_event = Some(self.boards.clone());
+        }
         // check if we are in a user input mode
-        if self.state.app_status == AppStatus::UserInput {
+        let app_return = if self.state.app_status == AppStatus::UserInput {
             handle_user_input_mode(self, key).await
         } else if self.state.app_status == AppStatus::KeyBindMode {
             handle_keybind_mode(self, key).await
         } else {
             handle_general_actions(self, key).await
-        }
+        };
+        self.record_undo_state();
+        app_return
     }
     /// Send a network event to the IO thread
     pub async fn dispatch(&mut self, action: IoEvent) {
@@ -137,7 +161,95 @@ impl App {
     }
 
     pub async fn handle_mouse(&mut self, mouse_action: Mouse) -> AppReturn {
-        handle_mouse_action(self, mouse_action).await
+        // only clicks can change the boards, dragged cards are dropped with a click as well
+        if let Mouse::LeftPress | Mouse::RightPress | Mouse::MiddlePress = mouse_action {
+            self.boards_before_event = Some(self.boards.clone());
+        }
+        let app_return = handle_mouse_action(self, mouse_action).await;
+        self.record_undo_state();
+        app_return
+    }
+
+    /// Whether a key can change the boards, typing and moving around never do so the boards are not
+    /// snapshotted for them
+    fn key_may_change_boards(&self, key: Key) -> bool {
+        match self.state.app_status {
+            AppStatus::UserInput => key == Key::Enter,
+            AppStatus::KeyBindMode => false,
+            _ => {
+                let action = self
+                    .config
+                    .keybindings
+                    .clone()
+                    .key_to_action(key)
+                    .or_else(|| Action::iterator().find(|action| action.keys().contains(&key)));
+                action.is_some_and(|action| {
+                    !matches!(
+                        action,
+                        Action::Quit
+                            | Action::NextFocus
+                            | Action::PrvFocus
+                            | Action::OpenConfigMenu
+                            | Action::Up
+                            | Action::Down
+                            | Action::Right
+                            | Action::Left
+                            | Action::TakeUserInput
+                            | Action::HideUiElement
+                            | Action::SaveState
+                            | Action::NewBoard
+                            | Action::NewCard
+                            | Action::ResetUI
+                            | Action::GoToMainMenu
+                            | Action::ToggleCommandPalette
+                            | Action::ClearAllToasts
+                            | Action::Undo
+                            | Action::Redo
+                            | Action::SearchCards
+                            | Action::FilterCards
+                            | Action::SortCards
+                            | Action::OpenInEditor
+                            | Action::QuickAddCard
+                    )
+                })
+            }
+        }
+    }
+
+    /// Pushes the boards as they were before an event onto the undo stack if the event changed them
+    fn record_undo_state(&mut self) {
+        let boards_before_event = self.boards_before_event.take();
+        if let Some(boards) = boards_before_event.filter(|boards| *boards != self.boards) {
+            self.undo_stack.push(boards);
+            if self.undo_stack.len() > MAX_UNDO_STATES {
+                self.undo_stack.remove(0);
+            }
+            self.redo_stack.clear();
+        }
+    }
+
+    /// Restores the boards from before the last change, returns false if there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+        if let Some(boards) = self.undo_stack.pop() {
+            self.redo_stack
+                .push(std::mem::replace(&mut self.boards, boards));
+            self.boards_before_event = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reapplies the last undone change, returns false if there is nothing to redo
+    pub fn redo(&mut self) -> bool {
+        if let Some(boards) = self.redo_stack.pop() {
+            self.undo_stack
+                .push(std::mem::replace(&mut self.boards, boards));
+            self.boards_before_event = None;
+            true
+        } else {
+            false
+        }
     }
 
     pub fn actions(&self) -> &Actions {
@@ -159,8 +271,102 @@ impl App {
         }
         self.state.app_status = AppStatus::initialized()
     }
+    /// Unarchived boards with only the unarchived cards matching the active filter
+    pub fn filtered_boards(&self) -> Vec<Board> {
+        let mut boards = self
+            .boards
+            .iter()
+            .filter(|board| !board.archived)
+            .map(|board| board.without_archived_cards())
+            .collect::<Vec<Board>>();
+        if let Some(filter) = &self.state.active_filter {
+            for board in boards.iter_mut() {
+                board.cards.retain(|card| filter.matches(card));
+            }
+        }
+        for board in boards.iter_mut() {
+            self.config.swimlanes.group_cards(&mut board.cards);
+        }
+        boards
+    }
     pub fn set_boards(&mut self, boards: Vec<Board>) {
         self.boards = boards;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+    /// Adds the next instance of every completed recurring card, returns how many were added
+    pub fn create_next_recurring_cards(&mut self) -> usize {
+        let created_cards = self
+            .boards
+            .iter_mut()
+            .map(|board| board.create_next_recurring_cards())
+            .sum::<usize>();
+        if created_cards > 0 {
+            info!("Created {} recurring cards", created_cards);
+            self.send_info_toast(&format!("Created {} recurring cards", created_cards), None);
+        }
+        created_cards
+    }
+    /// Archives the cards completed more than auto_archive_completed_cards_after_days days ago, 0 disables it
+    pub fn auto_archive_completed_cards(&mut self) {
+        let after_days = self.config.auto_archive_completed_cards_after_days;
+        if after_days == 0 {
+            return;
+        }
+        let archived_cards = self
+            .boards
+            .iter_mut()
+            .map(|board| board.auto_archive_completed_cards(after_days))
+            .sum::<usize>();
+        if archived_cards > 0 {
+            info!(
+                "Archived {} cards completed more than {} days ago",
+                archived_cards, after_days
+            );
+            self.send_info_toast(
+                &format!(
+                    "Archived {} cards completed more than {} days ago",
+                    archived_cards, after_days
+                ),
+                None,
+            );
+        }
+    }
+    /// Archived boards followed by the archived cards of unarchived boards
+    pub fn archived_items(&self) -> Vec<ArchivedItem> {
+        let archived_boards = self
+            .boards
+            .iter()
+            .filter(|board| board.archived)
+            .map(|board| ArchivedItem::Board(board.id));
+        let archived_cards = self
+            .boards
+            .iter()
+            .filter(|board| !board.archived)
+            .flat_map(|board| {
+                board
+                    .cards
+                    .iter()
+                    .filter(|card| card.archived)
+                    .map(|card| ArchivedItem::Card(board.id, card.id))
+            });
+        archived_boards.chain(archived_cards).collect()
+    }
+    pub fn archive_next(&mut self) {
+        let no_of_archived_items = self.archived_items().len();
+        let i = match self.state.archive_list_state.selected() {
+            Some(i) if i + 1 < no_of_archived_items => i + 1,
+            _ => 0,
+        };
+        self.state.archive_list_state.select(Some(i));
+    }
+    pub fn archive_prev(&mut self) {
+        let no_of_archived_items = self.archived_items().len();
+        let i = match self.state.archive_list_state.selected() {
+            Some(0) | None => no_of_archived_items.saturating_sub(1),
+            Some(i) => i - 1,
+        };
+        self.state.archive_list_state.select(Some(i));
     }
     pub fn loaded(&mut self) {
         self.is_loading = false;
@@ -408,6 +614,153 @@ impl App {
         };
         self.state.command_palette_list_state.select(Some(i));
     }
+    pub fn card_search_up(&mut self) {
+        let no_of_results = self.card_search.search_results.len();
+        if no_of_results == 0 {
+            return;
+        }
+        let i = match self.state.card_search_list_state.selected() {
+            Some(0) | None => no_of_results - 1,
+            Some(i) => i - 1,
+        };
+        self.state.card_search_list_state.select(Some(i));
+    }
+    pub fn card_search_down(&mut self) {
+        let no_of_results = self.card_search.search_results.len();
+        if no_of_results == 0 {
+            return;
+        }
+        let i = match self.state.card_search_list_state.selected() {
+            Some(i) if i + 1 < no_of_results => i + 1,
+            _ => 0,
+        };
+        self.state.card_search_list_state.select(Some(i));
+    }
+    /// Focuses a card, scrolling the visible boards and cards if it is not on screen
+    pub fn jump_to_card(&mut self, board_id: u128, card_id: u128) {
+        let card_is_filtered_out = self.state.active_filter.as_ref().is_some_and(|filter| {
+            self.boards
+                .iter()
+                .flat_map(|b| b.cards.iter())
+                .any(|c| c.id == card_id && !filter.matches(c))
+        });
+        if card_is_filtered_out {
+            self.state.active_filter = None;
+            info!("Cleared the active filter to show the card");
+            self.send_info_toast("Cleared the active filter to show the card", None);
+        }
+        let boards = self.filtered_boards();
+        let board_index = if let Some(index) = boards.iter().position(|b| b.id == board_id) {
+            index
+        } else {
+            debug!("Cannot jump to card: board {} not found", board_id);
+            return;
+        };
+        self.state.current_board_id = Some(board_id);
+        self.state.current_card_id = Some(card_id);
+        if self
+            .visible_boards_and_cards
+            .get(&board_id)
+            .is_some_and(|cards| cards.contains(&card_id))
+        {
+            return;
+        }
+        let no_of_boards = self.config.no_of_boards_to_show as usize;
+        let no_of_cards = self.config.no_of_cards_to_show as usize;
+        let first_board_index = if self.visible_boards_and_cards.contains_key(&board_id) {
+            boards
+                .iter()
+                .position(|b| self.visible_boards_and_cards.keys().next() == Some(&b.id))
+                .unwrap_or(board_index)
+        } else {
+            board_index.min(boards.len().saturating_sub(no_of_boards))
+        };
+        let mut visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>> = LinkedHashMap::new();
+        for board in boards.iter().skip(first_board_index).take(no_of_boards) {
+            let visible_cards = if board.id == board_id {
+                let card_index = board
+                    .cards
+                    .iter()
+                    .position(|c| c.id == card_id)
+                    .unwrap_or(0);
+                let first_card_index =
+                    card_index.min(board.cards.len().saturating_sub(no_of_cards));
+                board
+                    .cards
+                    .iter()
+                    .skip(first_card_index)
+                    .take(no_of_cards)
+                    .map(|c| c.id)
+                    .collect()
+            } else if let Some(visible_cards) = self.visible_boards_and_cards.get(&board.id) {
+                visible_cards.clone()
+            } else {
+                board.cards.iter().take(no_of_cards).map(|c| c.id).collect()
+            };
+            visible_boards_and_cards.insert(board.id, visible_cards);
+        }
+        self.visible_boards_and_cards = visible_boards_and_cards;
+    }
+    pub fn saved_filter_up(&mut self) {
+        let no_of_saved_filters = self.config.saved_filters.len();
+        if no_of_saved_filters == 0 {
+            return;
+        }
+        let i = match self.state.saved_filters_list_state.selected() {
+            Some(0) | None => no_of_saved_filters - 1,
+            Some(i) => i - 1,
+        };
+        self.select_saved_filter(i);
+    }
+    pub fn saved_filter_down(&mut self) {
+        let no_of_saved_filters = self.config.saved_filters.len();
+        if no_of_saved_filters == 0 {
+            return;
+        }
+        let i = match self.state.saved_filters_list_state.selected() {
+            Some(i) if i + 1 < no_of_saved_filters => i + 1,
+            _ => 0,
+        };
+        self.select_saved_filter(i);
+    }
+    pub fn workspace_up(&mut self) {
+        let no_of_workspaces = self.state.workspaces.len();
+        if no_of_workspaces == 0 {
+            return;
+        }
+        let i = match self.state.workspace_list_state.selected() {
+            Some(0) | None => no_of_workspaces - 1,
+            Some(i) => i - 1,
+        };
+        self.select_workspace(i);
+    }
+    pub fn workspace_down(&mut self) {
+        let no_of_workspaces = self.state.workspaces.len();
+        if no_of_workspaces == 0 {
+            return;
+        }
+        let i = match self.state.workspace_list_state.selected() {
+            Some(i) if i + 1 < no_of_workspaces => i + 1,
+            _ => 0,
+        };
+        self.select_workspace(i);
+    }
+    /// Selects a workspace and copies its name into the workspace input
+    pub fn select_workspace(&mut self, index: usize) {
+        if let Some(workspace) = self.state.workspaces.get(index) {
+            self.state.current_user_input = workspace.clone();
+            self.state.text_input.reset();
+            self.state.workspace_list_state.select(Some(index));
+        }
+    }
+    /// Selects a saved filter and copies its expression into the filter input
+    pub fn select_saved_filter(&mut self, index: usize) {
+        if let Some(expression) = self.config.saved_filters.values().nth(index) {
+            self.state.current_user_input = expression.clone();
+            self.state.text_input.reset();
+            self.state.saved_filters_list_state.select(Some(index));
+        }
+    }
     pub fn keybind_list_maker(&mut self) {
         let keybinds = &self.config.keybindings;
         let default_actions = &self.actions;
@@ -516,11 +869,19 @@ impl App {
             ));
         }
     }
+    /// Workflow of the current board, the default workflow when no board is selected
+    pub fn current_board_workflow(&self) -> Vec<WorkflowStatus> {
+        self.state
+            .current_board_id
+            .and_then(|board_id| self.boards.iter().find(|board| board.id == board_id))
+            .map(|board| board.workflow())
+            .unwrap_or_else(WorkflowStatus::default_workflow)
+    }
     pub fn select_card_status_prev(&mut self) {
         let i = match self.state.card_status_selector_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    CardStatus::all().len() - 1
+                    self.current_board_workflow().len() - 1
                 } else {
                     i - 1
                 }
@@ -532,7 +893,7 @@ impl App {
     pub fn select_card_status_next(&mut self) {
         let i = match self.state.card_status_selector_state.selected() {
             Some(i) => {
-                if i >= CardStatus::all().len() - 1 {
+                if i >= self.current_board_workflow().len() - 1 {
                     0
                 } else {
                     i + 1
@@ -542,6 +903,56 @@ impl App {
         };
         self.state.card_status_selector_state.select(Some(i));
     }
+    /// Number of history entries of the card shown in the card view
+    fn current_card_history_len(&self) -> usize {
+        if let Some((_, card)) = &self.card_being_edited {
+            return card.history.len();
+        }
+        self.state
+            .current_board_id
+            .and_then(|board_id| self.boards.iter().find(|board| board.id == board_id))
+            .and_then(|board| {
+                self.state
+                    .current_card_id
+                    .and_then(|card_id| board.get_card(card_id))
+            })
+            .map(|card| card.history.len())
+            .unwrap_or(0)
+    }
+    pub fn select_card_history_prev(&mut self) {
+        let history_len = self.current_card_history_len();
+        if history_len == 0 {
+            return;
+        }
+        let i = match self.state.card_view_history_list_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    history_len - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.card_view_history_list_state.select(Some(i));
+    }
+    pub fn select_card_history_next(&mut self) {
+        let history_len = self.current_card_history_len();
+        if history_len == 0 {
+            return;
+        }
+        let i = match self.state.card_view_history_list_state.selected() {
+            Some(i) => {
+                if i >= history_len - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.card_view_history_list_state.select(Some(i));
+    }
     pub fn increase_loading_toast_time(&mut self, msg: &str, increase_by: Duration) {
         let toast = self.state.toasts.iter_mut().find(|x| x.message == msg);
         if toast.is_none() {
@@ -709,6 +1120,70 @@ impl App {
         };
         self.state.card_priority_selector_state.select(Some(i));
     }
+    pub fn select_card_recurrence_next(&mut self) {
+        let no_of_options = CardRecurrence::options().len();
+        let i = match self.state.card_recurrence_selector_state.selected() {
+            Some(i) if i + 1 < no_of_options => i + 1,
+            _ => 0,
+        };
+        self.state.card_recurrence_selector_state.select(Some(i));
+    }
+    pub fn select_card_recurrence_prev(&mut self) {
+        let no_of_options = CardRecurrence::options().len();
+        let i = match self.state.card_recurrence_selector_state.selected() {
+            Some(0) | None => no_of_options - 1,
+            Some(i) => i - 1,
+        };
+        self.state.card_recurrence_selector_state.select(Some(i));
+    }
+    pub fn context_menu_next(&mut self) {
+        let no_of_items = self.state.context_menu_items.len();
+        if no_of_items == 0 {
+            return;
+        }
+        let i = match self.state.context_menu_state.selected() {
+            Some(i) if i + 1 < no_of_items => i + 1,
+            _ => 0,
+        };
+        self.state.context_menu_state.select(Some(i));
+    }
+    pub fn context_menu_prev(&mut self) {
+        let no_of_items = self.state.context_menu_items.len();
+        if no_of_items == 0 {
+            return;
+        }
+        let i = match self.state.context_menu_state.selected() {
+            Some(0) | None => no_of_items - 1,
+            Some(i) => i - 1,
+        };
+        self.state.context_menu_state.select(Some(i));
+    }
+    pub fn select_sort_option_next(&mut self) {
+        let i = match self.state.sort_cards_list_state.selected() {
+            Some(i) => {
+                if i >= CardSortKey::sort_options().len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.sort_cards_list_state.select(Some(i));
+    }
+    pub fn select_sort_option_prev(&mut self) {
+        let i = match self.state.sort_cards_list_state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    CardSortKey::sort_options().len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.sort_cards_list_state.select(Some(i));
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -717,6 +1192,8 @@ pub enum MainMenuItem {
     Config,
     Help,
     LoadSave,
+    Archive,
+    Workspaces,
     Quit,
 }
 
@@ -727,6 +1204,8 @@ impl Display for MainMenuItem {
             MainMenuItem::Config => write!(f, "Configure"),
             MainMenuItem::Help => write!(f, "Help"),
             MainMenuItem::LoadSave => write!(f, "Load a Save"),
+            MainMenuItem::Archive => write!(f, "Browse the Archive"),
+            MainMenuItem::Workspaces => write!(f, "Switch Workspace"),
             MainMenuItem::Quit => write!(f, "Quit"),
         }
     }
@@ -743,6 +1222,8 @@ impl MainMenu {
             MainMenuItem::Config,
             MainMenuItem::Help,
             MainMenuItem::LoadSave,
+            MainMenuItem::Archive,
+            MainMenuItem::Workspaces,
             MainMenuItem::Quit,
         ]
     }
@@ -753,7 +1234,9 @@ impl MainMenu {
             1 => MainMenuItem::Config,
             2 => MainMenuItem::Help,
             3 => MainMenuItem::LoadSave,
-            4 => MainMenuItem::Quit,
+            4 => MainMenuItem::Archive,
+            5 => MainMenuItem::Workspaces,
+            6 => MainMenuItem::Quit,
             _ => MainMenuItem::Quit,
         }
     }
@@ -775,6 +1258,20 @@ pub enum PopupMode {
     CustomRGBPromptBG,
     ConfirmDiscardCardChanges,
     CardPrioritySelector,
+    CardRecurrenceSelector,
+    ConfirmDestructiveAction(DestructiveAction),
+    CardSearch,
+    FilterCards,
+    SaveFilterPrompt,
+    SortCards,
+    LinkCard,
+    SetWipLimitPrompt,
+    SetWorkflowPrompt,
+    /// Asks for the JSON export to import, true replaces the boards instead of merging into them
+    ImportFromJSONPrompt(bool),
+    SwitchWorkspace,
+    ContextMenu,
+    QuickAddCard,
 }
 
 impl Display for PopupMode {
@@ -794,6 +1291,19 @@ impl Display for PopupMode {
             PopupMode::CustomRGBPromptBG => write!(f, "Custom RGB Prompt"),
             PopupMode::ConfirmDiscardCardChanges => write!(f, "Confirm Discard Card Changes"),
             PopupMode::CardPrioritySelector => write!(f, "Change Card Priority"),
+            PopupMode::CardRecurrenceSelector => write!(f, "Change Card Recurrence"),
+            PopupMode::ConfirmDestructiveAction(_) => write!(f, "Confirm Action"),
+            PopupMode::CardSearch => write!(f, "Search Cards"),
+            PopupMode::FilterCards => write!(f, "Filter Cards"),
+            PopupMode::SaveFilterPrompt => write!(f, "Save Filter Prompt"),
+            PopupMode::SortCards => write!(f, "Sort Cards"),
+            PopupMode::LinkCard => write!(f, "Link Card"),
+            PopupMode::SetWipLimitPrompt => write!(f, "Set WIP Limit Prompt"),
+            PopupMode::SetWorkflowPrompt => write!(f, "Set Workflow Prompt"),
+            PopupMode::ImportFromJSONPrompt(_) => write!(f, "Import from JSON Prompt"),
+            PopupMode::SwitchWorkspace => write!(f, "Switch Workspace"),
+            PopupMode::ContextMenu => write!(f, "Context Menu"),
+            PopupMode::QuickAddCard => write!(f, "Quick Add Card"),
         }
     }
 }
@@ -806,8 +1316,12 @@ impl PopupMode {
                 Focus::CardDueDate,
                 Focus::CardPriority,
                 Focus::CardStatus,
+                Focus::CardRecurrence,
                 Focus::CardTags,
                 Focus::CardComments,
+                Focus::CardChecklist,
+                Focus::CardLinks,
+                Focus::CardHistory,
                 Focus::SubmitButton,
             ],
             PopupMode::CommandPalette => vec![],
@@ -828,10 +1342,133 @@ impl PopupMode {
             PopupMode::CustomRGBPromptBG => vec![Focus::TextInput, Focus::SubmitButton],
             PopupMode::ConfirmDiscardCardChanges => vec![Focus::SubmitButton, Focus::ExtraFocus],
             PopupMode::CardPrioritySelector => vec![],
+            PopupMode::CardRecurrenceSelector => vec![],
+            PopupMode::ConfirmDestructiveAction(_) => vec![Focus::SubmitButton, Focus::ExtraFocus],
+            PopupMode::CardSearch => vec![],
+            PopupMode::FilterCards => vec![],
+            PopupMode::SaveFilterPrompt => vec![],
+            PopupMode::SortCards => vec![],
+            PopupMode::LinkCard => vec![],
+            PopupMode::SetWipLimitPrompt => vec![],
+            PopupMode::SetWorkflowPrompt => vec![],
+            PopupMode::ImportFromJSONPrompt(_) => vec![],
+            PopupMode::QuickAddCard => vec![],
+            PopupMode::SwitchWorkspace => vec![],
+            PopupMode::ContextMenu => vec![],
+        }
+    }
+}
+
+/// An entry of the archive view, cards are stored as (board id, card id)
+#[derive(Clone, PartialEq, Debug, Copy)]
+pub enum ArchivedItem {
+    Board(u128),
+    Card(u128, u128),
+}
+
+/// Actions that ask for confirmation before running, unless skip_confirmation_dialogs is set
+#[derive(Clone, PartialEq, Debug, Copy)]
+pub enum DestructiveAction {
+    DeleteCard,
+    DeleteBoard,
+    DeleteSave,
+    ResetConfig,
+    ResetConfigAndKeybindings,
+    ReplaceBoardsWithImport,
+}
+
+impl Display for DestructiveAction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            DestructiveAction::DeleteCard => write!(f, "Delete Card"),
+            DestructiveAction::DeleteBoard => write!(f, "Delete Board"),
+            DestructiveAction::DeleteSave => write!(f, "Delete Save File"),
+            DestructiveAction::ResetConfig => write!(f, "Reset Config"),
+            DestructiveAction::ResetConfigAndKeybindings => {
+                write!(f, "Reset Config and Keybindings")
+            }
+            DestructiveAction::ReplaceBoardsWithImport => write!(f, "Replace Boards with Import"),
+        }
+    }
+}
+
+/// Entries of the right click menu, MoveCardTo is listed once per board after MoveCardToBoard is picked
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ContextMenuItem {
+    EditCard,
+    ChangeCardStatus,
+    ChangeCardPriority,
+    MoveCardToBoard,
+    DuplicateCard,
+    ArchiveCard,
+    DeleteCard,
+    MoveCardTo(u128, String),
+    SetWipLimit,
+    SetWorkflow,
+    SortCards,
+    ArchiveBoard,
+    DeleteBoard,
+}
+
+impl Display for ContextMenuItem {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ContextMenuItem::EditCard => write!(f, "Edit"),
+            ContextMenuItem::ChangeCardStatus => write!(f, "Change Status"),
+            ContextMenuItem::ChangeCardPriority => write!(f, "Change Priority"),
+            ContextMenuItem::MoveCardToBoard => write!(f, "Move to Board..."),
+            ContextMenuItem::DuplicateCard => write!(f, "Duplicate"),
+            ContextMenuItem::ArchiveCard => write!(f, "Archive"),
+            ContextMenuItem::DeleteCard => write!(f, "Delete"),
+            ContextMenuItem::MoveCardTo(_, board_name) => write!(f, "{}", board_name),
+            ContextMenuItem::SetWipLimit => write!(f, "Set WIP Limit"),
+            ContextMenuItem::SetWorkflow => write!(f, "Set Workflow"),
+            ContextMenuItem::SortCards => write!(f, "Sort Cards"),
+            ContextMenuItem::ArchiveBoard => write!(f, "Archive"),
+            ContextMenuItem::DeleteBoard => write!(f, "Delete"),
         }
     }
 }
 
+impl ContextMenuItem {
+    /// Items of the right click menu for a card, or for the board when card_id is None
+    pub fn items_for(card_id: Option<u128>) -> Vec<Self> {
+        if card_id.is_some() {
+            ContextMenuItem::card_items()
+        } else {
+            ContextMenuItem::board_items()
+        }
+    }
+    /// One item per board a card of from_board_id can be moved to, archived boards are left out
+    pub fn move_card_to_items(boards: &[Board], from_board_id: u128) -> Vec<Self> {
+        boards
+            .iter()
+            .filter(|board| !board.archived && board.id != from_board_id)
+            .map(|board| ContextMenuItem::MoveCardTo(board.id, board.name.clone()))
+            .collect()
+    }
+    pub fn card_items() -> Vec<Self> {
+        vec![
+            ContextMenuItem::EditCard,
+            ContextMenuItem::ChangeCardStatus,
+            ContextMenuItem::ChangeCardPriority,
+            ContextMenuItem::MoveCardToBoard,
+            ContextMenuItem::DuplicateCard,
+            ContextMenuItem::ArchiveCard,
+            ContextMenuItem::DeleteCard,
+        ]
+    }
+    pub fn board_items() -> Vec<Self> {
+        vec![
+            ContextMenuItem::SetWipLimit,
+            ContextMenuItem::SetWorkflow,
+            ContextMenuItem::SortCards,
+            ContextMenuItem::ArchiveBoard,
+            ContextMenuItem::DeleteBoard,
+        ]
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AppState {
     pub app_status: AppStatus,
@@ -845,12 +1482,13 @@ pub struct AppState {
     pub new_board_form: Vec<String>,
     pub new_card_form: Vec<String>,
     pub load_save_state: ListState,
+    pub archive_list_state: ListState,
     pub edit_keybindings_state: TableState,
     pub edited_keybinding: Option<Vec<Key>>,
     pub help_state: TableState,
     pub keybind_store: Vec<Vec<String>>,
     pub default_view_state: ListState,
-    pub current_cursor_position: Option<usize>,
+    pub text_input: TextInput,
     pub toasts: Vec<ToastWidget>,
     pub term_background_color: (u8, u8, u8),
     pub preview_boards_and_cards: Option<Vec<Board>>,
@@ -877,7 +1515,27 @@ pub struct AppState {
     pub card_view_list_state: ListState,
     pub card_view_tag_list_state: ListState,
     pub card_view_comment_list_state: ListState,
+    pub card_view_checklist_list_state: ListState,
+    pub card_view_link_list_state: ListState,
+    pub card_view_history_list_state: ListState,
     pub card_priority_selector_state: ListState,
+    pub card_recurrence_selector_state: ListState,
+    pub card_search_list_state: ListState,
+    pub active_filter: Option<CardFilter>,
+    pub saved_filters_list_state: ListState,
+    pub sort_cards_list_state: ListState,
+    pub card_link_kind: CardLinkKind,
+    /// Filled when the workspace switcher opens, see get_workspaces
+    pub workspaces: Vec<String>,
+    pub workspace_list_state: ListState,
+    /// (board_id, card_id) of the card being dragged with the mouse
+    pub dragged_card: Option<(u128, u128)>,
+    /// Board and card under the mouse, set by render_body every frame
+    pub hovered_board_and_card: Option<(u128, Option<u128>)>,
+    pub context_menu_items: Vec<ContextMenuItem>,
+    pub context_menu_state: ListState,
+    /// Mouse coordinates the context menu was opened at
+    pub context_menu_position: (u16, u16),
 }
 
 impl Default for AppState {
@@ -894,12 +1552,13 @@ impl Default for AppState {
             new_board_form: vec![String::new(), String::new()],
             new_card_form: vec![String::new(), String::new(), String::new()],
             load_save_state: ListState::default(),
+            archive_list_state: ListState::default(),
             edit_keybindings_state: TableState::default(),
             edited_keybinding: None,
             help_state: TableState::default(),
             keybind_store: Vec::new(),
             default_view_state: ListState::default(),
-            current_cursor_position: None,
+            text_input: TextInput::default(),
             toasts: Vec::new(),
             term_background_color: get_term_bg_color(),
             preview_boards_and_cards: None,
@@ -930,7 +1589,23 @@ impl Default for AppState {
             card_view_list_state: ListState::default(),
             card_view_tag_list_state: ListState::default(),
             card_view_comment_list_state: ListState::default(),
+            card_view_checklist_list_state: ListState::default(),
+            card_view_link_list_state: ListState::default(),
+            card_view_history_list_state: ListState::default(),
             card_priority_selector_state: ListState::default(),
+            card_recurrence_selector_state: ListState::default(),
+            card_search_list_state: ListState::default(),
+            active_filter: None,
+            saved_filters_list_state: ListState::default(),
+            sort_cards_list_state: ListState::default(),
+            card_link_kind: CardLinkKind::BlockedBy,
+            workspaces: Vec::new(),
+            workspace_list_state: ListState::default(),
+            dragged_card: None,
+            hovered_board_and_card: None,
+            context_menu_items: Vec::new(),
+            context_menu_state: ListState::default(),
+            context_menu_position: (0, 0),
         }
     }
 }
@@ -949,6 +1624,22 @@ pub struct AppConfig {
     pub no_of_boards_to_show: u16,
     pub enable_mouse_support: bool,
     pub default_theme: String,
+    #[serde(default)]
+    pub skip_confirmation_dialogs: bool,
+    /// Filter expressions by name, see CardFilter
+    #[serde(default)]
+    pub saved_filters: BTreeMap<String, String>,
+    /// 0 disables auto archiving
+    #[serde(default)]
+    pub auto_archive_completed_cards_after_days: u16,
+    /// Refuse instead of warn when a card would go over a board's wip_limit
+    #[serde(default)]
+    pub enforce_wip_limits: bool,
+    #[serde(default)]
+    pub swimlanes: SwimlaneGrouping,
+    /// Empty for the default workspace, which saves straight into the save_directory
+    #[serde(default)]
+    pub workspace: String,
 }
 
 impl Default for AppConfig {
@@ -968,11 +1659,36 @@ impl Default for AppConfig {
             no_of_boards_to_show: NO_OF_BOARDS_PER_PAGE,
             enable_mouse_support: true,
             default_theme: default_theme.name,
+            skip_confirmation_dialogs: false,
+            saved_filters: BTreeMap::new(),
+            auto_archive_completed_cards_after_days: 0,
+            enforce_wip_limits: false,
+            swimlanes: SwimlaneGrouping::None,
+            workspace: String::new(),
         }
     }
 }
 
 impl AppConfig {
+    /// Where the save files of the current workspace live
+    pub fn workspace_save_directory(&self) -> PathBuf {
+        if self.workspace.is_empty() {
+            self.save_directory.clone()
+        } else {
+            self.save_directory
+                .join(WORKSPACES_DIR_NAME)
+                .join(&self.workspace)
+        }
+    }
+
+    pub fn workspace_name(&self) -> &str {
+        if self.workspace.is_empty() {
+            DEFAULT_WORKSPACE_NAME
+        } else {
+            &self.workspace
+        }
+    }
+
     pub fn to_list(&self) -> Vec<Vec<String>> {
         vec![
             vec![
@@ -1016,6 +1732,19 @@ impl AppConfig {
                 String::from("Default Theme"),
                 self.default_theme.to_string(),
             ],
+            vec![
+                String::from("Skip Confirmation Dialogs"),
+                self.skip_confirmation_dialogs.to_string(),
+            ],
+            vec![
+                String::from("Auto Archive Completed Cards After Days"),
+                self.auto_archive_completed_cards_after_days.to_string(),
+            ],
+            vec![
+                String::from("Enforce WIP Limits"),
+                self.enforce_wip_limits.to_string(),
+            ],
+            vec![String::from("Swimlanes"), self.swimlanes.to_string()],
             vec![String::from("Edit Keybindings")],
         ]
     }
@@ -1089,6 +1818,43 @@ impl AppConfig {
                         app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
                     }
                 }
+                "Skip Confirmation Dialogs" => {
+                    if value.to_lowercase() == "true" {
+                        config.skip_confirmation_dialogs = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.skip_confirmation_dialogs = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Swimlanes" => {
+                    config.swimlanes = SwimlaneGrouping::parse(value);
+                }
+                "Enforce WIP Limits" => {
+                    if value.to_lowercase() == "true" {
+                        config.enforce_wip_limits = true;
+                    } else if value.to_lowercase() == "false" {
+                        config.enforce_wip_limits = false;
+                    } else {
+                        error!("Invalid boolean: {}", value);
+                        app.send_error_toast(&format!("Expected boolean, got: {}", value), None);
+                    }
+                }
+                "Auto Archive Completed Cards After Days" => {
+                    if let Ok(after_days) = value.parse::<u16>() {
+                        config.auto_archive_completed_cards_after_days = after_days;
+                    } else {
+                        error!("Invalid number: {}", value);
+                        app.send_error_toast(
+                            &format!(
+                                "Expected number of days (integer, 0 to disable), got: {}",
+                                value
+                            ),
+                            None,
+                        );
+                    }
+                }
                 "Number of Days to Warn Before Due Date" => {
                     let new_delta = value.parse::<u16>();
                     if let Ok(new_delta) = new_delta {
@@ -1303,6 +2069,15 @@ impl AppConfig {
             "go_to_main_menu" => self.keybindings.go_to_main_menu = value,
             "toggle_command_palette" => self.keybindings.toggle_command_palette = value,
             "clear_all_toasts" => self.keybindings.clear_all_toasts = value,
+            "undo" => self.keybindings.undo = value,
+            "redo" => self.keybindings.redo = value,
+            "search_cards" => self.keybindings.search_cards = value,
+            "filter_cards" => self.keybindings.filter_cards = value,
+            "sort_cards" => self.keybindings.sort_cards = value,
+            "archive_card" => self.keybindings.archive_card = value,
+            "archive_board" => self.keybindings.archive_board = value,
+            "open_in_editor" => self.keybindings.open_in_editor = value,
+            "quick_add_card" => self.keybindings.quick_add_card = value,
             _ => {
                 debug!("Invalid key: {}", key);
                 error!("Unable to edit keybinding");
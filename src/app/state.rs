@@ -26,6 +26,7 @@ pub enum UiMode {
     NewCard,
     LoadSave,
     CreateTheme,
+    Archive,
 }
 
 #[derive(Clone, PartialEq, Debug, Default)]
@@ -69,16 +70,29 @@ pub enum Focus {
     StyleEditorModifier,
     TextInput,
     CardPriority,
+    CardRecurrence,
     CardStatus,
     CardTags,
     CardComments,
+    CardChecklist,
+    CardLinks,
+    CardHistory,
+    CardSearch,
+    SavedFilters,
+    SortCardsPopup,
+    ArchivedItems,
     ChangeCardPriorityPopup,
+    ChangeCardRecurrencePopup,
+    WorkspaceList,
+    ContextMenu,
     #[default]
     NoFocus,
     ExtraFocus, // Used in cases where defining a new focus is not necessary
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+// keybindings added in later versions fall back to their defaults in older config files
+#[serde(default)]
 pub struct KeyBindings {
     pub quit: Vec<Key>,
     pub open_config_menu: Vec<Key>,
@@ -102,6 +116,15 @@ pub struct KeyBindings {
     pub go_to_main_menu: Vec<Key>,
     pub toggle_command_palette: Vec<Key>,
     pub clear_all_toasts: Vec<Key>,
+    pub undo: Vec<Key>,
+    pub redo: Vec<Key>,
+    pub search_cards: Vec<Key>,
+    pub filter_cards: Vec<Key>,
+    pub sort_cards: Vec<Key>,
+    pub archive_card: Vec<Key>,
+    pub archive_board: Vec<Key>,
+    pub open_in_editor: Vec<Key>,
+    pub quick_add_card: Vec<Key>,
 }
 
 impl UiMode {
@@ -124,6 +147,7 @@ impl UiMode {
             "New Card" => Some(UiMode::NewCard),
             "Load a Save" => Some(UiMode::LoadSave),
             "Create Theme" => Some(UiMode::CreateTheme),
+            "Archive" => Some(UiMode::Archive),
             _ => None,
         }
     }
@@ -178,6 +202,7 @@ impl UiMode {
             ],
             UiMode::LoadSave => vec![Focus::Body],
             UiMode::CreateTheme => vec![Focus::ThemeEditor, Focus::SubmitButton],
+            UiMode::Archive => vec![Focus::ArchivedItems],
         }
     }
 
@@ -223,6 +248,7 @@ impl fmt::Display for UiMode {
             UiMode::NewCard => write!(f, "New Card"),
             UiMode::LoadSave => write!(f, "Load a Save"),
             UiMode::CreateTheme => write!(f, "Create Theme"),
+            UiMode::Archive => write!(f, "Archive"),
         }
     }
 }
@@ -270,10 +296,21 @@ impl Focus {
             Self::StyleEditorModifier => "Theme Editor Modifier",
             Self::TextInput => "Text Input",
             Self::CardPriority => "Card Priority",
+            Self::CardRecurrence => "Card Recurrence",
             Self::CardStatus => "Card Status",
             Self::CardTags => "Card Tags",
             Self::CardComments => "Card Comments",
+            Self::CardChecklist => "Card Checklist",
+            Self::CardLinks => "Card Links",
+            Self::CardHistory => "Card History",
+            Self::CardSearch => "Card Search",
+            Self::SavedFilters => "Saved Filters",
+            Self::SortCardsPopup => "Sort Cards Popup",
+            Self::ArchivedItems => "Archived Items",
             Self::ChangeCardPriorityPopup => "Change Card Priority Popup",
+            Self::ChangeCardRecurrencePopup => "Change Card Recurrence Popup",
+            Self::WorkspaceList => "Workspace List",
+            Self::ContextMenu => "Context Menu",
             Self::NoFocus => "No Focus",
             Self::ExtraFocus => "Extra Focus",
         }
@@ -340,10 +377,21 @@ impl FromStr for Focus {
             "Theme Editor Modifier" => Ok(Self::StyleEditorModifier),
             "Text Input" => Ok(Self::TextInput),
             "Card Priority" => Ok(Self::CardPriority),
+            "Card Recurrence" => Ok(Self::CardRecurrence),
             "Card Status" => Ok(Self::CardStatus),
             "Card Tags" => Ok(Self::CardTags),
             "Card Comments" => Ok(Self::CardComments),
+            "Card Checklist" => Ok(Self::CardChecklist),
+            "Card Links" => Ok(Self::CardLinks),
+            "Card History" => Ok(Self::CardHistory),
+            "Card Search" => Ok(Self::CardSearch),
+            "Saved Filters" => Ok(Self::SavedFilters),
+            "Sort Cards Popup" => Ok(Self::SortCardsPopup),
+            "Archived Items" => Ok(Self::ArchivedItems),
             "Change Card Priority Popup" => Ok(Self::ChangeCardPriorityPopup),
+            "Change Card Recurrence Popup" => Ok(Self::ChangeCardRecurrencePopup),
+            "Workspace List" => Ok(Self::WorkspaceList),
+            "Context Menu" => Ok(Self::ContextMenu),
             "Submit Button" => Ok(Self::SubmitButton),
             "Extra Focus" => Ok(Self::ExtraFocus),
             _ => Ok(Self::NoFocus),
@@ -385,6 +433,15 @@ impl KeyBindings {
             ("go_to_main_menu", &self.go_to_main_menu),
             ("toggle_command_palette", &self.toggle_command_palette),
             ("clear_all_toasts", &self.clear_all_toasts),
+            ("undo", &self.undo),
+            ("redo", &self.redo),
+            ("search_cards", &self.search_cards),
+            ("filter_cards", &self.filter_cards),
+            ("sort_cards", &self.sort_cards),
+            ("archive_card", &self.archive_card),
+            ("archive_board", &self.archive_board),
+            ("open_in_editor", &self.open_in_editor),
+            ("quick_add_card", &self.quick_add_card),
         ]
         .into_iter()
     }
@@ -419,6 +476,15 @@ impl KeyBindings {
                     "go_to_main_menu" => return Some(&Action::GoToMainMenu),
                     "toggle_command_palette" => return Some(&Action::ToggleCommandPalette),
                     "clear_all_toasts" => return Some(&Action::ClearAllToasts),
+                    "undo" => return Some(&Action::Undo),
+                    "redo" => return Some(&Action::Redo),
+                    "search_cards" => return Some(&Action::SearchCards),
+                    "filter_cards" => return Some(&Action::FilterCards),
+                    "sort_cards" => return Some(&Action::SortCards),
+                    "archive_card" => return Some(&Action::ArchiveCard),
+                    "archive_board" => return Some(&Action::ArchiveBoard),
+                    "open_in_editor" => return Some(&Action::OpenInEditor),
+                    "quick_add_card" => return Some(&Action::QuickAddCard),
                     _ => return None,
                 }
             }
@@ -450,6 +516,15 @@ impl KeyBindings {
             "go_to_main_menu" => Some(&Action::GoToMainMenu),
             "toggle_command_palette" => Some(&Action::ToggleCommandPalette),
             "clear_all_toasts" => Some(&Action::ClearAllToasts),
+            "undo" => Some(&Action::Undo),
+            "redo" => Some(&Action::Redo),
+            "search_cards" => Some(&Action::SearchCards),
+            "filter_cards" => Some(&Action::FilterCards),
+            "sort_cards" => Some(&Action::SortCards),
+            "archive_card" => Some(&Action::ArchiveCard),
+            "archive_board" => Some(&Action::ArchiveBoard),
+            "open_in_editor" => Some(&Action::OpenInEditor),
+            "quick_add_card" => Some(&Action::QuickAddCard),
             _ => None,
         }
     }
@@ -480,6 +555,15 @@ impl Default for KeyBindings {
             go_to_main_menu: vec![Key::Char('m')],
             toggle_command_palette: vec![Key::Ctrl('p')],
             clear_all_toasts: vec![Key::Char('t')],
+            undo: vec![Key::Ctrl('z')],
+            redo: vec![Key::Ctrl('y')],
+            search_cards: vec![Key::Char('/')],
+            filter_cards: vec![Key::Char('f')],
+            sort_cards: vec![Key::Char('s')],
+            archive_card: vec![Key::Char('a')],
+            archive_board: vec![Key::Char('A')],
+            open_in_editor: vec![Key::Ctrl('e')],
+            quick_add_card: vec![Key::Char('N')],
         }
     }
 }
@@ -0,0 +1,766 @@
+use clap::{Subcommand, ValueEnum};
+use eyre::{anyhow, Result};
+use std::{
+    fs,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    app::kanban::{
+        Board, Card, CardDate, CardFilter, CardPriority, CardRecurrence, CardStatus, WorkflowStatus,
+    },
+    constants::FIELD_NOT_SET,
+    io::{
+        data_handler::{
+            boards_to_csv, boards_to_markdown, get_available_local_savefiles, get_config,
+            get_local_kanban_state, import_kanban_from_csv, import_kanban_from_json,
+            import_kanban_from_markdown, merge_imported_boards, save_kanban_state_locally,
+            write_config, ExportStruct,
+        },
+        handler::get_latest_save_file,
+    },
+};
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CliCommand {
+    /// Manage boards in the latest save
+    #[command(subcommand)]
+    Board(BoardCommand),
+    /// Manage cards in the latest save
+    #[command(subcommand)]
+    Card(CardCommand),
+    /// Manage saved card filters
+    #[command(subcommand)]
+    Filter(FilterCommand),
+    /// Export all boards, prints to stdout unless an output file is given
+    Export {
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Markdown)]
+        format: ExportFormat,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Import boards from a file created by one of the exporters
+    Import {
+        file: PathBuf,
+        /// Defaults to the format matching the file extension
+        #[arg(short, long, value_enum)]
+        format: Option<ExportFormat>,
+        /// Replace all existing boards instead of merging into them
+        #[arg(long)]
+        replace: bool,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Markdown,
+    Csv,
+}
+
+impl ExportFormat {
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("md") | Some("markdown") => ExportFormat::Markdown,
+            Some("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Json,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum BoardCommand {
+    /// List all boards
+    #[command(alias = "ls")]
+    List,
+    /// Add a new board
+    Add {
+        name: String,
+        #[arg(short, long, default_value = "")]
+        description: String,
+    },
+    /// Remove a board (by name, id or 1 based index)
+    #[command(alias = "remove")]
+    Rm { board: String },
+    /// Show or set the workflow statuses of a board
+    Workflow {
+        board: String,
+        /// Comma separated statuses with an optional kind, e.g. "Backlog, In Progress, Done:Complete"
+        definition: Option<String>,
+    },
+    /// Show or set the maximum number of cards on a board, 0 removes the limit
+    Wip { board: String, limit: Option<u16> },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CardCommand {
+    /// List the cards of a board (by name, id or 1 based index)
+    #[command(alias = "list")]
+    Ls {
+        board: String,
+        /// Filter expression or the name of a saved filter, e.g. "tag:backend due:<7d"
+        #[arg(short, long)]
+        filter: Option<String>,
+    },
+    /// Add a new card to a board
+    Add {
+        board: String,
+        name: String,
+        #[arg(short, long, default_value = "")]
+        description: String,
+        /// Due date in the format DD/MM/YYYY or DD/MM/YYYY-HH:MM:SS
+        #[arg(long, default_value = "")]
+        due: String,
+        /// Low, Medium or High
+        #[arg(short, long, default_value = "Low")]
+        priority: String,
+        /// Daily, Weekly or Monthly
+        #[arg(short, long)]
+        repeat: Option<String>,
+    },
+    /// Add a card from one line of text, e.g. "Fix login bug #backend !high @fri"
+    Quick {
+        board: String,
+        /// #tag adds a tag, !low/!medium/!high sets the priority, @date sets the due date
+        /// (today, tomorrow, a weekday, 3d, 2w or DD/MM/YYYY), any other @word is kept as a tag
+        #[arg(required = true, num_args = 1..)]
+        text: Vec<String>,
+    },
+    /// Move a card (by name or id) to another board
+    Move {
+        card: String,
+        to_board: String,
+        /// Only look for the card in this board
+        #[arg(short, long)]
+        from_board: Option<String>,
+    },
+    /// Set the workflow status of a card (by name or id)
+    Status {
+        card: String,
+        status: String,
+        /// Only look for the card in this board
+        #[arg(short, long)]
+        board: Option<String>,
+    },
+    /// Mark a card (by name or id) as complete
+    Done {
+        card: String,
+        /// Only look for the card in this board
+        #[arg(short, long)]
+        board: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum FilterCommand {
+    /// List the saved filters
+    #[command(alias = "list")]
+    Ls,
+    /// Save a filter expression under a name, replacing any filter with the same name
+    Save { name: String, expression: String },
+    /// Remove a saved filter
+    #[command(alias = "remove")]
+    Rm { name: String },
+}
+
+/// Runs a cli command against the latest save and writes a new save version if anything changed
+pub fn run_cli_command(command: CliCommand) -> Result<()> {
+    let mut boards = load_latest_boards()?;
+    let changed = match command {
+        CliCommand::Board(board_command) => handle_board_command(&mut boards, board_command)?,
+        CliCommand::Card(card_command) => handle_card_command(&mut boards, card_command)?,
+        CliCommand::Filter(filter_command) => handle_filter_command(filter_command)?,
+        CliCommand::Export { format, output } => handle_export_command(&boards, format, output)?,
+        CliCommand::Import {
+            file,
+            format,
+            replace,
+        } => handle_import_command(
+            &mut boards,
+            &file,
+            format.unwrap_or_else(|| ExportFormat::from_path(&file)),
+            replace,
+        )?,
+    };
+    if changed {
+        let config = get_config(false).unwrap_or_default();
+        // save_kanban_state_locally expects the save directory to exist
+        fs::create_dir_all(config.workspace_save_directory())?;
+        save_kanban_state_locally(boards).map_err(|e| anyhow!("Error saving boards: {}", e))?;
+    }
+    Ok(())
+}
+
+fn load_latest_boards() -> Result<Vec<Board>> {
+    let save_directory = get_config(false)
+        .unwrap_or_default()
+        .workspace_save_directory();
+    match fs::read_dir(&save_directory) {
+        Ok(_) => {}
+        // no saves yet, start from scratch
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => {
+            return Err(anyhow!(
+                "Cannot read the save directory {}: {}",
+                save_directory.display(),
+                e
+            ))
+        }
+    }
+    // other files in the save directory are not save files
+    if get_available_local_savefiles()
+        .unwrap_or_default()
+        .is_empty()
+    {
+        return Ok(vec![]);
+    }
+    let (latest_save_file, latest_version) = get_latest_save_file()?;
+    get_local_kanban_state(latest_save_file.clone(), latest_version, true).map_err(|e| {
+        anyhow!(
+            "Cannot load {}, data might be corrupted: {}",
+            latest_save_file,
+            e
+        )
+    })
+}
+
+fn handle_board_command(boards: &mut Vec<Board>, command: BoardCommand) -> Result<bool> {
+    match command {
+        BoardCommand::List => {
+            if boards.is_empty() {
+                println!("No boards found");
+            }
+            for (index, board) in boards.iter().enumerate() {
+                println!(
+                    "{}. {} ({} cards) [{}]{}",
+                    index + 1,
+                    board.name,
+                    board.cards.len(),
+                    board.id,
+                    if board.archived { " (archived)" } else { "" }
+                );
+            }
+            Ok(false)
+        }
+        BoardCommand::Add { name, description } => {
+            if name.trim().is_empty() {
+                return Err(anyhow!("Board name cannot be empty"));
+            }
+            if boards.iter().any(|board| board.name == name) {
+                return Err(anyhow!("A board named '{}' already exists", name));
+            }
+            let board = Board::new(name, description);
+            println!("Created board '{}' [{}]", board.name, board.id);
+            boards.push(board);
+            Ok(true)
+        }
+        BoardCommand::Rm { board } => {
+            let board_index = find_board_index(boards, &board)?;
+            let removed_board = boards.remove(board_index);
+            println!(
+                "Removed board '{}' with {} cards",
+                removed_board.name,
+                removed_board.cards.len()
+            );
+            Ok(true)
+        }
+        BoardCommand::Workflow { board, definition } => {
+            let board_index = find_board_index(boards, &board)?;
+            let board = &mut boards[board_index];
+            let definition = match definition {
+                Some(definition) => definition,
+                None => {
+                    for (index, workflow_status) in board.workflow().iter().enumerate() {
+                        println!(
+                            "{}. {} ({})",
+                            index + 1,
+                            workflow_status.name,
+                            workflow_status.kind
+                        );
+                    }
+                    return Ok(false);
+                }
+            };
+            board
+                .set_workflow(WorkflowStatus::parse_workflow(&definition).map_err(|e| anyhow!(e))?);
+            println!(
+                "Set workflow of board '{}' to {}",
+                board.name,
+                WorkflowStatus::workflow_to_string(&board.workflow)
+            );
+            Ok(true)
+        }
+        BoardCommand::Wip { board, limit } => {
+            let board_index = find_board_index(boards, &board)?;
+            let board = &mut boards[board_index];
+            match limit {
+                None if board.wip_limit == 0 => {
+                    println!("Board '{}' has no WIP limit", board.name);
+                    Ok(false)
+                }
+                None => {
+                    println!(
+                        "Board '{}' has {} of {} cards",
+                        board.name,
+                        board.wip_count(),
+                        board.wip_limit
+                    );
+                    Ok(false)
+                }
+                Some(limit) => {
+                    board.wip_limit = limit;
+                    if limit == 0 {
+                        println!("Removed the WIP limit of board '{}'", board.name);
+                    } else {
+                        println!("Set the WIP limit of board '{}' to {}", board.name, limit);
+                    }
+                    Ok(true)
+                }
+            }
+        }
+    }
+}
+
+fn handle_card_command(boards: &mut [Board], command: CardCommand) -> Result<bool> {
+    match command {
+        CardCommand::Ls { board, filter } => {
+            let board = &boards[find_board_index(boards, &board)?];
+            let filter = filter.map(|filter| resolve_filter(&filter)).transpose()?;
+            let cards = board
+                .cards
+                .iter()
+                .filter(|card| match &filter {
+                    Some(filter) => filter.matches(card),
+                    None => true,
+                })
+                .collect::<Vec<&Card>>();
+            if cards.is_empty() {
+                if let Some(filter) = &filter {
+                    println!("No cards in board '{}' match '{}'", board.name, filter);
+                } else {
+                    println!("No cards in board '{}'", board.name);
+                }
+            }
+            for card in cards {
+                println!(
+                    "[{}] {} | {} | {} | Due: {} [{}]{}{}",
+                    if card.card_status == CardStatus::Complete {
+                        "x"
+                    } else {
+                        " "
+                    },
+                    card.name,
+                    card.status_name(),
+                    card.priority,
+                    card.date_due
+                        .map(|date_due| date_due.to_string())
+                        .unwrap_or_else(|| FIELD_NOT_SET.to_string()),
+                    card.id,
+                    if card.archived { " (archived)" } else { "" },
+                    if card.card_status != CardStatus::Complete
+                        && !card.open_blockers(boards).is_empty()
+                    {
+                        " (blocked)"
+                    } else {
+                        ""
+                    }
+                );
+            }
+            Ok(false)
+        }
+        CardCommand::Add {
+            board,
+            name,
+            description,
+            due,
+            priority,
+            repeat,
+        } => {
+            let board_index = find_board_index(boards, &board)?;
+            if name.trim().is_empty() {
+                return Err(anyhow!("Card name cannot be empty"));
+            }
+            if boards[board_index]
+                .cards
+                .iter()
+                .any(|card| card.name == name)
+            {
+                return Err(anyhow!(
+                    "A card named '{}' already exists in board '{}'",
+                    name,
+                    boards[board_index].name
+                ));
+            }
+            let due_date = CardDate::parse_optional(&due).map_err(|e| anyhow!(e))?;
+            let priority = parse_priority(&priority)?;
+            check_wip_limit(&boards[board_index])?;
+            let recurrence = repeat.as_deref().map(parse_recurrence).transpose()?;
+            let mut card = Card::new(name, description, due_date, priority, vec![], vec![]);
+            card.recurrence = recurrence;
+            boards[board_index].adopt_card_status(&mut card);
+            println!(
+                "Created card '{}' in board '{}' [{}]",
+                card.name, boards[board_index].name, card.id
+            );
+            boards[board_index].cards.push(card);
+            Ok(true)
+        }
+        CardCommand::Quick { board, text } => {
+            let board_index = find_board_index(boards, &board)?;
+            let mut card =
+                Card::from_quick_add(&text.join(" "), CardDate::now()).map_err(|e| anyhow!(e))?;
+            if boards[board_index]
+                .cards
+                .iter()
+                .any(|existing_card| existing_card.name == card.name)
+            {
+                return Err(anyhow!(
+                    "A card named '{}' already exists in board '{}'",
+                    card.name,
+                    boards[board_index].name
+                ));
+            }
+            check_wip_limit(&boards[board_index])?;
+            boards[board_index].adopt_card_status(&mut card);
+            println!(
+                "Created card '{}' in board '{}' [{}]",
+                card.name, boards[board_index].name, card.id
+            );
+            boards[board_index].cards.push(card);
+            Ok(true)
+        }
+        CardCommand::Move {
+            card,
+            to_board,
+            from_board,
+        } => {
+            let to_board_index = find_board_index(boards, &to_board)?;
+            let (board_index, card_index) = find_card_index(boards, &card, from_board.as_deref())?;
+            if board_index == to_board_index {
+                println!(
+                    "Card '{}' is already in board '{}'",
+                    boards[board_index].cards[card_index].name, boards[board_index].name
+                );
+                return Ok(false);
+            }
+            check_wip_limit(&boards[to_board_index])?;
+            let mut card = boards[board_index].cards.remove(card_index);
+            card.log_activity(format!(
+                "Moved from board '{}' to board '{}'",
+                boards[board_index].name, boards[to_board_index].name
+            ));
+            boards[to_board_index].adopt_card_status(&mut card);
+            println!(
+                "Moved card '{}' from board '{}' to board '{}'",
+                card.name, boards[board_index].name, boards[to_board_index].name
+            );
+            boards[to_board_index].cards.push(card);
+            Ok(true)
+        }
+        CardCommand::Status {
+            card,
+            status,
+            board,
+        } => {
+            let (board_index, card_index) = find_card_index(boards, &card, board.as_deref())?;
+            let board = &mut boards[board_index];
+            let workflow_status = board.find_workflow_status(&status).ok_or_else(|| {
+                anyhow!(
+                    "No status named '{}' in the workflow of board '{}'",
+                    status,
+                    board.name
+                )
+            })?;
+            let card = &mut board.cards[card_index];
+            card.set_status(&workflow_status);
+            println!(
+                "Changed status of card '{}' to {}",
+                card.name, workflow_status
+            );
+            if workflow_status.kind == CardStatus::Complete {
+                warn_about_open_blockers(boards, &boards[board_index].cards[card_index]);
+                create_next_recurring_cards(&mut boards[board_index]);
+            }
+            Ok(true)
+        }
+        CardCommand::Done { card, board } => {
+            let (board_index, card_index) = find_card_index(boards, &card, board.as_deref())?;
+            let board = &mut boards[board_index];
+            let workflow_status = board
+                .workflow_status_of_kind(&CardStatus::Complete)
+                .ok_or_else(|| anyhow!("Board '{}' has no Complete status", board.name))?;
+            let card = &mut board.cards[card_index];
+            card.set_status(&workflow_status);
+            println!("Marked card '{}' as {}", card.name, workflow_status);
+            warn_about_open_blockers(boards, &boards[board_index].cards[card_index]);
+            create_next_recurring_cards(&mut boards[board_index]);
+            Ok(true)
+        }
+    }
+}
+
+/// Errors with enforce_wip_limits, otherwise warns, when one more card would go over the board's wip_limit
+fn check_wip_limit(board: &Board) -> Result<()> {
+    if !board.is_at_wip_limit() {
+        return Ok(());
+    }
+    if get_config(false).unwrap_or_default().enforce_wip_limits {
+        return Err(anyhow!(
+            "Board '{}' has reached its WIP limit of {}",
+            board.name,
+            board.wip_limit
+        ));
+    }
+    println!(
+        "Warning: board '{}' has reached its WIP limit of {}",
+        board.name, board.wip_limit
+    );
+    Ok(())
+}
+
+fn create_next_recurring_cards(board: &mut Board) {
+    let created = board.create_next_recurring_cards();
+    if created > 0 {
+        println!(
+            "Created {} recurring card(s) in board '{}'",
+            created, board.name
+        );
+    }
+}
+
+fn warn_about_open_blockers(boards: &[Board], card: &Card) {
+    let open_blockers = card
+        .open_blockers(boards)
+        .iter()
+        .map(|blocker| format!("'{}'", blocker.name))
+        .collect::<Vec<String>>();
+    if !open_blockers.is_empty() {
+        println!(
+            "Warning: card '{}' is still blocked by {}",
+            card.name,
+            open_blockers.join(", ")
+        );
+    }
+}
+
+fn handle_filter_command(command: FilterCommand) -> Result<bool> {
+    let mut config = get_config(false).map_err(|e| anyhow!(e))?;
+    match command {
+        FilterCommand::Ls => {
+            if config.saved_filters.is_empty() {
+                println!("No saved filters");
+            }
+            for (name, expression) in config.saved_filters.iter() {
+                println!("{}: {}", name, expression);
+            }
+        }
+        FilterCommand::Save { name, expression } => {
+            if name.trim().is_empty() {
+                return Err(anyhow!("Filter name cannot be empty"));
+            }
+            let filter = CardFilter::parse(&expression).map_err(|e| anyhow!(e))?;
+            config
+                .saved_filters
+                .insert(name.clone(), filter.to_string());
+            write_config(&config).map_err(|e| anyhow!(e))?;
+            println!("Saved filter '{}' as {}", name, filter);
+        }
+        FilterCommand::Rm { name } => {
+            if config.saved_filters.remove(&name).is_none() {
+                return Err(anyhow!("No saved filter named '{}'", name));
+            }
+            write_config(&config).map_err(|e| anyhow!(e))?;
+            println!("Removed filter '{}'", name);
+        }
+    }
+    // filters live in the config, the boards are never changed
+    Ok(false)
+}
+
+fn handle_export_command(
+    boards: &[Board],
+    format: ExportFormat,
+    output: Option<PathBuf>,
+) -> Result<bool> {
+    let exported = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&ExportStruct {
+            kanban_version: env!("CARGO_PKG_VERSION").to_string(),
+            export_date: chrono::Local::now().format("%d-%m-%Y").to_string(),
+            boards: boards.to_vec(),
+        })?,
+        ExportFormat::Markdown => boards_to_markdown(boards),
+        ExportFormat::Csv => boards_to_csv(boards).map_err(|e| anyhow!(e))?,
+    };
+    if let Some(output) = output {
+        fs::write(&output, exported)?;
+        println!("Exported {} boards to {}", boards.len(), output.display());
+    } else {
+        // ignore broken pipes so the output can be piped into head and friends
+        let _ = writeln!(std::io::stdout(), "{}", exported);
+    }
+    Ok(false)
+}
+
+fn handle_import_command(
+    boards: &mut Vec<Board>,
+    file: &Path,
+    format: ExportFormat,
+    replace: bool,
+) -> Result<bool> {
+    let imported_boards = match format {
+        ExportFormat::Json => {
+            let export = import_kanban_from_json(file).map_err(|e| anyhow!(e))?;
+            println!(
+                "Importing {} boards exported from v{} on {}",
+                export.boards.len(),
+                export.kanban_version,
+                export.export_date
+            );
+            if export.kanban_version != env!("CARGO_PKG_VERSION") {
+                println!(
+                    "Warning: current version is v{}, some data might not be imported correctly",
+                    env!("CARGO_PKG_VERSION")
+                );
+            }
+            export.boards
+        }
+        ExportFormat::Markdown => {
+            let imported_boards = import_kanban_from_markdown(file).map_err(|e| anyhow!(e))?;
+            println!("Importing {} boards from markdown", imported_boards.len());
+            imported_boards
+        }
+        ExportFormat::Csv => {
+            let imported_boards = import_kanban_from_csv(file).map_err(|e| anyhow!(e))?;
+            println!(
+                "Importing {} cards in {} boards from csv",
+                imported_boards
+                    .iter()
+                    .map(|board| board.cards.len())
+                    .sum::<usize>(),
+                imported_boards.len()
+            );
+            imported_boards
+        }
+    };
+    if replace {
+        println!("Replaced {} boards", boards.len());
+        *boards = imported_boards;
+        Ok(true)
+    } else {
+        let (boards_added, cards_added) = merge_imported_boards(boards, imported_boards);
+        println!("Merged {} boards and {} cards", boards_added, cards_added);
+        Ok(boards_added + cards_added > 0)
+    }
+}
+
+/// Finds a board by id, exact name, case insensitive name or 1 based index (in that order)
+fn find_board_index(boards: &[Board], query: &str) -> Result<usize> {
+    if let Ok(id) = query.parse::<u128>() {
+        if let Some(index) = boards.iter().position(|board| board.id == id) {
+            return Ok(index);
+        }
+    }
+    if let Some(index) = boards.iter().position(|board| board.name == query) {
+        return Ok(index);
+    }
+    if let Some(index) = boards
+        .iter()
+        .position(|board| board.name.to_lowercase() == query.to_lowercase())
+    {
+        return Ok(index);
+    }
+    if let Ok(index) = query.parse::<usize>() {
+        if index > 0 && index <= boards.len() {
+            return Ok(index - 1);
+        }
+    }
+    Err(anyhow!("No board found matching '{}'", query))
+}
+
+/// Finds a card by id or name, returns (board index, card index)
+fn find_card_index(
+    boards: &[Board],
+    query: &str,
+    board_query: Option<&str>,
+) -> Result<(usize, usize)> {
+    let board_indexes = if let Some(board_query) = board_query {
+        vec![find_board_index(boards, board_query)?]
+    } else {
+        (0..boards.len()).collect()
+    };
+    let id = query.parse::<u128>().ok();
+    let mut matches = Vec::new();
+    for board_index in board_indexes {
+        for (card_index, card) in boards[board_index].cards.iter().enumerate() {
+            if Some(card.id) == id || card.name == query {
+                matches.push((board_index, card_index));
+            }
+        }
+    }
+    match matches.len() {
+        0 => Err(anyhow!("No card found matching '{}'", query)),
+        1 => Ok(matches[0]),
+        _ => Err(anyhow!(
+            "{} cards match '{}', use the card id or narrow it down with --board",
+            matches.len(),
+            query
+        )),
+    }
+}
+
+/// Looks up a saved filter by name, otherwise parses the query as a filter expression
+fn resolve_filter(query: &str) -> Result<CardFilter> {
+    let config = get_config(false).unwrap_or_default();
+    let expression = config
+        .saved_filters
+        .get(query)
+        .map(String::as_str)
+        .unwrap_or(query);
+    CardFilter::parse(expression).map_err(|e| anyhow!(e))
+}
+
+fn parse_priority(priority: &str) -> Result<CardPriority> {
+    CardPriority::all()
+        .into_iter()
+        .find(|p| p.to_string().to_lowercase() == priority.to_lowercase())
+        .ok_or_else(|| {
+            anyhow!(
+                "Invalid priority '{}', expected Low, Medium or High",
+                priority
+            )
+        })
+}
+
+fn parse_recurrence(recurrence: &str) -> Result<CardRecurrence> {
+    CardRecurrence::options()
+        .into_iter()
+        .flatten()
+        .find(|r| r.to_string().to_lowercase() == recurrence.to_lowercase())
+        .ok_or_else(|| {
+            anyhow!(
+                "Invalid recurrence '{}', expected Daily, Weekly or Monthly",
+                recurrence
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_board_by_name_or_index() {
+        let boards = vec![
+            Board::new("Todo".to_string(), String::new()),
+            Board::new("Done".to_string(), String::new()),
+        ];
+        assert_eq!(find_board_index(&boards, "done").unwrap(), 1);
+        assert_eq!(find_board_index(&boards, "1").unwrap(), 0);
+        assert_eq!(
+            find_board_index(&boards, &boards[1].id.to_string()).unwrap(),
+            1
+        );
+        assert!(find_board_index(&boards, "3").is_err());
+    }
+}
@@ -5,6 +5,9 @@ pub const CONFIG_FILE_NAME: &str = "config.json";
 pub const CONFIG_DIR_NAME: &str = "rust_kanban";
 pub const SAVE_DIR_NAME: &str = "kanban_saves";
 pub const SAVE_FILE_NAME: &str = "kanban";
+pub const WORKSPACES_DIR_NAME: &str = "workspaces";
+pub const DEFAULT_WORKSPACE_NAME: &str = "Default";
+pub const SAVE_FILE_FORMAT_VERSION: u32 = 9;
 pub const NO_OF_BOARDS_PER_PAGE: u16 = 3;
 pub const MIN_NO_BOARDS_PER_PAGE: u16 = 1;
 pub const MAX_NO_BOARDS_PER_PAGE: u16 = 5;
@@ -33,6 +36,8 @@ pub const SAMPLE_TEXT: &str = "Sample Text";
 pub const THEME_DIR_NAME: &str = "themes";
 pub const THEME_FILE_NAME: &str = "kanban_theme";
 pub const DEFAULT_DATE_FORMAT: &str = "%d/%m/%Y-%H:%M:%S";
+pub const MAX_UNDO_STATES: usize = 50;
+pub const CARD_SEARCH_THRESHOLD: f32 = 0.3;
 
 // Style
 pub const GENERAL_STYLE: Style = Style {
@@ -17,6 +17,8 @@ pub struct Events {
     _tx: tokio::sync::mpsc::Sender<InputEvent>,
     // To stop the loop
     stop_capture: Arc<AtomicBool>,
+    // To hand the terminal over to another program
+    pause_capture: Arc<AtomicBool>,
 }
 
 impl Events {
@@ -24,11 +26,17 @@ impl Events {
     pub fn new(tick_rate: Duration) -> Events {
         let (tx, rx) = tokio::sync::mpsc::channel(100);
         let stop_capture = Arc::new(AtomicBool::new(false));
+        let pause_capture = Arc::new(AtomicBool::new(false));
 
         let event_tx = tx.clone();
         let event_stop_capture = stop_capture.clone();
+        let event_pause_capture = pause_capture.clone();
         tokio::spawn(async move {
             loop {
+                if event_pause_capture.load(Ordering::Relaxed) {
+                    tokio::time::sleep(tick_rate).await;
+                    continue;
+                }
                 // poll for tick rate duration, if no event, sent tick event.
                 if crossterm::event::poll(tick_rate).unwrap() {
                     let event = crossterm::event::read().unwrap();
@@ -58,6 +66,7 @@ impl Events {
             rx,
             _tx: tx,
             stop_capture,
+            pause_capture,
         }
     }
 
@@ -71,6 +80,16 @@ impl Events {
         }
     }
 
+    /// Stops reading terminal events until resume is called, waits for a pending read to finish
+    pub async fn pause(&mut self, tick_rate: Duration) {
+        self.pause_capture.store(true, Ordering::Relaxed);
+        tokio::time::sleep(tick_rate).await;
+    }
+
+    pub fn resume(&mut self) {
+        self.pause_capture.store(false, Ordering::Relaxed);
+    }
+
     /// Close
     pub fn close(&mut self) {
         self.stop_capture.store(true, Ordering::Relaxed)
@@ -41,6 +41,8 @@ pub enum Key {
     ShiftDown,
     ShiftLeft,
     ShiftRight,
+    CtrlLeft,
+    CtrlRight,
     Unknown,
 }
 
@@ -87,6 +89,8 @@ impl Display for Key {
             Key::ShiftDown => write!(f, "<Shift+Down>"),
             Key::ShiftLeft => write!(f, "<Shift+Left>"),
             Key::ShiftRight => write!(f, "<Shift+Right>"),
+            Key::CtrlLeft => write!(f, "<Ctrl+Left>"),
+            Key::CtrlRight => write!(f, "<Ctrl+Right>"),
             _ => write!(f, "<{:?}>", self),
         }
     }
@@ -111,6 +115,12 @@ impl From<event::KeyEvent> for Key {
                 modifiers: event::KeyModifiers::SHIFT,
                 ..
             } => Key::ShiftLeft,
+            event::KeyEvent {
+                code: event::KeyCode::Left,
+                kind: event::KeyEventKind::Press,
+                modifiers: event::KeyModifiers::CONTROL,
+                ..
+            } => Key::CtrlLeft,
             event::KeyEvent {
                 code: event::KeyCode::Left,
                 kind: event::KeyEventKind::Press,
@@ -122,6 +132,12 @@ impl From<event::KeyEvent> for Key {
                 modifiers: event::KeyModifiers::SHIFT,
                 ..
             } => Key::ShiftRight,
+            event::KeyEvent {
+                code: event::KeyCode::Right,
+                kind: event::KeyEventKind::Press,
+                modifiers: event::KeyModifiers::CONTROL,
+                ..
+            } => Key::CtrlRight,
             event::KeyEvent {
                 code: event::KeyCode::Right,
                 kind: event::KeyEventKind::Press,
@@ -1,15 +1,27 @@
 use log::{debug, error, info};
 use regex::Regex;
 use savefile::prelude::*;
-use serde::Serialize;
-use std::{cmp::Ordering, collections::HashMap, env, fs, path::PathBuf};
+use savefile_derive::Savefile;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
 
 use super::handler::{get_config_dir, make_file_system_safe_name};
 use crate::{
-    app::{kanban::Board, state::UiMode, AppConfig},
+    app::{
+        kanban::{Board, Card, CardDate, CardPriority, CardStatus, WorkflowStatus},
+        state::UiMode,
+        AppConfig,
+    },
     constants::{
-        CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME, THEME_DIR_NAME,
-        THEME_FILE_NAME,
+        APP_TITLE, CONFIG_DIR_NAME, CONFIG_FILE_NAME, DEFAULT_WORKSPACE_NAME, FIELD_NOT_SET,
+        SAVE_DIR_NAME, SAVE_FILE_FORMAT_VERSION, SAVE_FILE_NAME, THEME_DIR_NAME, THEME_FILE_NAME,
+        WORKSPACES_DIR_NAME,
     },
     inputs::key::Key,
     io::handler::prepare_config_dir,
@@ -18,6 +30,10 @@ use crate::{
 
 extern crate savefile;
 
+/// The workspace picked with --workspace and the one from the config file, the first is used for
+/// this run only while the second is what gets written back to the config file
+static WORKSPACE_OVERRIDE: Mutex<Option<(String, String)>> = Mutex::new(None);
+
 pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String> {
     let config_dir_status = get_config_dir();
     let config_dir = if let Ok(config_dir) = config_dir_status {
@@ -48,6 +64,7 @@ pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String>
             AppConfig::default()
         }
     };
+    let config = with_workspace_override(config);
     let config_keybinds = config.keybindings.clone();
     // make sure there is no overlap between keybinds
     if ignore_overlapped_keybinds {
@@ -80,6 +97,7 @@ pub fn get_config(ignore_overlapped_keybinds: bool) -> Result<AppConfig, String>
 }
 
 pub fn write_config(config: &AppConfig) -> Result<(), String> {
+    let config = without_workspace_override(config.clone());
     let config_str = serde_json::to_string_pretty(&config).unwrap();
     prepare_config_dir()?;
     let config_dir = get_config_dir()?;
@@ -127,7 +145,9 @@ pub fn save_kanban_state_locally(boards: Vec<Board>) -> Result<(), SavefileError
     // versioning style is: SAVE_FILE_NAME_27-12-2020_v1
     // if the file exists, increment the version number
     // if the file does not exist, version number is 1
-    let files = fs::read_dir(&config.save_directory)?;
+    let save_directory = config.workspace_save_directory();
+    fs::create_dir_all(&save_directory)?;
+    let files = fs::read_dir(&save_directory)?;
     let mut version = 1;
     for file in files {
         let file = file?;
@@ -167,8 +187,8 @@ pub fn save_kanban_state_locally(boards: Vec<Board>) -> Result<(), SavefileError
         chrono::Local::now().format("%d-%m-%Y"),
         version
     );
-    let file_path = config.save_directory.join(file_name);
-    let save_status = save_file(file_path, version, &boards);
+    let file_path = save_directory.join(file_name);
+    let save_status = save_file(file_path, SAVE_FILE_FORMAT_VERSION, &boards);
     match save_status {
         Ok(_) => Ok(()),
         Err(e) => Err(e),
@@ -187,12 +207,95 @@ pub fn get_local_kanban_state(
         debug!("Error getting config: {}", get_config_status.unwrap_err());
         AppConfig::default()
     };
-    let file_path = config.save_directory.join(file_name);
+    let file_path = config.workspace_save_directory().join(file_name);
     if !preview_mode {
         info!("Loading local save file: {:?}", file_path);
     }
-    let boards = load_file(file_path, version)?;
-    Ok(boards)
+    load_boards_from_file(&file_path, version)
+}
+
+fn load_boards_from_file(file_path: &Path, version: u32) -> Result<Vec<Board>, SavefileError> {
+    match load_file(file_path, SAVE_FILE_FORMAT_VERSION) {
+        Ok(boards) => Ok(boards),
+        Err(e) => {
+            // saves written before the save file format was versioned used the save
+            // counter as their version and stored card dates as strings
+            let legacy_boards: Vec<LegacyBoard> = load_file(file_path, version).map_err(|_| e)?;
+            info!("Migrating legacy save file: {:?}", file_path);
+            Ok(legacy_boards.into_iter().map(Board::from).collect())
+        }
+    }
+}
+
+use self::legacy::{LegacyBoard, LegacyCard};
+
+#[allow(non_local_definitions)]
+mod legacy {
+    use super::*;
+
+    #[derive(Savefile)]
+    pub(super) struct LegacyBoard {
+        pub(super) id: u128,
+        pub(super) name: String,
+        pub(super) description: String,
+        pub(super) cards: Vec<LegacyCard>,
+    }
+
+    #[derive(Savefile)]
+    pub(super) struct LegacyCard {
+        pub(super) id: u128,
+        pub(super) name: String,
+        pub(super) description: String,
+        pub(super) date_created: String,
+        pub(super) date_modified: String,
+        pub(super) date_due: String,
+        pub(super) date_completed: String,
+        pub(super) priority: CardPriority,
+        pub(super) card_status: CardStatus,
+        pub(super) tags: Vec<String>,
+        pub(super) comments: Vec<String>,
+    }
+}
+
+impl From<LegacyBoard> for Board {
+    fn from(legacy_board: LegacyBoard) -> Self {
+        Self {
+            id: legacy_board.id,
+            name: legacy_board.name,
+            description: legacy_board.description,
+            cards: legacy_board.cards.into_iter().map(Card::from).collect(),
+            workflow: WorkflowStatus::default_workflow(),
+            archived: false,
+            wip_limit: 0,
+        }
+    }
+}
+
+impl From<LegacyCard> for Card {
+    fn from(legacy_card: LegacyCard) -> Self {
+        let date_created = CardDate::parse(&legacy_card.date_created).unwrap_or_else(CardDate::now);
+        Self {
+            id: legacy_card.id,
+            name: legacy_card.name,
+            description: legacy_card.description,
+            date_created,
+            date_modified: CardDate::parse(&legacy_card.date_modified).unwrap_or(date_created),
+            date_due: CardDate::parse_optional(&legacy_card.date_due).unwrap_or_default(),
+            date_completed: CardDate::parse_optional(&legacy_card.date_completed)
+                .unwrap_or_default(),
+            priority: legacy_card.priority,
+            workflow_status: legacy_card.card_status.to_string(),
+            card_status: legacy_card.card_status,
+            tags: legacy_card.tags,
+            comments: legacy_card.comments,
+            history: Vec::new(),
+            archived: false,
+            checklist: Vec::new(),
+            links: Vec::new(),
+            recurrence: None,
+            recurrence_day: None,
+        }
+    }
 }
 
 pub fn get_available_local_savefiles() -> Option<Vec<String>> {
@@ -203,7 +306,7 @@ pub fn get_available_local_savefiles() -> Option<Vec<String>> {
         debug!("Error getting config: {}", get_config_status.unwrap_err());
         AppConfig::default()
     };
-    let read_dir_status = fs::read_dir(&config.save_directory);
+    let read_dir_status = fs::read_dir(config.workspace_save_directory());
     match read_dir_status {
         Ok(files) => {
             let mut savefiles = Vec::new();
@@ -261,13 +364,14 @@ pub fn get_available_local_savefiles() -> Option<Vec<String>> {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExportStruct {
+    pub kanban_version: String,
+    pub export_date: String,
+    pub boards: Vec<Board>,
+}
+
 pub fn export_kanban_to_json(boards: &[Board]) -> Result<String, String> {
-    #[derive(Serialize)]
-    struct ExportStruct {
-        kanban_version: String,
-        export_date: String,
-        boards: Vec<Board>,
-    }
     // use serde serialization
     let get_config_status = get_config(false);
     let config = if let Ok(config) = get_config_status {
@@ -286,23 +390,7 @@ pub fn export_kanban_to_json(boards: &[Board]) -> Result<String, String> {
         export_date: date.to_string(),
         boards: boards.to_vec(),
     };
-    let file_path = config.save_directory.join("kanban_export.json");
-    // check if file exists if so add a number to the end of the file name with _<number>
-    let file_path = if file_path.exists() {
-        let mut i = 1;
-        let mut new_file_path = config
-            .save_directory
-            .join(format!("kanban_export_{}.json", i));
-        while new_file_path.exists() {
-            i += 1;
-            new_file_path = config
-                .save_directory
-                .join(format!("kanban_export_{}.json", i));
-        }
-        new_file_path
-    } else {
-        file_path
-    };
+    let file_path = get_available_export_path(&config.save_directory, "json");
     // write to file
     let write_status = fs::write(
         file_path.clone(),
@@ -314,6 +402,590 @@ pub fn export_kanban_to_json(boards: &[Board]) -> Result<String, String> {
     }
 }
 
+// check if file exists if so add a number to the end of the file name with _<number>
+fn get_available_export_path(save_directory: &Path, extension: &str) -> PathBuf {
+    let file_path = save_directory.join(format!("kanban_export.{}", extension));
+    if !file_path.exists() {
+        return file_path;
+    }
+    let mut i = 1;
+    let mut new_file_path = save_directory.join(format!("kanban_export_{}.{}", i, extension));
+    while new_file_path.exists() {
+        i += 1;
+        new_file_path = save_directory.join(format!("kanban_export_{}.{}", i, extension));
+    }
+    new_file_path
+}
+
+pub fn import_kanban_from_json(file_path: &Path) -> Result<ExportStruct, String> {
+    let file_contents = fs::read_to_string(file_path)
+        .map_err(|e| format!("Could not read {}: {}", file_path.display(), e))?;
+    let export_struct: ExportStruct = serde_json::from_str(&file_contents).map_err(|e| {
+        format!(
+            "{} is not a valid kanban export: {}",
+            file_path.display(),
+            e
+        )
+    })?;
+    if export_struct.kanban_version.is_empty() {
+        return Err(format!(
+            "{} does not specify the version it was exported from",
+            file_path.display()
+        ));
+    }
+    validate_unique_ids(&export_struct.boards)?;
+    Ok(export_struct)
+}
+
+// ids are used to find boards and cards, duplicates would make them unreachable
+fn validate_unique_ids(boards: &[Board]) -> Result<(), String> {
+    let mut board_ids = HashSet::new();
+    let mut card_ids = HashSet::new();
+    for board in boards.iter() {
+        if !board_ids.insert(board.id) {
+            return Err(format!(
+                "Duplicate board id found for board '{}'",
+                board.name
+            ));
+        }
+        for card in board.cards.iter() {
+            if !card_ids.insert(card.id) {
+                return Err(format!("Duplicate card id found for card '{}'", card.name));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Finds the most recently modified kanban_export*.json in the save directory
+pub fn get_latest_kanban_export() -> Option<PathBuf> {
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let re = Regex::new(r"^kanban_export(_\d+)?\.json$").unwrap();
+    fs::read_dir(&config.save_directory)
+        .ok()?
+        .filter_map(|file| file.ok())
+        .filter(|file| re.is_match(&file.file_name().to_string_lossy()))
+        .filter_map(|file| Some((file.metadata().ok()?.modified().ok()?, file.path())))
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+/// Merges imported boards into existing ones (matched by id, then by name), cards that already exist in any
+/// board (same id) are skipped. Returns the number of boards and cards added
+pub fn merge_imported_boards(
+    boards: &mut Vec<Board>,
+    imported_boards: Vec<Board>,
+) -> (usize, usize) {
+    let mut boards_added = 0;
+    let mut cards_added = 0;
+    let mut card_ids = boards
+        .iter()
+        .flat_map(|board| board.cards.iter().map(|card| card.id))
+        .collect::<HashSet<u128>>();
+    for mut imported_board in imported_boards {
+        let existing_board_index = boards
+            .iter()
+            .position(|b| b.id == imported_board.id)
+            .or_else(|| boards.iter().position(|b| b.name == imported_board.name));
+        if let Some(existing_board_index) = existing_board_index {
+            let board = &mut boards[existing_board_index];
+            for mut card in imported_board.cards {
+                if card_ids.insert(card.id) {
+                    board.adopt_card_status(&mut card);
+                    board.cards.push(card);
+                    cards_added += 1;
+                }
+            }
+        } else {
+            imported_board.cards.retain(|card| card_ids.insert(card.id));
+            cards_added += imported_board.cards.len();
+            boards_added += 1;
+            boards.push(imported_board);
+        }
+    }
+    (boards_added, cards_added)
+}
+
+pub fn export_kanban_to_markdown(boards: &[Board]) -> Result<String, String> {
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let file_path = get_available_export_path(&config.save_directory, "md");
+    let write_status = fs::write(file_path.clone(), boards_to_markdown(boards));
+    match write_status {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn import_kanban_from_markdown(file_path: &Path) -> Result<Vec<Board>, String> {
+    let file_contents = fs::read_to_string(file_path)
+        .map_err(|e| format!("Could not read {}: {}", file_path.display(), e))?;
+    let boards = markdown_to_boards(&file_contents)?;
+    validate_unique_ids(&boards)?;
+    Ok(boards)
+}
+
+/// Boards are level 2 headings and cards are task list items with their metadata in inline code spans, e.g.
+/// - [ ] Fix login `status: Active` `priority: High` `due: 2023/01/20-12:00:00` `tags: backend, urgent` `id: 42`
+pub fn boards_to_markdown(boards: &[Board]) -> String {
+    let mut markdown = format!(
+        "# {}\n<!-- kanban_version: {}, export_date: {} -->\n",
+        APP_TITLE,
+        env!("CARGO_PKG_VERSION"),
+        chrono::Local::now().format("%d-%m-%Y")
+    );
+    for board in boards {
+        markdown.push_str(&format!("\n## {}\n<!-- id: {} -->\n", board.name, board.id));
+        if board.workflow() != WorkflowStatus::default_workflow() {
+            markdown.push_str(&format!(
+                "<!-- workflow: {} -->\n",
+                WorkflowStatus::workflow_to_string(&board.workflow)
+            ));
+        }
+        if !board.description.is_empty() && board.description != FIELD_NOT_SET {
+            markdown.push_str(&format!("{}\n", board.description));
+        }
+        if !board.cards.is_empty() {
+            markdown.push('\n');
+        }
+        for card in board.cards.iter() {
+            let check_box = if card.card_status == CardStatus::Complete {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            markdown.push_str(&format!(
+                "- {} {} `status: {}` `priority: {}`",
+                check_box,
+                card.name,
+                card.status_name(),
+                card.priority
+            ));
+            if let Some(date_due) = card.date_due {
+                markdown.push_str(&format!(" `due: {}`", date_due));
+            }
+            if let Some(date_completed) = card.date_completed {
+                markdown.push_str(&format!(" `completed: {}`", date_completed));
+            }
+            if !card.tags.is_empty() {
+                markdown.push_str(&format!(" `tags: {}`", card.tags.join(", ")));
+            }
+            markdown.push_str(&format!(" `id: {}`\n", card.id));
+            if !card.description.is_empty() && card.description != FIELD_NOT_SET {
+                for line in card.description.lines() {
+                    markdown.push_str(&format!("  {}\n", line));
+                }
+            }
+            for comment in card.comments.iter() {
+                markdown.push_str(&format!("  > {}\n", comment));
+            }
+        }
+    }
+    markdown
+}
+
+pub fn markdown_to_boards(markdown: &str) -> Result<Vec<Board>, String> {
+    let metadata_re = Regex::new(r"`(\w+): ([^`]*)`").unwrap();
+    let id_comment_re = Regex::new(r"^<!--\s*id:\s*(\d+)\s*-->$").unwrap();
+    let workflow_comment_re = Regex::new(r"^<!--\s*workflow:\s*(.*?)\s*-->$").unwrap();
+    let mut boards: Vec<Board> = Vec::new();
+    for (line_index, line) in markdown.lines().enumerate() {
+        let line_number = line_index + 1;
+        let trimmed_line = line.trim();
+        if trimmed_line.is_empty() || line.starts_with("# ") {
+            continue;
+        }
+        if let Some(board_name) = line.strip_prefix("## ") {
+            boards.push(Board::new(board_name.trim().to_string(), String::new()));
+            continue;
+        }
+        let board = if let Some(board) = boards.last_mut() {
+            board
+        } else {
+            // anything before the first board heading is ignored
+            continue;
+        };
+        if let Some(captures) = id_comment_re.captures(trimmed_line) {
+            board.id = captures[1]
+                .parse::<u128>()
+                .map_err(|e| format!("Invalid board id on line {}: {}", line_number, e))?;
+            continue;
+        }
+        if let Some(captures) = workflow_comment_re.captures(trimmed_line) {
+            board.workflow = WorkflowStatus::parse_workflow(&captures[1])
+                .map_err(|e| format!("{} on line {}", e, line_number))?;
+            continue;
+        }
+        let task = line
+            .strip_prefix("- [ ] ")
+            .map(|task| (task, false))
+            .or_else(|| line.strip_prefix("- [x] ").map(|task| (task, true)))
+            .or_else(|| line.strip_prefix("- [X] ").map(|task| (task, true)));
+        if let Some((task, checked)) = task {
+            let name_end = metadata_re
+                .find(task)
+                .map(|m| m.start())
+                .unwrap_or(task.len());
+            let mut card = Card::new(
+                task[..name_end].trim().to_string(),
+                String::new(),
+                None,
+                CardPriority::Low,
+                vec![],
+                vec![],
+            );
+            for captures in metadata_re.captures_iter(&task[name_end..]) {
+                let value = captures[2].trim();
+                match &captures[1] {
+                    "status" => {
+                        let workflow_status =
+                            find_imported_status(board, value).ok_or_else(|| {
+                                format!("Invalid status '{}' on line {}", value, line_number)
+                            })?;
+                        apply_imported_status(&mut card, &workflow_status);
+                    }
+                    "priority" => {
+                        card.priority = CardPriority::all()
+                            .into_iter()
+                            .find(|priority| priority.to_string().eq_ignore_ascii_case(value))
+                            .ok_or_else(|| {
+                                format!("Invalid priority '{}' on line {}", value, line_number)
+                            })?;
+                    }
+                    "due" => {
+                        card.date_due = CardDate::parse_optional(value)
+                            .map_err(|e| format!("{} on line {}", e, line_number))?;
+                    }
+                    "completed" => {
+                        card.date_completed = CardDate::parse_optional(value)
+                            .map_err(|e| format!("{} on line {}", e, line_number))?;
+                    }
+                    "tags" => {
+                        card.tags = value
+                            .split(',')
+                            .map(|tag| tag.trim().to_string())
+                            .filter(|tag| !tag.is_empty())
+                            .collect();
+                    }
+                    "id" => {
+                        card.id = value.parse::<u128>().map_err(|e| {
+                            format!("Invalid card id on line {}: {}", line_number, e)
+                        })?;
+                    }
+                    _ => {}
+                }
+            }
+            // the check box wins over the status so cards can be ticked off in the markdown
+            if checked && card.card_status != CardStatus::Complete {
+                let workflow_status = board
+                    .workflow_status_of_kind(&CardStatus::Complete)
+                    .unwrap_or_else(|| {
+                        WorkflowStatus::new(&CardStatus::Complete.to_string(), CardStatus::Complete)
+                    });
+                apply_imported_status(&mut card, &workflow_status);
+            } else if !checked && card.card_status == CardStatus::Complete {
+                let workflow_status = board
+                    .workflow_status_of_kind(&CardStatus::Active)
+                    .unwrap_or_else(|| board.workflow()[0].clone());
+                apply_imported_status(&mut card, &workflow_status);
+            }
+            board.cards.push(card);
+            continue;
+        }
+        if line.starts_with("  ") {
+            if let Some(card) = board.cards.last_mut() {
+                if let Some(comment) = trimmed_line.strip_prefix('>') {
+                    card.comments.push(comment.trim().to_string());
+                } else if card.description == FIELD_NOT_SET {
+                    card.description = trimmed_line.to_string();
+                } else {
+                    card.description.push('\n');
+                    card.description.push_str(trimmed_line);
+                }
+                continue;
+            }
+        }
+        if !board.cards.is_empty() {
+            return Err(format!(
+                "Unexpected line {} in board '{}': {}",
+                line_number, board.name, line
+            ));
+        }
+        if board.description.is_empty() {
+            board.description = trimmed_line.to_string();
+        } else {
+            board.description.push('\n');
+            board.description.push_str(trimmed_line);
+        }
+    }
+    Ok(boards)
+}
+
+// one row per card, flattened with the name of its board
+#[derive(Serialize, Deserialize, Debug)]
+struct CsvCardRecord {
+    board: String,
+    id: String,
+    name: String,
+    description: String,
+    date_created: String,
+    date_modified: String,
+    date_due: String,
+    date_completed: String,
+    priority: String,
+    status: String,
+    tags: String,
+    comments: String,
+}
+
+pub fn export_kanban_to_csv(boards: &[Board]) -> Result<String, String> {
+    let get_config_status = get_config(false);
+    let config = if let Ok(config) = get_config_status {
+        config
+    } else {
+        debug!("Error getting config: {}", get_config_status.unwrap_err());
+        AppConfig::default()
+    };
+    let file_path = get_available_export_path(&config.save_directory, "csv");
+    let write_status = fs::write(file_path.clone(), boards_to_csv(boards)?);
+    match write_status {
+        Ok(_) => Ok(file_path.to_str().unwrap().to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub fn import_kanban_from_csv(file_path: &Path) -> Result<Vec<Board>, String> {
+    let file_contents = fs::read_to_string(file_path)
+        .map_err(|e| format!("Could not read {}: {}", file_path.display(), e))?;
+    let boards = csv_to_boards(&file_contents)?;
+    validate_unique_ids(&boards)?;
+    Ok(boards)
+}
+
+/// Tags are joined with ", " and comments with new lines
+pub fn boards_to_csv(boards: &[Board]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for board in boards {
+        for card in board.cards.iter() {
+            writer
+                .serialize(CsvCardRecord {
+                    board: board.name.clone(),
+                    id: card.id.to_string(),
+                    name: card.name.clone(),
+                    description: card.description.clone(),
+                    date_created: card.date_created.to_string(),
+                    date_modified: card.date_modified.to_string(),
+                    date_due: card
+                        .date_due
+                        .map(|date| date.to_string())
+                        .unwrap_or_default(),
+                    date_completed: card
+                        .date_completed
+                        .map(|date| date.to_string())
+                        .unwrap_or_default(),
+                    priority: card.priority.to_string(),
+                    status: if card.status_name() == card.card_status.to_string() {
+                        card.status_name()
+                    } else {
+                        format!("{}:{}", card.status_name(), card.card_status)
+                    },
+                    tags: card.tags.join(", "),
+                    comments: card.comments.join("\n"),
+                })
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    let csv_bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(csv_bytes).map_err(|e| e.to_string())
+}
+
+pub fn csv_to_boards(csv_string: &str) -> Result<Vec<Board>, String> {
+    let mut reader = csv::Reader::from_reader(csv_string.as_bytes());
+    let mut boards: Vec<Board> = Vec::new();
+    for (row_index, record) in reader.deserialize::<CsvCardRecord>().enumerate() {
+        // header is line 1
+        let line_number = row_index + 2;
+        let record = record.map_err(|e| format!("Invalid row on line {}: {}", line_number, e))?;
+        let priority = CardPriority::all()
+            .into_iter()
+            .find(|priority| {
+                priority
+                    .to_string()
+                    .eq_ignore_ascii_case(record.priority.trim())
+            })
+            .ok_or_else(|| {
+                format!(
+                    "Invalid priority '{}' on line {}",
+                    record.priority, line_number
+                )
+            })?;
+        // custom workflow statuses are written as "name:kind"
+        let workflow_status = match find_imported_status(&Board::default(), &record.status) {
+            Some(workflow_status) => workflow_status,
+            None => WorkflowStatus::parse(&record.status)
+                .map_err(|e| format!("{} on line {}", e, line_number))?,
+        };
+        let parse_date = |value: &str| {
+            CardDate::parse_optional(value).map_err(|e| format!("{} on line {}", e, line_number))
+        };
+        let mut card = Card::new(
+            record.name,
+            record.description,
+            parse_date(&record.date_due)?,
+            priority,
+            record
+                .tags
+                .split(',')
+                .map(|tag| tag.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+            record
+                .comments
+                .lines()
+                .filter(|comment| !comment.trim().is_empty())
+                .map(|comment| comment.to_string())
+                .collect(),
+        );
+        // rows added by hand in a spreadsheet will not have an id or dates yet
+        if !record.id.trim().is_empty() {
+            card.id = record
+                .id
+                .trim()
+                .parse::<u128>()
+                .map_err(|e| format!("Invalid card id on line {}: {}", line_number, e))?;
+        }
+        if let Some(date_created) = parse_date(&record.date_created)? {
+            card.date_created = date_created;
+            card.history[0].date = date_created;
+        }
+        if let Some(date_modified) = parse_date(&record.date_modified)? {
+            card.date_modified = date_modified;
+        }
+        card.date_completed = parse_date(&record.date_completed)?;
+        apply_imported_status(&mut card, &workflow_status);
+        let board_name = record.board.trim();
+        if board_name.is_empty() {
+            return Err(format!("Missing board name on line {}", line_number));
+        }
+        let board_index = match boards.iter().position(|board| board.name == board_name) {
+            Some(board_index) => board_index,
+            None => {
+                boards.push(Board::new(board_name.to_string(), String::new()));
+                boards.len() - 1
+            }
+        };
+        let board = &mut boards[board_index];
+        if board.find_workflow_status(&workflow_status.name).is_none() {
+            board.workflow.push(workflow_status);
+        }
+        board.cards.push(card);
+    }
+    Ok(boards)
+}
+
+/// Looks up an imported status in the board workflow, the built in status names are always accepted
+fn find_imported_status(board: &Board, name: &str) -> Option<WorkflowStatus> {
+    board.find_workflow_status(name).or_else(|| {
+        CardStatus::all()
+            .into_iter()
+            .find(|status| status.to_string().eq_ignore_ascii_case(name.trim()))
+            .map(|status| WorkflowStatus::new(&status.to_string(), status))
+    })
+}
+
+/// Unlike Card::set_status this keeps the imported dates, only a missing completion date is stamped
+fn apply_imported_status(card: &mut Card, workflow_status: &WorkflowStatus) {
+    card.stamp_date_completed(&workflow_status.kind, CardDate::now());
+    card.card_status = workflow_status.kind.clone();
+    card.workflow_status = workflow_status.name.clone();
+}
+
+/// Names of the workspaces with a save directory, the default workspace first
+pub fn get_workspaces() -> Vec<String> {
+    let config = get_config(false).unwrap_or_default();
+    let mut workspaces = fs::read_dir(config.save_directory.join(WORKSPACES_DIR_NAME))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default();
+    workspaces.sort_by_key(|workspace| workspace.to_lowercase());
+    workspaces.insert(0, DEFAULT_WORKSPACE_NAME.to_string());
+    workspaces
+}
+
+/// The config workspace value for a workspace name, empty for the default workspace
+fn workspace_from_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.eq_ignore_ascii_case(DEFAULT_WORKSPACE_NAME) {
+        return Ok(String::new());
+    }
+    let workspace = make_file_system_safe_name(name);
+    if workspace.is_empty() && !name.is_empty() {
+        return Err(format!("Invalid workspace name: {}", name));
+    }
+    Ok(workspace)
+}
+
+/// Makes the named workspace the current one in the config and creates its save directory,
+/// an empty name or the default workspace name switch back to the default workspace
+pub fn set_current_workspace(name: &str) -> Result<AppConfig, String> {
+    let workspace = workspace_from_name(name)?;
+    // an explicit switch replaces the workspace picked for this run
+    *WORKSPACE_OVERRIDE.lock().unwrap() = None;
+    let mut config = get_config(false).unwrap_or_default();
+    config.workspace = workspace;
+    fs::create_dir_all(config.workspace_save_directory())
+        .map_err(|e| format!("Error creating workspace directory: {}", e))?;
+    write_config(&config)?;
+    Ok(config)
+}
+
+/// Uses the named workspace until the app exits without changing the current workspace in the
+/// config file, its save directory is created if needed
+pub fn use_workspace_for_this_run(name: &str) -> Result<(), String> {
+    let workspace = workspace_from_name(name)?;
+    *WORKSPACE_OVERRIDE.lock().unwrap() = None;
+    let config = get_config(true).unwrap_or_default();
+    let workspace_save_directory = AppConfig {
+        workspace: workspace.clone(),
+        ..config.clone()
+    }
+    .workspace_save_directory();
+    fs::create_dir_all(workspace_save_directory)
+        .map_err(|e| format!("Error creating workspace directory: {}", e))?;
+    *WORKSPACE_OVERRIDE.lock().unwrap() = Some((workspace, config.workspace));
+    Ok(())
+}
+
+fn with_workspace_override(mut config: AppConfig) -> AppConfig {
+    if let Some((workspace, _)) = WORKSPACE_OVERRIDE.lock().unwrap().as_ref() {
+        config.workspace = workspace.clone();
+    }
+    config
+}
+
+fn without_workspace_override(mut config: AppConfig) -> AppConfig {
+    if let Some((_, config_workspace)) = WORKSPACE_OVERRIDE.lock().unwrap().as_ref() {
+        config.workspace = config_workspace.clone();
+    }
+    config
+}
+
 pub fn get_default_save_directory() -> PathBuf {
     let mut default_save_path = env::temp_dir();
     default_save_path.push(SAVE_DIR_NAME);
@@ -394,3 +1066,184 @@ pub fn save_theme(theme: Theme) -> Result<String, String> {
     }
     Ok(theme_path.to_str().unwrap().to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_boards_through_markdown() {
+        let mut board = Board::new("Backend".to_string(), "Server work".to_string());
+        let mut card = Card::new(
+            "Fix login".to_string(),
+            "Users get logged out\nafter a minute".to_string(),
+            CardDate::parse("20/01/2023-12:00:00"),
+            CardPriority::High,
+            vec!["auth".to_string(), "urgent".to_string()],
+            vec!["Seen in prod".to_string()],
+        );
+        board.workflow =
+            WorkflowStatus::parse_workflow("Backlog, Review:Stale, Done:Complete").unwrap();
+        card.set_status(&board.workflow[1]);
+        board.cards.push(card);
+        let boards = vec![board];
+
+        let imported_boards = markdown_to_boards(&boards_to_markdown(&boards)).unwrap();
+        assert_eq!(imported_boards.len(), 1);
+        assert_eq!(imported_boards[0].id, boards[0].id);
+        assert_eq!(imported_boards[0].description, boards[0].description);
+        assert_eq!(imported_boards[0].workflow, boards[0].workflow);
+        let (card, imported_card) = (&boards[0].cards[0], &imported_boards[0].cards[0]);
+        assert_eq!(imported_card.id, card.id);
+        assert_eq!(imported_card.name, card.name);
+        assert_eq!(imported_card.description, card.description);
+        assert_eq!(imported_card.date_due, card.date_due);
+        assert_eq!(imported_card.priority, card.priority);
+        assert_eq!(imported_card.card_status, card.card_status);
+        assert_eq!(imported_card.workflow_status, card.workflow_status);
+        assert_eq!(imported_card.tags, card.tags);
+        assert_eq!(imported_card.comments, card.comments);
+    }
+
+    #[test]
+    fn should_round_trip_cards_through_csv() {
+        let mut board = Board::new("Backend".to_string(), String::new());
+        board.cards.push(Card::new(
+            "Fix login, again".to_string(),
+            "Users get \"logged out\"".to_string(),
+            None,
+            CardPriority::Medium,
+            vec!["auth".to_string(), "urgent".to_string()],
+            vec!["Seen in prod".to_string(), "Fixed in staging".to_string()],
+        ));
+        let boards = vec![board];
+
+        let imported_boards = csv_to_boards(&boards_to_csv(&boards).unwrap()).unwrap();
+        assert_eq!(imported_boards.len(), 1);
+        assert_eq!(imported_boards[0].name, boards[0].name);
+        assert_eq!(imported_boards[0].cards, boards[0].cards);
+    }
+
+    #[test]
+    fn should_complete_ticked_markdown_cards() {
+        let markdown = "## Todo\n- [x] Write docs `status: Active`\n- [ ] Review\n\
+            - [x] Ship `completed: 20/01/2023-12:00:00`\n";
+        let boards = markdown_to_boards(markdown).unwrap();
+        assert_eq!(boards[0].cards[0].card_status, CardStatus::Complete);
+        assert!(boards[0].cards[0].date_completed.is_some());
+        assert_eq!(
+            boards[0].cards[2].date_completed,
+            CardDate::parse("20/01/2023-12:00:00")
+        );
+        assert_eq!(boards[0].cards[1].card_status, CardStatus::Active);
+        assert_eq!(boards[0].cards[1].description, FIELD_NOT_SET);
+    }
+
+    #[test]
+    fn should_merge_imported_boards_without_duplicate_ids() {
+        let card = |name: &str| {
+            Card::new(
+                name.to_string(),
+                String::new(),
+                None,
+                CardPriority::Low,
+                vec![],
+                vec![],
+            )
+        };
+        let mut todo = Board::new("Todo".to_string(), String::new());
+        todo.cards.push(card("Fix login"));
+        let mut done = Board::new("Done".to_string(), String::new());
+        done.cards.push(card("Write docs"));
+        let mut boards = vec![todo, done];
+
+        // matched by name, the card already in "Done" is not added again
+        let mut imported_todo = Board::new("Todo".to_string(), String::new());
+        imported_todo.cards = vec![boards[1].cards[0].clone(), card("Add cache")];
+        // matched by id
+        let mut imported_done = boards[1].clone();
+        imported_done.name = "Finished".to_string();
+        imported_done.cards = vec![boards[0].cards[0].clone(), card("Release")];
+        // a new board keeps only the cards that are not in any board yet
+        let mut imported_review = Board::new("Review".to_string(), String::new());
+        imported_review.cards = vec![boards[0].cards[0].clone(), card("Check perf")];
+
+        let (boards_added, cards_added) = merge_imported_boards(
+            &mut boards,
+            vec![imported_todo, imported_done, imported_review],
+        );
+        assert_eq!((boards_added, cards_added), (1, 3));
+        let card_names = |board: &Board| {
+            board
+                .cards
+                .iter()
+                .map(|card| card.name.clone())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(card_names(&boards[0]), vec!["Fix login", "Add cache"]);
+        assert_eq!(card_names(&boards[1]), vec!["Write docs", "Release"]);
+        assert_eq!(boards[2].name, "Review");
+        assert_eq!(card_names(&boards[2]), vec!["Check perf"]);
+        assert!(validate_unique_ids(&boards).is_ok());
+    }
+
+    #[test]
+    fn should_find_workspace_save_directories() {
+        assert_eq!(workspace_from_name(" default "), Ok(String::new()));
+        assert_eq!(workspace_from_name(""), Ok(String::new()));
+        assert_eq!(
+            workspace_from_name("Side Project"),
+            Ok("SideProject".to_string())
+        );
+        assert!(workspace_from_name("/:*").is_err());
+
+        let mut config = AppConfig {
+            save_directory: PathBuf::from("saves"),
+            ..AppConfig::default()
+        };
+        assert_eq!(config.workspace_name(), DEFAULT_WORKSPACE_NAME);
+        assert_eq!(config.workspace_save_directory(), PathBuf::from("saves"));
+        config.workspace = workspace_from_name("Side Project").unwrap();
+        assert_eq!(config.workspace_name(), "SideProject");
+        assert_eq!(
+            config.workspace_save_directory(),
+            PathBuf::from("saves")
+                .join(WORKSPACES_DIR_NAME)
+                .join("SideProject")
+        );
+    }
+
+    #[test]
+    fn should_migrate_legacy_save_files() {
+        let legacy_boards = vec![LegacyBoard {
+            id: 1,
+            name: "Backend".to_string(),
+            description: String::new(),
+            cards: vec![LegacyCard {
+                id: 2,
+                name: "Fix login".to_string(),
+                description: FIELD_NOT_SET.to_string(),
+                date_created: "2023-01-18 10:00:00.000000 UTC".to_string(),
+                date_modified: "18/01/2023-11:00:00".to_string(),
+                date_due: "2023/01/20-12:00:00".to_string(),
+                date_completed: "N/A".to_string(),
+                priority: CardPriority::High,
+                card_status: CardStatus::Active,
+                tags: vec![],
+                comments: vec![],
+            }],
+        }];
+        let file_path = env::temp_dir().join(format!("kanban_legacy_save_{}", std::process::id()));
+        save_file(&file_path, 3, &legacy_boards).unwrap();
+
+        let boards = load_boards_from_file(&file_path, 3).unwrap();
+        fs::remove_file(&file_path).unwrap();
+        let card = &boards[0].cards[0];
+        assert_eq!(
+            card.date_modified,
+            CardDate::parse("18/01/2023-11:00:00").unwrap()
+        );
+        assert_eq!(card.date_due, CardDate::parse("20/01/2023-12:00:00"));
+        assert_eq!(card.date_completed, None);
+    }
+}
@@ -3,7 +3,7 @@ use eyre::{anyhow, Result};
 use linked_hash_map::LinkedHashMap;
 use log::{debug, error, info};
 use ratatui::widgets::ListState;
-use savefile::{load_file, save_file};
+use savefile::save_file;
 use std::{
     env,
     path::{Path, PathBuf},
@@ -15,10 +15,12 @@ use super::data_handler::{get_available_local_savefiles, get_local_kanban_state}
 use super::IoEvent;
 use crate::{
     app::{kanban::Board, state::UiMode, App, AppConfig},
-    constants::{CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_NAME},
+    constants::{
+        CONFIG_DIR_NAME, CONFIG_FILE_NAME, SAVE_DIR_NAME, SAVE_FILE_FORMAT_VERSION, SAVE_FILE_NAME,
+    },
     io::data_handler::{
         get_config, get_default_save_directory, get_saved_themes, reset_config,
-        save_kanban_state_locally,
+        save_kanban_state_locally, set_current_workspace,
     },
     ui::TextColorOptions,
 };
@@ -45,6 +47,7 @@ impl IoAsyncHandler {
             IoEvent::ResetVisibleBoardsandCards => self.refresh_visible_boards_and_cards().await,
             IoEvent::AutoSave => self.auto_save().await,
             IoEvent::LoadPreview => self.load_preview().await,
+            IoEvent::SwitchWorkspace(workspace) => self.switch_workspace(workspace).await,
         };
 
         let mut app = self.app.lock().await;
@@ -69,6 +72,8 @@ impl IoAsyncHandler {
             app.send_error_toast("Cannot create save directory", None);
         }
         app.boards = prepare_boards(&mut app);
+        app.create_next_recurring_cards();
+        app.auto_archive_completed_cards();
         app.keybind_list_maker();
         app.dispatch(IoEvent::ResetVisibleBoardsandCards).await;
         app.initialized(); // we could update the app state
@@ -172,6 +177,8 @@ impl IoAsyncHandler {
         match board_data {
             Ok(boards) => {
                 app.set_boards(boards);
+                app.create_next_recurring_cards();
+                app.auto_archive_completed_cards();
                 info!("👍 Save file {:?} loaded", save_file_name);
                 app.send_info_toast(&format!("👍 Save file {:?} loaded", save_file_name), None);
             }
@@ -216,7 +223,7 @@ impl IoAsyncHandler {
         } else {
             get_config_status.unwrap()
         };
-        let path = config.save_directory.join(file_name);
+        let path = config.workspace_save_directory().join(file_name);
         // check if the file exists
         if !Path::new(&path).exists() {
             error!("Cannot delete save file: file not found");
@@ -263,6 +270,43 @@ impl IoAsyncHandler {
         auto_save(&mut app).await
     }
 
+    async fn switch_workspace(&mut self, workspace: String) -> Result<()> {
+        let mut app = self.app.lock().await;
+        // keep the changes of the workspace being left, unless it never had anything to save
+        if !app.boards.is_empty() || get_latest_save_file().is_ok() {
+            auto_save(&mut app).await?;
+        }
+        let config = match set_current_workspace(&workspace) {
+            Ok(config) => config,
+            Err(e) => {
+                error!("Cannot switch workspace: {}", e);
+                app.send_error_toast(&format!("Cannot switch workspace: {}", e), None);
+                return Ok(());
+            }
+        };
+        app.config.workspace = config.workspace;
+        info!("🚀 Switching to workspace {}", app.config.workspace_name());
+        let boards = match get_latest_save_file() {
+            Ok((latest_save_file, latest_version)) => {
+                get_local_kanban_state(latest_save_file, latest_version, false)?
+            }
+            Err(_) => vec![],
+        };
+        app.set_boards(boards);
+        app.create_next_recurring_cards();
+        app.auto_archive_completed_cards();
+        app.state.active_filter = None;
+        refresh_visible_boards_and_cards(&mut app);
+        app.state.ui_mode = app.config.default_view;
+        let workspace_name = app.config.workspace_name().to_string();
+        info!("👍 Switched to workspace {}", workspace_name);
+        app.send_info_toast(
+            &format!("👍 Switched to workspace {}", workspace_name),
+            None,
+        );
+        Ok(())
+    }
+
     async fn load_preview(&mut self) -> Result<()> {
         let mut app = self.app.lock().await;
         if app.state.load_save_state.selected().is_none() {
@@ -303,7 +347,13 @@ impl IoAsyncHandler {
         let board_data = get_local_kanban_state(save_file_name.clone(), version, true);
         match board_data {
             Ok(boards) => {
-                app.state.preview_boards_and_cards = Some(boards);
+                app.state.preview_boards_and_cards = Some(
+                    boards
+                        .iter()
+                        .filter(|board| !board.archived)
+                        .map(|board| board.without_archived_cards())
+                        .collect(),
+                );
                 // get self.boards and make Vec<LinkedHashMap<u128, Vec<u128>>> of visible boards and cards
                 let mut visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>> =
                     LinkedHashMap::new();
@@ -451,7 +501,7 @@ fn prepare_boards(app: &mut App) -> Vec<Board> {
 }
 
 // return save file name and the latest verison
-fn get_latest_save_file() -> Result<(String, u32)> {
+pub fn get_latest_save_file() -> Result<(String, u32)> {
     let local_save_files = get_available_local_savefiles();
     let local_save_files = if let Some(local_save_files) = local_save_files {
         local_save_files
@@ -498,7 +548,7 @@ fn get_latest_save_file() -> Result<(String, u32)> {
 
 pub fn refresh_visible_boards_and_cards(app: &mut App) {
     let mut visible_boards_and_cards: LinkedHashMap<u128, Vec<u128>> = LinkedHashMap::new();
-    for (i, board) in app.boards.iter().enumerate() {
+    for (i, board) in app.filtered_boards().iter().enumerate() {
         if (i) as u16 == app.config.no_of_boards_to_show {
             break;
         }
@@ -534,6 +584,8 @@ pub fn refresh_visible_boards_and_cards(app: &mut App) {
         {
             app.state.current_card_id =
                 Some(app.visible_boards_and_cards.values().next().unwrap()[0]);
+        } else {
+            app.state.current_card_id = None;
         }
     }
 }
@@ -561,8 +613,7 @@ pub async fn auto_save(app: &mut App) -> Result<()> {
         let latest_save_file_info = latest_save_file_info.unwrap();
         let save_file_name = latest_save_file_info.0;
         file_version = latest_save_file_info.1;
-        let file_path = config.save_directory.join(save_file_name);
-        let boards: Vec<Board> = load_file(file_path, file_version)?;
+        let boards = get_local_kanban_state(save_file_name, file_version, true)?;
         app.boards != boards
     } else {
         true
@@ -574,8 +625,10 @@ pub async fn auto_save(app: &mut App) -> Result<()> {
             chrono::Local::now().format("%d-%m-%Y"),
             file_version + 1
         );
-        let file_path = config.save_directory.join(file_name);
-        let save_status = save_file(file_path, file_version, &app.boards);
+        let save_directory = config.workspace_save_directory();
+        std::fs::create_dir_all(&save_directory)?;
+        let file_path = save_directory.join(file_name);
+        let save_status = save_file(file_path, SAVE_FILE_FORMAT_VERSION, &app.boards);
         match save_status {
             Ok(_) => Ok(()),
             Err(e) => Err(anyhow!("Error saving file: {}", e)),
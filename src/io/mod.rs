@@ -12,4 +12,5 @@ pub enum IoEvent {
     ResetVisibleBoardsandCards,
     AutoSave,
     LoadPreview,
+    SwitchWorkspace(String),
 }
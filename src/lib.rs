@@ -1,13 +1,26 @@
-use app::{App, AppReturn};
-use crossterm::{event::EnableMouseCapture, execute};
-use eyre::Result;
+use app::{
+    app_helper::{card_editor_text, handle_card_editor_result},
+    App, AppReturn,
+};
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+};
+use eyre::{bail, eyre, Result};
 use inputs::{events::Events, InputEvent};
 use io::IoEvent;
-use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
-use std::{borrow::Cow, io::stdout, sync::Arc, time::Duration};
+use ratatui::{backend::CrosstermBackend, Terminal};
+use std::{
+    env, fs,
+    io::{stdout, Stdout},
+    process::Command,
+    sync::Arc,
+    time::Duration,
+};
 use ui::ui_main;
 
 pub mod app;
+pub mod cli;
 pub mod constants;
 pub mod inputs;
 pub mod io;
@@ -29,10 +42,8 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
     terminal.hide_cursor()?;
 
     // User event handler
-    let mut events = {
-        let tick_rate = app.lock().await.config.tickrate;
-        Events::new(Duration::from_millis(tick_rate))
-    };
+    let tick_rate = Duration::from_millis(app.lock().await.config.tickrate);
+    let mut events = Events::new(tick_rate);
 
     // Trigger state change from Init to Initialized
     {
@@ -64,6 +75,16 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
             events.close();
             break;
         }
+        if result == AppReturn::OpenInEditor {
+            if let Some(text) = card_editor_text(&app) {
+                events.pause(tick_rate).await;
+                let edited_text =
+                    edit_in_external_editor(&mut terminal, &text, app.config.enable_mouse_support)
+                        .map_err(|e| e.to_string());
+                events.resume();
+                handle_card_editor_result(&mut app, edited_text);
+            }
+        }
     }
 
     // Restore the terminal and close application
@@ -76,32 +97,58 @@ pub async fn start_ui(app: &Arc<tokio::sync::Mutex<App>>) -> Result<()> {
     Ok(())
 }
 
-/// Takes wrapped text and the current cursor position (1D) and the avaiable space to return the x and y position of the cursor (2D)
-fn calculate_cursor_position(
-    text: Vec<Cow<str>>,
-    current_cursor_position: usize,
-    view_box: Rect,
-) -> (u16, u16) {
-    let wrapped_text_iter = text.iter();
-    let mut cursor_pos = current_cursor_position;
+/// Hands the terminal over to $VISUAL or $EDITOR to edit the text and takes it back afterwards
+fn edit_in_external_editor(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    text: &str,
+    enable_mouse_support: bool,
+) -> Result<String> {
+    execute!(stdout(), DisableMouseCapture)?;
+    terminal.clear()?;
+    terminal.set_cursor(0, 0)?;
+    terminal.show_cursor()?;
+    crossterm::terminal::disable_raw_mode()?;
+
+    let edited_text = open_in_external_editor(text);
 
-    let mut x_pos = view_box.x + 1 + cursor_pos as u16;
-    let mut y_pos = view_box.y + 1;
-    for (i, line) in wrapped_text_iter.enumerate() {
-        x_pos = view_box.x + 1 + cursor_pos as u16;
-        y_pos = view_box.y + 1 + i as u16;
-        if cursor_pos <= line.len() {
-            // if x_pos is > i subtract i
-            let x_pos = if x_pos > i as u16 {
-                x_pos - i as u16
+    crossterm::terminal::enable_raw_mode()?;
+    if enable_mouse_support {
+        execute!(stdout(), EnableMouseCapture)?;
+    }
+    terminal.hide_cursor()?;
+    // the editor drew over the screen so everything has to be redrawn
+    terminal.clear()?;
+    edited_text
+}
+
+fn open_in_external_editor(text: &str) -> Result<String> {
+    let editor = ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| {
+            if cfg!(windows) {
+                "notepad".to_string()
             } else {
-                x_pos
-            };
-            return (x_pos, y_pos);
-        }
-        cursor_pos -= line.len();
+                "vi".to_string()
+            }
+        });
+    // editors like "code --wait" come with their own arguments
+    let mut editor_args = editor.split_whitespace();
+    let program = editor_args.next().unwrap_or_default();
+    let file_path = env::temp_dir().join(format!("rust_kanban_card_{}.md", std::process::id()));
+    fs::write(&file_path, text)?;
+    let status = Command::new(program)
+        .args(editor_args)
+        .arg(&file_path)
+        .status();
+    let edited_text = fs::read_to_string(&file_path);
+    _ = fs::remove_file(&file_path);
+    let status = status.map_err(|e| eyre!("Could not start '{}': {}", editor, e))?;
+    if !status.success() {
+        bail!("'{}' exited with {}", editor, status);
     }
-    (x_pos, y_pos)
+    Ok(edited_text?)
 }
 
 /// function to lerp between rgb values of two colors
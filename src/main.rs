@@ -5,8 +5,9 @@ use log::LevelFilter;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use rust_kanban::{
     app::App,
+    cli::{run_cli_command, CliCommand},
     constants::APP_TITLE,
-    io::{handler::IoAsyncHandler, IoEvent},
+    io::{data_handler::use_workspace_for_this_run, handler::IoAsyncHandler, IoEvent},
     start_ui,
 };
 use std::{io::stdout, sync::Arc};
@@ -18,6 +19,13 @@ struct CliArgs {
     // optional argument to reset config
     #[arg(short, long)]
     reset: Option<bool>,
+    /// Open a workspace by name for this run only, creating it if needed, use Switch Workspace in
+    /// the command palette to change the workspace opened by default
+    #[arg(short, long)]
+    workspace: Option<String>,
+    // optional subcommand to edit boards and cards without starting the ui
+    #[command(subcommand)]
+    command: Option<CliCommand>,
 }
 
 #[tokio::main]
@@ -51,6 +59,22 @@ async fn main() -> Result<()> {
     // parse cli args
     let args = CliArgs::parse();
 
+    if let Some(workspace) = &args.workspace {
+        if let Err(e) = use_workspace_for_this_run(workspace) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    // subcommands run headless, without entering raw mode
+    if let Some(command) = args.command {
+        if let Err(e) = run_cli_command(command) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     let (sync_io_tx, mut sync_io_rx) = tokio::sync::mpsc::channel::<IoEvent>(100);
 
     // We need to share the App between thread
@@ -19,6 +19,7 @@ use crate::{
         LOG_WARN_STYLE, MOUSE_HIGHLIGHT_STYLE, PROGRESS_BAR_STYLE, SAMPLE_TEXT,
     },
 };
+pub mod text_input;
 pub mod ui_helper;
 pub mod ui_main;
 pub mod widgets;
@@ -0,0 +1,455 @@
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::Style,
+    text::{Span, Spans},
+    widgets::{Block, Paragraph},
+    Frame,
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{app::state::Focus, inputs::key::Key};
+
+const MAX_UNDO_STEPS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+}
+
+/// Editing state for the text field that currently has focus, the text itself stays with its owner
+/// (a form, the card being edited, ...) and is passed in on every call. Positions are counted in
+/// grapheme clusters, a cursor of `None` sits at the end of the text.
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    field: Option<(Focus, Option<usize>)>,
+    cursor: Option<usize>,
+    selection_anchor: Option<usize>,
+    undo_stack: Vec<(String, Option<usize>)>,
+    last_edit: Option<EditKind>,
+    scroll_offset: u16,
+}
+
+impl TextInput {
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Starts over when a different field (or list item of a field) is being edited, so the cursor
+    /// and undo history never carry over
+    pub fn edit_field(&mut self, focus: Focus, item: Option<usize>) {
+        if self.field != Some((focus, item)) {
+            self.reset();
+            self.field = Some((focus, item));
+        }
+    }
+
+    pub fn cursor(&self, text: &str) -> usize {
+        let len = text.graphemes(true).count();
+        self.cursor.map_or(len, |cursor| cursor.min(len))
+    }
+
+    pub fn set_cursor(&mut self, cursor: Option<usize>) {
+        self.cursor = cursor;
+        self.selection_anchor = None;
+        self.last_edit = None;
+    }
+
+    /// Start and end of the selected graphemes, if anything is selected
+    pub fn selection(&self, text: &str) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?.min(text.graphemes(true).count());
+        let cursor = self.cursor(text);
+        match anchor.cmp(&cursor) {
+            std::cmp::Ordering::Less => Some((anchor, cursor)),
+            std::cmp::Ordering::Greater => Some((cursor, anchor)),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Display width of the text before the cursor, useful for placing the terminal cursor in
+    /// single line fields
+    pub fn cursor_width(&self, text: &str) -> usize {
+        let cursor = self.cursor(text);
+        text.graphemes(true)
+            .take(cursor)
+            .map(|grapheme| grapheme.width())
+            .sum()
+    }
+
+    /// Applies a key to the text, returns false if the key is not an editing key
+    pub fn handle_key(&mut self, text: &mut String, key: Key, multiline: bool) -> bool {
+        match key {
+            Key::Char(c) => {
+                self.insert_str(text, &c.to_string());
+            }
+            Key::Enter if multiline => {
+                self.insert_str(text, "\n");
+            }
+            Key::Backspace => {
+                if !self.delete_selection(text) {
+                    let cursor = self.cursor(text);
+                    if cursor > 0 {
+                        self.delete_range(text, cursor - 1, cursor);
+                    }
+                }
+            }
+            Key::Delete => {
+                if !self.delete_selection(text) {
+                    let cursor = self.cursor(text);
+                    if cursor < text.graphemes(true).count() {
+                        self.delete_range(text, cursor, cursor + 1);
+                    }
+                }
+            }
+            Key::Left => match self.selection(text) {
+                Some((start, _)) => self.set_cursor(Some(start)),
+                None => self.set_cursor(Some(self.cursor(text).saturating_sub(1))),
+            },
+            Key::Right => match self.selection(text) {
+                Some((_, end)) => self.set_cursor(Some(end)),
+                None => self.set_cursor(Some(self.cursor(text) + 1)),
+            },
+            Key::ShiftLeft => self.select_to(text, self.cursor(text).saturating_sub(1)),
+            Key::ShiftRight => self.select_to(text, self.cursor(text) + 1),
+            Key::CtrlLeft => self.set_cursor(Some(previous_word_start(text, self.cursor(text)))),
+            Key::CtrlRight => self.set_cursor(Some(next_word_end(text, self.cursor(text)))),
+            Key::Home => self.set_cursor(Some(line_start(text, self.cursor(text)))),
+            Key::End => self.set_cursor(Some(line_end(text, self.cursor(text)))),
+            Key::Up | Key::Down if multiline => {
+                let cursor = self.cursor(text);
+                let start = line_start(text, cursor);
+                let column = cursor - start;
+                let target_line_start = if key == Key::Up {
+                    if start == 0 {
+                        return true;
+                    }
+                    line_start(text, start - 1)
+                } else {
+                    let end = line_end(text, cursor);
+                    if end == text.graphemes(true).count() {
+                        return true;
+                    }
+                    end + 1
+                };
+                let target_line_end = line_end(text, target_line_start);
+                self.set_cursor(Some((target_line_start + column).min(target_line_end)));
+            }
+            Key::Ctrl('a') => {
+                self.selection_anchor = Some(0);
+                self.cursor = None;
+            }
+            Key::Ctrl('z') => self.undo(text),
+            _ => return false,
+        }
+        true
+    }
+
+    pub fn insert_str(&mut self, text: &mut String, value: &str) {
+        let whitespace = value.chars().all(char::is_whitespace);
+        if whitespace || self.last_edit != Some(EditKind::Insert) {
+            self.push_undo(text);
+        }
+        self.last_edit = Some(EditKind::Insert);
+        if let Some((start, end)) = self.selection(text) {
+            text.replace_range(byte_index(text, start)..byte_index(text, end), "");
+            self.cursor = Some(start);
+            self.selection_anchor = None;
+        }
+        let cursor = self.cursor(text);
+        text.insert_str(byte_index(text, cursor), value);
+        // inserting can merge graphemes (combining marks), so recount instead of adding
+        let inserted_end = text[..byte_index(text, cursor) + value.len()]
+            .graphemes(true)
+            .count();
+        self.cursor = Some(inserted_end);
+    }
+
+    pub fn undo(&mut self, text: &mut String) {
+        if let Some((previous_text, previous_cursor)) = self.undo_stack.pop() {
+            *text = previous_text;
+            self.set_cursor(previous_cursor);
+        }
+    }
+
+    /// Keeps the line with the cursor inside a view of the given height and returns the number of
+    /// lines to scroll by
+    pub fn scroll(&mut self, cursor_line: u16, height: u16) -> u16 {
+        if height == 0 {
+            return self.scroll_offset;
+        }
+        if cursor_line < self.scroll_offset {
+            self.scroll_offset = cursor_line;
+        } else if cursor_line >= self.scroll_offset + height {
+            self.scroll_offset = cursor_line + 1 - height;
+        }
+        self.scroll_offset
+    }
+
+    /// Renders the text inside the block, wrapped to the width of the area. While editing, the
+    /// terminal cursor is placed, the selection highlighted and the view scrolled to the cursor
+    pub fn render<B>(
+        &mut self,
+        rect: &mut Frame<B>,
+        text: &str,
+        block: Block,
+        area: Rect,
+        selection_style: Style,
+        editing: bool,
+    ) where
+        B: Backend,
+    {
+        let inner_area = block.inner(area);
+        let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+        let rows = wrap_rows(&graphemes, inner_area.width as usize);
+        let selection = if editing { self.selection(text) } else { None };
+        let lines = rows
+            .iter()
+            .map(|(start, end)| {
+                let mut spans = vec![];
+                let mut segment_start = *start;
+                for index in *start..=*end {
+                    let boundary = index == *end
+                        || selection.is_some_and(|(selection_start, selection_end)| {
+                            index == selection_start || index == selection_end
+                        });
+                    if boundary && index > segment_start {
+                        let selected = selection.is_some_and(|(selection_start, selection_end)| {
+                            segment_start >= selection_start && segment_start < selection_end
+                        });
+                        spans.push(styled_span(
+                            graphemes[segment_start..index].concat(),
+                            selected,
+                            Style::default(),
+                            selection_style,
+                        ));
+                        segment_start = index;
+                    }
+                }
+                Spans::from(spans)
+            })
+            .collect::<Vec<Spans>>();
+        if !editing {
+            rect.render_widget(Paragraph::new(lines).block(block), area);
+            return;
+        }
+        let cursor = self.cursor(text);
+        let cursor_row = rows
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, (start, _))| *start <= cursor)
+            .map_or(0, |(row, _)| row);
+        let cursor_column: usize = rows.get(cursor_row).map_or(0, |(start, _)| {
+            graphemes[*start..cursor]
+                .iter()
+                .map(|grapheme| grapheme.width())
+                .sum()
+        });
+        let scroll = self.scroll(cursor_row as u16, inner_area.height);
+        rect.render_widget(Paragraph::new(lines).block(block).scroll((scroll, 0)), area);
+        rect.set_cursor(
+            inner_area.x + (cursor_column as u16).min(inner_area.width.saturating_sub(1)),
+            inner_area.y + cursor_row as u16 - scroll,
+        );
+    }
+
+    fn select_to(&mut self, text: &str, cursor: usize) {
+        let current = self.cursor(text);
+        if self.selection_anchor.is_none() {
+            self.selection_anchor = Some(current);
+        }
+        self.cursor = Some(cursor.min(text.graphemes(true).count()));
+        self.last_edit = None;
+    }
+
+    fn delete_selection(&mut self, text: &mut String) -> bool {
+        match self.selection(text) {
+            Some((start, end)) => {
+                self.delete_range(text, start, end);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn delete_range(&mut self, text: &mut String, start: usize, end: usize) {
+        if self.last_edit != Some(EditKind::Delete) || end - start > 1 {
+            self.push_undo(text);
+        }
+        self.last_edit = Some(EditKind::Delete);
+        text.replace_range(byte_index(text, start)..byte_index(text, end), "");
+        self.cursor = Some(start);
+        self.selection_anchor = None;
+    }
+
+    fn push_undo(&mut self, text: &str) {
+        if self.undo_stack.len() == MAX_UNDO_STEPS {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push((text.to_string(), self.cursor));
+    }
+}
+
+fn styled_span<'a, T>(text: T, selected: bool, style: Style, selection_style: Style) -> Span<'a>
+where
+    T: Into<std::borrow::Cow<'a, str>>,
+{
+    Span::styled(text, if selected { selection_style } else { style })
+}
+
+/// Splits the graphemes into rows that fit the width, breaking after whitespace where possible.
+/// Rows are grapheme ranges, line breaks are not part of any row
+fn wrap_rows(graphemes: &[&str], width: usize) -> Vec<(usize, usize)> {
+    let width = width.max(1);
+    let mut rows = vec![];
+    let mut row_start = 0;
+    let mut row_width = 0;
+    let mut last_break = None;
+    for (index, grapheme) in graphemes.iter().enumerate() {
+        if *grapheme == "\n" || *grapheme == "\r\n" {
+            rows.push((row_start, index));
+            row_start = index + 1;
+            row_width = 0;
+            last_break = None;
+            continue;
+        }
+        let grapheme_width = grapheme.width();
+        if row_width + grapheme_width > width && index > row_start {
+            let break_at = match last_break {
+                Some(break_at) if break_at > row_start => break_at,
+                _ => index,
+            };
+            rows.push((row_start, break_at));
+            row_width = graphemes[break_at..index]
+                .iter()
+                .map(|grapheme| grapheme.width())
+                .sum();
+            row_start = break_at;
+            last_break = None;
+        }
+        row_width += grapheme_width;
+        if grapheme.chars().all(char::is_whitespace) {
+            last_break = Some(index + 1);
+        }
+    }
+    rows.push((row_start, graphemes.len()));
+    rows
+}
+
+fn byte_index(text: &str, grapheme_index: usize) -> usize {
+    text.grapheme_indices(true)
+        .nth(grapheme_index)
+        .map_or(text.len(), |(byte_index, _)| byte_index)
+}
+
+fn is_word(grapheme: &str) -> bool {
+    grapheme.chars().next().is_some_and(char::is_alphanumeric)
+}
+
+fn previous_word_start(text: &str, cursor: usize) -> usize {
+    let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+    let mut position = cursor.min(graphemes.len());
+    while position > 0 && !is_word(graphemes[position - 1]) {
+        position -= 1;
+    }
+    while position > 0 && is_word(graphemes[position - 1]) {
+        position -= 1;
+    }
+    position
+}
+
+fn next_word_end(text: &str, cursor: usize) -> usize {
+    let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+    let mut position = cursor.min(graphemes.len());
+    while position < graphemes.len() && !is_word(graphemes[position]) {
+        position += 1;
+    }
+    while position < graphemes.len() && is_word(graphemes[position]) {
+        position += 1;
+    }
+    position
+}
+
+fn line_start(text: &str, cursor: usize) -> usize {
+    text.graphemes(true)
+        .take(cursor)
+        .enumerate()
+        .filter(|(_, grapheme)| *grapheme == "\n" || *grapheme == "\r\n")
+        .last()
+        .map_or(0, |(index, _)| index + 1)
+}
+
+fn line_end(text: &str, cursor: usize) -> usize {
+    let graphemes = text.graphemes(true).collect::<Vec<&str>>();
+    graphemes
+        .iter()
+        .enumerate()
+        .skip(cursor)
+        .find(|(_, grapheme)| **grapheme == "\n" || **grapheme == "\r\n")
+        .map_or(graphemes.len(), |(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(input: &mut TextInput, text: &mut String, value: &str) {
+        for c in value.chars() {
+            input.handle_key(text, Key::Char(c), true);
+        }
+    }
+
+    #[test]
+    fn should_edit_grapheme_clusters_as_one_character() {
+        let mut input = TextInput::default();
+        let mut text = String::from("cafe\u{301} 👩‍💻");
+        assert_eq!(input.cursor(&text), 6);
+        input.handle_key(&mut text, Key::Backspace, false);
+        assert_eq!(text, "cafe\u{301} ");
+        input.handle_key(&mut text, Key::Left, false);
+        input.handle_key(&mut text, Key::Backspace, false);
+        assert_eq!(text, "caf ");
+        assert_eq!(input.cursor_width(&text), 3);
+    }
+
+    #[test]
+    fn should_move_by_words_and_lines() {
+        let mut input = TextInput::default();
+        let mut text = String::from("first line\nsecond one");
+        input.handle_key(&mut text, Key::CtrlLeft, true);
+        assert_eq!(input.cursor(&text), 18);
+        input.handle_key(&mut text, Key::Home, true);
+        assert_eq!(input.cursor(&text), 11);
+        input.handle_key(&mut text, Key::Up, true);
+        assert_eq!(input.cursor(&text), 0);
+        input.handle_key(&mut text, Key::CtrlRight, true);
+        assert_eq!(input.cursor(&text), 5);
+        input.handle_key(&mut text, Key::End, true);
+        assert_eq!(input.cursor(&text), 10);
+        assert!(!input.handle_key(&mut text, Key::Enter, false));
+    }
+
+    #[test]
+    fn should_replace_selection_and_undo() {
+        let mut input = TextInput::default();
+        let mut text = String::from("hello world");
+        input.handle_key(&mut text, Key::ShiftLeft, false);
+        input.handle_key(&mut text, Key::ShiftLeft, false);
+        assert_eq!(input.selection(&text), Some((9, 11)));
+        type_text(&mut input, &mut text, "k!");
+        assert_eq!(text, "hello work!");
+        input.handle_key(&mut text, Key::Ctrl('z'), false);
+        assert_eq!(text, "hello world");
+        input.handle_key(&mut text, Key::Ctrl('a'), false);
+        input.handle_key(&mut text, Key::Delete, false);
+        assert!(text.is_empty());
+    }
+
+    #[test]
+    fn should_wrap_rows_at_whitespace() {
+        let graphemes = "one two three\nfour".graphemes(true).collect::<Vec<&str>>();
+        assert_eq!(wrap_rows(&graphemes, 8), vec![(0, 8), (8, 13), (14, 18)]);
+    }
+}
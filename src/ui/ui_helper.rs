@@ -1,4 +1,4 @@
-use chrono::{Local, NaiveDateTime};
+use chrono::{Duration, Local};
 use log::debug;
 use ratatui::{
     backend::Backend,
@@ -6,31 +6,36 @@ use ratatui::{
     style::Style,
     text::{Span, Spans},
     widgets::{
-        Block, BorderType, Borders, Cell, Clear, Gauge, List, ListItem, Paragraph, Row, Table, Wrap,
+        Block, BorderType, Borders, Cell, Clear, Gauge, List, ListItem, ListState, Paragraph, Row,
+        Table, Wrap,
     },
     Frame,
 };
 use std::cmp::Ordering;
 use tui_logger::TuiLoggerWidget;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
     app::{
-        kanban::{CardPriority, CardStatus},
+        kanban::{
+            find_card, Card, CardDate, CardLink, CardLinkKind, CardPriority, CardRecurrence,
+            CardSortKey, CardStatus, SwimlaneGrouping, WorkflowStatus,
+        },
         state::{AppStatus, Focus, UiMode},
-        App, AppConfig, MainMenu, PopupMode,
+        App, AppConfig, ArchivedItem, DestructiveAction, MainMenu, PopupMode,
     },
-    calculate_cursor_position,
     constants::{
-        APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, DEFAULT_DATE_FORMAT,
-        FIELD_NOT_SET, LIST_SELECTED_SYMBOL, MAX_TOASTS_TO_DISPLAY, MIN_TERM_HEIGHT,
-        MIN_TERM_WIDTH, SCREEN_TO_TOAST_WIDTH_RATIO, SPINNER_FRAMES, VERTICAL_SCROLL_BAR_SYMBOL,
+        APP_TITLE, DEFAULT_BOARD_TITLE_LENGTH, DEFAULT_CARD_TITLE_LENGTH, FIELD_NOT_SET,
+        LIST_SELECTED_SYMBOL, MAX_TOASTS_TO_DISPLAY, MIN_TERM_HEIGHT, MIN_TERM_WIDTH,
+        SCREEN_TO_TOAST_WIDTH_RATIO, SPINNER_FRAMES, VERTICAL_SCROLL_BAR_SYMBOL,
     },
     io::data_handler::{get_available_local_savefiles, get_config},
 };
 
 use super::{
+    text_input::TextInput,
     widgets::{ToastType, ToastWidget},
-    TextColorOptions, TextModifierOptions,
+    TextColorOptions, TextModifierOptions, Theme,
 };
 
 /// Draws main screen with kanban boards
@@ -613,7 +618,7 @@ where
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded),
         )
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(Wrap { trim: true });
     let edit_item = Paragraph::new(app.state.current_user_input.clone())
         .block(
             Block::default()
@@ -623,16 +628,15 @@ where
                 .border_style(edit_box_style)
                 .border_type(BorderType::Rounded),
         )
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(Wrap { trim: true });
 
     let log = draw_logs(app, true, false, chunks[2]);
 
     if app.state.app_status == AppStatus::UserInput {
-        let current_cursor_position = if app.state.current_cursor_position.is_some() {
-            app.state.current_cursor_position.unwrap() as u16
-        } else {
-            app.state.current_user_input.len() as u16
-        };
+        let current_cursor_position =
+            app.state
+                .text_input
+                .cursor_width(&app.state.current_user_input) as u16;
         let x_offset = current_cursor_position % (chunks[1].width - 2);
         let y_offset = current_cursor_position / (chunks[1].width - 2);
         let x_cursor_position = chunks[1].x + x_offset + 1;
@@ -760,7 +764,7 @@ where
                 .border_type(BorderType::Rounded),
         )
         .alignment(Alignment::Center)
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(Wrap { trim: true });
 
     rect.render_stateful_widget(
         default_view_list,
@@ -978,7 +982,7 @@ where
         )
         .style(default_style)
         .alignment(Alignment::Center)
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(Wrap { trim: true });
 
     let reset_button = Paragraph::new("Reset Keybindings to Default")
         .block(
@@ -1080,7 +1084,7 @@ where
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded),
             )
-            .wrap(ratatui::widgets::Wrap { trim: true });
+            .wrap(Wrap { trim: true });
         let current_edited_keybinding = app.state.edited_keybinding.clone();
         let mut current_edited_keybinding_string = String::new();
         if let Some(current_edited_keybinding) = current_edited_keybinding {
@@ -1097,16 +1101,12 @@ where
                     .border_style(edit_box_style)
                     .border_type(BorderType::Rounded),
             )
-            .wrap(ratatui::widgets::Wrap { trim: true });
+            .wrap(Wrap { trim: true });
 
         let log = draw_logs(app, true, false, chunks[2]);
 
         if app.state.app_status == AppStatus::KeyBindMode {
-            let current_cursor_position = if app.state.current_cursor_position.is_some() {
-                app.state.current_cursor_position.unwrap() as u16
-            } else {
-                current_edited_keybinding_string.len() as u16
-            };
+            let current_cursor_position = current_edited_keybinding_string.len() as u16;
             let x_offset = current_cursor_position % (chunks[1].width - 2);
             let y_offset = current_cursor_position / (chunks[1].width - 2);
             let x_cursor_position = chunks[1].x + x_offset + 1;
@@ -1548,16 +1548,14 @@ pub fn render_body<B>(rect: &mut Frame<B>, area: Rect, app: &mut App, preview_mo
 where
     B: Backend,
 {
-    let fallback_boards = vec![];
     let focus = app.state.focus;
     let boards = if preview_mode {
-        if app.state.preview_boards_and_cards.is_some() {
-            app.state.preview_boards_and_cards.as_ref().unwrap()
-        } else {
-            &fallback_boards
-        }
+        app.state
+            .preview_boards_and_cards
+            .clone()
+            .unwrap_or_default()
     } else {
-        &app.boards
+        app.filtered_boards()
     };
     let progress_bar_style = if app.state.popup_mode.is_some() {
         app.theme.inactive_text_style
@@ -1649,7 +1647,7 @@ where
     }
     let board_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(constraints.as_ref())
+        .constraints(constraints.as_slice())
         .split(chunks[0]);
     // visible_boards_and_cards: Vec<LinkedHashMap<String, Vec<String>>>
     let visible_boards_and_cards = if preview_mode {
@@ -1657,6 +1655,15 @@ where
     } else {
         app.visible_boards_and_cards.clone()
     };
+    if !preview_mode {
+        app.state.hovered_board_and_card = None;
+    }
+    // preview boards keep their saved card order so they are never split into lanes
+    let swimlanes = if preview_mode {
+        SwimlaneGrouping::None
+    } else {
+        app.config.swimlanes.clone()
+    };
     for (board_index, board_and_card_tuple) in visible_boards_and_cards.iter().enumerate() {
         // render board with title in board chunks alongside with cards in card chunks of the board
         // break if board_index is more than NO_OF_BOARDS_PER_PAGE
@@ -1691,7 +1698,24 @@ where
         } else {
             board_title
         };
-        let board_title = format!("{} ({})", board_title, board.cards.len());
+        let unarchived_cards = board.cards.iter().filter(|c| !c.archived);
+        let board_title = match &app.state.active_filter {
+            Some(filter) if !preview_mode => format!(
+                "{} ({}/{})",
+                board_title,
+                unarchived_cards
+                    .clone()
+                    .filter(|c| filter.matches(c))
+                    .count(),
+                unarchived_cards.count()
+            ),
+            _ => format!("{} ({})", board_title, unarchived_cards.count()),
+        };
+        let board_title = if board.wip_limit > 0 {
+            format!("{} [WIP {}]", board_title, board.wip_limit)
+        } else {
+            board_title
+        };
         let board_title = if board_id == current_board {
             format!("{} {}", ">>", board_title)
         } else {
@@ -1709,6 +1733,23 @@ where
                 card_constraints.push(Constraint::Percentage(100 / board_cards.len() as u16));
             }
         }
+        // with swimlanes a one line header goes above the first visible card of every lane
+        let mut lane_headers: Vec<(usize, String)> = vec![];
+        let mut card_chunk_indexes = vec![];
+        let mut previous_lane = None;
+        for (card_index, card_id) in board_cards.iter().take(card_constraints.len()).enumerate() {
+            let lane = board
+                .get_card(*card_id)
+                .and_then(|card| swimlanes.lane(card));
+            if lane.is_some() && lane != previous_lane {
+                lane_headers.push((card_index + lane_headers.len(), lane.clone().unwrap()));
+                previous_lane = lane;
+            }
+            card_chunk_indexes.push(card_index + lane_headers.len());
+        }
+        for (chunk_index, _) in lane_headers.iter() {
+            card_constraints.insert(*chunk_index, Constraint::Length(1));
+        }
 
         // check if board_index is >= board_chunks.len() if yes continue
         if board_index >= board_chunks.len() {
@@ -1729,12 +1770,19 @@ where
             app.state.mouse_focus = Some(Focus::Body);
             app.state.focus = Focus::Body;
             app.state.current_board_id = Some(*board_id);
+            if !preview_mode {
+                app.state.hovered_board_and_card = Some((*board_id, None));
+            }
             app.theme.mouse_focus_style
         } else if *board_id == *current_board
             && matches!(focus, Focus::Body)
             && app.state.current_card_id.is_none()
         {
             app.theme.keyboard_focus_style
+        } else if board.is_over_wip_limit() {
+            app.theme.card_due_overdue_style
+        } else if board.is_at_wip_limit() {
+            app.theme.card_due_warning_style
         } else {
             app.theme.general_style
         };
@@ -1763,13 +1811,13 @@ where
             Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
-                .constraints(card_constraints.as_ref())
+                .constraints(card_constraints.as_slice())
                 .split(card_area_chunks[0])
         } else {
             Layout::default()
                 .direction(Direction::Vertical)
                 .margin(1)
-                .constraints(card_constraints.as_ref())
+                .constraints(card_constraints.as_slice())
                 .split(card_area_chunks[1])
         };
 
@@ -1813,15 +1861,22 @@ where
                 }
             }
         };
+        let lane_header_style = if app.state.popup_mode.is_some() {
+            app.theme.inactive_text_style
+        } else {
+            app.theme.help_key_style
+        };
+        for (chunk_index, lane) in lane_headers.iter() {
+            let lane_header = Paragraph::new(Spans::from(Span::styled(
+                format!("── {} ", lane),
+                lane_header_style,
+            )));
+            rect.render_widget(lane_header, card_chunks[*chunk_index]);
+        }
         for (card_index, card_id) in board_cards.iter().enumerate() {
             if card_index >= app.config.no_of_cards_to_show.into() {
                 break;
             }
-            let inner_card_chunks = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
-                .margin(1)
-                .split(card_chunks[card_index]);
             // unwrap card if panic skip it and log it
             let card = board.get_card(*card_id);
             // check if card is None, if so skip it and log it
@@ -1829,6 +1884,20 @@ where
                 continue;
             }
             let card = card.unwrap();
+            // cards with a checklist get a progress gauge below the extra info
+            let checklist_gauge_height = if card.checklist.is_empty() { 0 } else { 1 };
+            let inner_card_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(
+                    [
+                        Constraint::Min(0),
+                        Constraint::Length(3),
+                        Constraint::Length(checklist_gauge_height),
+                    ]
+                    .as_ref(),
+                )
+                .margin(1)
+                .split(card_chunks[card_chunk_indexes[card_index]]);
 
             let card_title = if card.name.len() > DEFAULT_CARD_TITLE_LENGTH.into() {
                 format!("{}...", &card.name[0..DEFAULT_CARD_TITLE_LENGTH as usize])
@@ -1848,81 +1917,61 @@ where
             };
 
             let mut card_extra_info = vec![Spans::from("")];
-            if card.date_due == FIELD_NOT_SET {
-                if app.state.popup_mode.is_some() {
-                    card_extra_info.push(Spans::from(Span::styled(
-                        "Due: Not Set",
-                        app.theme.inactive_text_style,
-                    )))
-                } else {
-                    card_extra_info.push(Spans::from(Span::styled(
-                        "Due: Not Set",
-                        app.theme.card_due_default_style,
-                    )))
-                }
-            } else {
-                let card_due_date = card.date_due.clone();
-                let parsed_due_date =
-                    NaiveDateTime::parse_from_str(&card_due_date, "%Y/%m/%d-%H:%M:%S");
-                // card due date is in the format dd/mm/yyyy check if the due date is within WARNING_DUE_DATE_DAYS if so highlight it
-                let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
-                    let today = Local::now().naive_local();
-                    let days_left = parsed_due_date.signed_duration_since(today).num_days();
-                    let parsed_due_date = parsed_due_date.format(DEFAULT_DATE_FORMAT).to_string();
-                    if app.state.popup_mode.is_some() {
-                        Spans::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.theme.inactive_text_style,
-                        ))
-                    } else if days_left >= 0 {
-                        match days_left.cmp(&(app.config.warning_delta as i64)) {
-                            Ordering::Less | Ordering::Equal => Spans::from(Span::styled(
-                                format!("Due: {}", parsed_due_date),
-                                app.theme.card_due_warning_style,
-                            )),
-                            Ordering::Greater => Spans::from(Span::styled(
-                                format!("Due: {}", parsed_due_date),
-                                app.theme.card_due_default_style,
-                            )),
-                        }
-                    } else {
-                        Spans::from(Span::styled(
-                            format!("Due: {}", parsed_due_date),
-                            app.theme.card_due_overdue_style,
-                        ))
-                    }
-                } else if app.state.popup_mode.is_some() {
+            if let Some(card_due_date) = card.date_due {
+                // check if the due date is within WARNING_DUE_DATE_DAYS if so highlight it
+                let today = Local::now().naive_local();
+                let days_left = card_due_date.signed_duration_since(today).num_days();
+                let card_due_date_styled = if app.state.popup_mode.is_some() {
                     Spans::from(Span::styled(
                         format!("Due: {}", card_due_date),
                         app.theme.inactive_text_style,
                     ))
+                } else if days_left >= 0 {
+                    match days_left.cmp(&(app.config.warning_delta as i64)) {
+                        Ordering::Less | Ordering::Equal => Spans::from(Span::styled(
+                            format!("Due: {}", card_due_date),
+                            app.theme.card_due_warning_style,
+                        )),
+                        Ordering::Greater => Spans::from(Span::styled(
+                            format!("Due: {}", card_due_date),
+                            app.theme.card_due_default_style,
+                        )),
+                    }
                 } else {
                     Spans::from(Span::styled(
                         format!("Due: {}", card_due_date),
-                        app.theme.card_due_default_style,
+                        app.theme.card_due_overdue_style,
                     ))
                 };
                 card_extra_info.extend(vec![card_due_date_styled]);
+            } else if app.state.popup_mode.is_some() {
+                card_extra_info.push(Spans::from(Span::styled(
+                    "Due: Not Set",
+                    app.theme.inactive_text_style,
+                )))
+            } else {
+                card_extra_info.push(Spans::from(Span::styled(
+                    "Due: Not Set",
+                    app.theme.card_due_default_style,
+                )))
             }
 
-            let card_status = format!("Status: {}", card.card_status.clone());
-            let card_status = if app.state.popup_mode.is_some() {
+            let card_status = format!("Status: {}", card.status_name());
+            let mut card_status = if app.state.popup_mode.is_some() {
                 Spans::from(Span::styled(card_status, app.theme.inactive_text_style))
             } else {
-                match card.card_status {
-                    CardStatus::Active => Spans::from(Span::styled(
-                        card_status,
-                        app.theme.card_status_active_style,
-                    )),
-                    CardStatus::Complete => Spans::from(Span::styled(
-                        card_status,
-                        app.theme.card_status_completed_style,
-                    )),
-                    CardStatus::Stale => {
-                        Spans::from(Span::styled(card_status, app.theme.card_status_stale_style))
-                    }
-                }
+                Spans::from(Span::styled(
+                    card_status,
+                    card_status_style(app, &card.card_status),
+                ))
             };
+            if card.card_status != CardStatus::Complete
+                && !card.open_blockers(&app.boards).is_empty()
+            {
+                card_status
+                    .0
+                    .push(Span::styled(" (Blocked)", error_text_style));
+            }
             card_extra_info.extend(vec![card_status]);
 
             // if card id is same as current_card, highlight it
@@ -1930,12 +1979,17 @@ where
                 app.theme.inactive_text_style
             } else if check_if_mouse_is_in_area(
                 app.state.current_mouse_coordinates,
-                card_chunks[card_index],
+                card_chunks[card_chunk_indexes[card_index]],
             ) {
                 app.state.mouse_focus = Some(Focus::Body);
                 app.state.focus = Focus::Body;
                 app.state.current_card_id = Some(*card_id);
+                if !preview_mode {
+                    app.state.hovered_board_and_card = Some((*board_id, Some(*card_id)));
+                }
                 app.theme.mouse_focus_style
+            } else if app.state.dragged_card.map(|(_, id)| id) == Some(*card_id) {
+                app.theme.inactive_text_style
             } else if app.state.current_card_id.unwrap_or(0) == *card_id
                 && matches!(focus, Focus::Body)
                 && *board_id == *current_board
@@ -1949,7 +2003,7 @@ where
                 .borders(Borders::ALL)
                 .border_style(card_style)
                 .border_type(BorderType::Rounded);
-            rect.render_widget(card_block, card_chunks[card_index]);
+            rect.render_widget(card_block, card_chunks[card_chunk_indexes[card_index]]);
             let card_paragraph = Paragraph::new(card_description)
                 .alignment(Alignment::Left)
                 .block(Block::default())
@@ -1960,9 +2014,21 @@ where
                 .block(Block::default())
                 .wrap(ratatui::widgets::Wrap { trim: false });
             rect.render_widget(card_extra_info, inner_card_chunks[1]);
+            if let Some(completion_ratio) = card.completion_ratio() {
+                let (done, total) = card.checklist_progress();
+                let checklist_gauge = Gauge::default()
+                    .gauge_style(progress_bar_style)
+                    .ratio(completion_ratio)
+                    .label(format!("{}/{}", done, total));
+                rect.render_widget(checklist_gauge, inner_card_chunks[2]);
+            }
         }
     }
 
+    if !preview_mode {
+        render_dragged_card(rect, area, app);
+    }
+
     if !app.config.disable_scrollbars {
         // draw line_gauge in chunks[1]
         // get the index of the current board in boards and set percentage
@@ -1992,6 +2058,48 @@ where
     }
 }
 
+/// Draws a ghost of the card being dragged under the mouse pointer
+fn render_dragged_card<B>(rect: &mut Frame<B>, area: Rect, app: &App)
+where
+    B: Backend,
+{
+    let card = if let Some((board_id, card_id)) = app.state.dragged_card {
+        app.boards
+            .iter()
+            .find(|b| b.id == board_id)
+            .and_then(|b| b.get_card(card_id))
+    } else {
+        None
+    };
+    let card = if let Some(card) = card {
+        card
+    } else {
+        return;
+    };
+    let card_title = if card.name.len() > DEFAULT_CARD_TITLE_LENGTH.into() {
+        format!("{}...", &card.name[0..DEFAULT_CARD_TITLE_LENGTH as usize])
+    } else {
+        card.name.clone()
+    };
+    let width = (card_title.len() as u16 + 4).min(area.width);
+    let height = 3.min(area.height);
+    let (mouse_x, mouse_y) = app.state.current_mouse_coordinates;
+    let ghost_area = Rect::new(
+        mouse_x.clamp(area.x, area.right().saturating_sub(width)),
+        mouse_y.clamp(area.y, area.bottom().saturating_sub(height)),
+        width,
+        height,
+    );
+    let ghost = Paragraph::new(card_title).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(app.theme.mouse_focus_style)
+            .border_type(BorderType::Rounded),
+    );
+    rect.render_widget(Clear, ghost_area);
+    rect.render_widget(ghost, ghost_area);
+}
+
 /// helper function to create a centered rect using up certain percentage of the available rect `r`
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -2112,16 +2220,27 @@ pub fn draw_title<'a>(app: &mut App, render_area: Rect) -> Paragraph<'a> {
     } else {
         app.theme.general_style
     };
+    let mut title = Spans::from(APP_TITLE);
+    if !app.config.workspace.is_empty() {
+        title.0.push(Span::styled(
+            format!("  [Workspace: {}]", app.config.workspace),
+            app.theme.help_key_style,
+        ));
+    }
+    if let Some(filter) = &app.state.active_filter {
+        title.0.push(Span::styled(
+            format!("  [Filter: {}]", filter),
+            app.theme.help_key_style,
+        ));
+    }
     // check if focus is on title
-    Paragraph::new(APP_TITLE)
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .style(title_style)
-                .borders(Borders::ALL)
-                .border_style(border_style)
-                .border_type(BorderType::Rounded),
-        )
+    Paragraph::new(title).alignment(Alignment::Center).block(
+        Block::default()
+            .style(title_style)
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .border_type(BorderType::Rounded),
+    )
 }
 
 /// Helper function to check terminal size
@@ -2174,7 +2293,7 @@ where
         app.theme.inactive_text_style
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[1]) {
         if app.state.mouse_focus != Some(Focus::NewBoardName) {
-            app.state.current_cursor_position = None;
+            app.state.text_input.reset();
             app.state.app_status = AppStatus::Initialized;
         }
         app.state.mouse_focus = Some(Focus::NewBoardName);
@@ -2189,7 +2308,7 @@ where
         app.theme.inactive_text_style
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
         if app.state.mouse_focus != Some(Focus::NewBoardDescription) {
-            app.state.current_cursor_position = None;
+            app.state.text_input.reset();
             app.state.app_status = AppStatus::Initialized;
         }
         app.state.mouse_focus = Some(Focus::NewBoardDescription);
@@ -2210,7 +2329,7 @@ where
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[4]) {
         app.state.mouse_focus = Some(Focus::SubmitButton);
         app.state.focus = Focus::SubmitButton;
-        app.state.current_cursor_position = None;
+        app.state.text_input.reset();
         app.state.app_status = AppStatus::Initialized;
         app.theme.mouse_focus_style
     } else if matches!(app.state.focus, Focus::SubmitButton) {
@@ -2229,40 +2348,36 @@ where
         );
     rect.render_widget(title_paragraph, chunks[0]);
 
-    let wrapped_title_text =
-        textwrap::wrap(&app.state.new_board_form[0], (chunks[1].width - 2) as usize);
-    let board_name_field = wrapped_title_text
-        .iter()
-        .map(|x| Spans::from(Span::raw(&**x)))
-        .collect::<Vec<Spans>>();
-    let wrapped_description_text =
-        textwrap::wrap(&app.state.new_board_form[1], (chunks[2].width - 2) as usize);
-    let board_description_field = wrapped_description_text
-        .iter()
-        .map(|x| Spans::from(Span::raw(&**x)))
-        .collect::<Vec<Spans>>();
-
-    let board_name = Paragraph::new(board_name_field)
-        .alignment(Alignment::Left)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(name_style)
-                .border_type(BorderType::Rounded)
-                .title("Board Name (required)"),
-        );
-    rect.render_widget(board_name, chunks[1]);
+    let board_name_block = Block::default()
+        .borders(Borders::ALL)
+        .style(name_style)
+        .border_type(BorderType::Rounded)
+        .title("Board Name (required)");
+    let board_name = app.state.new_board_form[0].clone();
+    app.state.text_input.render(
+        rect,
+        &board_name,
+        board_name_block,
+        chunks[1],
+        app.theme.list_select_style,
+        app.state.focus == Focus::NewBoardName && app.state.app_status == AppStatus::UserInput,
+    );
 
-    let board_description = Paragraph::new(board_description_field)
-        .alignment(Alignment::Left)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(description_style)
-                .border_type(BorderType::Rounded)
-                .title("Board Description"),
-        );
-    rect.render_widget(board_description, chunks[2]);
+    let board_description_block = Block::default()
+        .borders(Borders::ALL)
+        .style(description_style)
+        .border_type(BorderType::Rounded)
+        .title("Board Description");
+    let board_description = app.state.new_board_form[1].clone();
+    app.state.text_input.render(
+        rect,
+        &board_description,
+        board_description_block,
+        chunks[2],
+        app.theme.list_select_style,
+        app.state.focus == Focus::NewBoardDescription
+            && app.state.app_status == AppStatus::UserInput,
+    );
 
     let input_mode_key = app
         .state
@@ -2315,7 +2430,7 @@ where
                 .border_type(BorderType::Rounded)
                 .border_style(default_style),
         )
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(Wrap { trim: true });
     rect.render_widget(help_paragraph, chunks[3]);
 
     let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
@@ -2326,36 +2441,6 @@ where
     );
     rect.render_widget(submit_button, chunks[4]);
 
-    if app.state.focus == Focus::NewBoardName && app.state.app_status == AppStatus::UserInput {
-        if app.state.current_cursor_position.is_some() {
-            let (x_pos, y_pos) = calculate_cursor_position(
-                wrapped_title_text,
-                app.state
-                    .current_cursor_position
-                    .unwrap_or_else(|| app.state.new_board_form[0].len()),
-                chunks[1],
-            );
-            rect.set_cursor(x_pos, y_pos);
-        } else {
-            rect.set_cursor(chunks[1].x + 1, chunks[1].y + 1);
-        }
-    } else if app.state.focus == Focus::NewBoardDescription
-        && app.state.app_status == AppStatus::UserInput
-    {
-        if app.state.current_cursor_position.is_some() {
-            let (x_pos, y_pos) = calculate_cursor_position(
-                wrapped_description_text,
-                app.state
-                    .current_cursor_position
-                    .unwrap_or_else(|| app.state.new_board_form[1].len()),
-                chunks[2],
-            );
-            rect.set_cursor(x_pos, y_pos);
-        } else {
-            rect.set_cursor(chunks[2].x + 1, chunks[2].y + 1);
-        }
-    }
-
     if app.config.enable_mouse_support {
         render_close_button(rect, app);
     }
@@ -2389,7 +2474,7 @@ where
         app.theme.inactive_text_style
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[1]) {
         if app.state.mouse_focus != Some(Focus::NewCardName) {
-            app.state.current_cursor_position = None;
+            app.state.text_input.reset();
             app.state.app_status = AppStatus::Initialized;
         }
         app.state.mouse_focus = Some(Focus::NewCardName);
@@ -2404,7 +2489,7 @@ where
         app.theme.inactive_text_style
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
         if app.state.mouse_focus != Some(Focus::CardDescription) {
-            app.state.current_cursor_position = None;
+            app.state.text_input.reset();
             app.state.app_status = AppStatus::Initialized;
         }
         app.state.mouse_focus = Some(Focus::CardDescription);
@@ -2419,7 +2504,7 @@ where
         app.theme.inactive_text_style
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[3]) {
         if app.state.mouse_focus != Some(Focus::CardDueDate) {
-            app.state.current_cursor_position = None;
+            app.state.text_input.reset();
             app.state.app_status = AppStatus::Initialized;
         }
         app.state.mouse_focus = Some(Focus::CardDueDate);
@@ -2440,7 +2525,7 @@ where
     } else if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[5]) {
         app.state.mouse_focus = Some(Focus::SubmitButton);
         app.state.focus = Focus::SubmitButton;
-        app.state.current_cursor_position = None;
+        app.state.text_input.reset();
         app.state.app_status = AppStatus::Initialized;
         app.theme.mouse_focus_style
     } else if matches!(app.state.focus, Focus::SubmitButton) {
@@ -2459,74 +2544,47 @@ where
         );
     rect.render_widget(title_paragraph, chunks[0]);
 
-    let wrapped_card_name_text =
-        textwrap::wrap(&app.state.new_card_form[0], (chunks[1].width - 2) as usize);
-    let card_name_field = wrapped_card_name_text
-        .iter()
-        .map(|x| Spans::from(Span::raw(&**x)))
-        .collect::<Vec<Spans>>();
-    let wrapped_card_description_text =
-        textwrap::wrap(&app.state.new_card_form[1], (chunks[2].width - 2) as usize);
-    let card_description_field = wrapped_card_description_text
-        .iter()
-        .map(|x| Spans::from(Span::raw(&**x)))
-        .collect::<Vec<Spans>>();
-    let wrapped_card_due_date_text =
-        textwrap::wrap(&app.state.new_card_form[2], (chunks[3].width - 2) as usize);
-    let card_due_date_field = wrapped_card_due_date_text
-        .iter()
-        .map(|x| Spans::from(Span::raw(&**x)))
-        .collect::<Vec<Spans>>();
-    let card_name = Paragraph::new(card_name_field)
-        .alignment(Alignment::Left)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(name_style)
-                .border_type(BorderType::Rounded)
-                .title("Card Name (required)"),
-        );
-    rect.render_widget(card_name, chunks[1]);
+    let card_name_block = Block::default()
+        .borders(Borders::ALL)
+        .style(name_style)
+        .border_type(BorderType::Rounded)
+        .title("Card Name (required)");
+    let card_name = app.state.new_card_form[0].clone();
+    app.state.text_input.render(
+        rect,
+        &card_name,
+        card_name_block,
+        chunks[1],
+        app.theme.list_select_style,
+        app.state.focus == Focus::NewCardName && app.state.app_status == AppStatus::UserInput,
+    );
 
-    let card_description = Paragraph::new(card_description_field)
-        .alignment(Alignment::Left)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(description_style)
-                .border_type(BorderType::Rounded)
-                .title("Card Description"),
-        );
-    rect.render_widget(card_description, chunks[2]);
-
-    // check if &app.state.new_card_form[2] is in the format (DD/MM/YYYY-HH:MM:SS) or (DD/MM/YYYY)
-    let parsed_date =
-        match NaiveDateTime::parse_from_str(&app.state.new_card_form[2], DEFAULT_DATE_FORMAT) {
-            Ok(date) => Some(date),
-            Err(_) => {
-                match NaiveDateTime::parse_from_str(&app.state.new_card_form[2], "%d/%m/%Y") {
-                    Ok(date) => Some(date),
-                    Err(_) => None,
-                }
-            }
-        };
-    let card_due_date = Paragraph::new(card_due_date_field)
-        .alignment(Alignment::Left)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .style(due_date_style)
-                .border_type(BorderType::Rounded)
-                .title("Card Due Date (DD/MM/YYYY-HH:MM:SS) or (DD/MM/YYYY)"),
-        );
-    if parsed_date.is_some() {
-        rect.render_widget(card_due_date, chunks[3]);
-    } else if !app.state.new_card_form[2].is_empty() {
+    let card_description_block = Block::default()
+        .borders(Borders::ALL)
+        .style(description_style)
+        .border_type(BorderType::Rounded)
+        .title("Card Description");
+    let card_description = app.state.new_card_form[1].clone();
+    app.state.text_input.render(
+        rect,
+        &card_description,
+        card_description_block,
+        chunks[2],
+        app.theme.list_select_style,
+        app.state.focus == Focus::CardDescription && app.state.app_status == AppStatus::UserInput,
+    );
+
+    let parsed_date = CardDate::parse(&app.state.new_card_form[2]);
+    let card_due_date_block = Block::default()
+        .borders(Borders::ALL)
+        .style(due_date_style)
+        .border_type(BorderType::Rounded)
+        .title("Card Due Date (DD/MM/YYYY-HH:MM:SS) or (DD/MM/YYYY)");
+    let card_due_date_area = if parsed_date.is_none() && !app.state.new_card_form[2].is_empty() {
         let new_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(70), Constraint::Length(20)].as_ref())
             .split(chunks[3]);
-        rect.render_widget(card_due_date, new_chunks[0]);
         let error_text = Spans::from(vec![Span::raw("Invalid date format")]);
         let error_paragraph = Paragraph::new(error_text)
             .alignment(Alignment::Center)
@@ -2537,9 +2595,19 @@ where
                     .style(app.theme.error_text_style),
             );
         rect.render_widget(error_paragraph, new_chunks[1]);
+        new_chunks[0]
     } else {
-        rect.render_widget(card_due_date, chunks[3]);
-    }
+        chunks[3]
+    };
+    let card_due_date = app.state.new_card_form[2].clone();
+    app.state.text_input.render(
+        rect,
+        &card_due_date,
+        card_due_date_block,
+        card_due_date_area,
+        app.theme.list_select_style,
+        app.state.focus == Focus::CardDueDate && app.state.app_status == AppStatus::UserInput,
+    );
 
     let input_mode_key = app
         .state
@@ -2593,7 +2661,7 @@ where
                 .border_type(BorderType::Rounded)
                 .border_style(default_style),
         )
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        .wrap(Wrap { trim: true });
     rect.render_widget(help_paragraph, chunks[4]);
 
     let submit_button = Paragraph::new("Submit").alignment(Alignment::Center).block(
@@ -2604,50 +2672,6 @@ where
     );
     rect.render_widget(submit_button, chunks[5]);
 
-    if app.state.focus == Focus::NewCardName && app.state.app_status == AppStatus::UserInput {
-        if app.state.current_cursor_position.is_some() {
-            let (x_pos, y_pos) = calculate_cursor_position(
-                wrapped_card_name_text,
-                app.state
-                    .current_cursor_position
-                    .unwrap_or_else(|| app.state.new_card_form[0].len()),
-                chunks[1],
-            );
-            rect.set_cursor(x_pos, y_pos);
-        } else {
-            rect.set_cursor(chunks[1].x + 1, chunks[1].y + 1);
-        }
-    } else if app.state.focus == Focus::CardDescription
-        && app.state.app_status == AppStatus::UserInput
-    {
-        if app.state.current_cursor_position.is_some() {
-            let (x_pos, y_pos) = calculate_cursor_position(
-                wrapped_card_description_text,
-                app.state
-                    .current_cursor_position
-                    .unwrap_or_else(|| app.state.new_card_form[1].len()),
-                chunks[2],
-            );
-            rect.set_cursor(x_pos, y_pos);
-        } else {
-            rect.set_cursor(chunks[2].x + 1, chunks[2].y + 1);
-        }
-    } else if app.state.focus == Focus::CardDueDate && app.state.app_status == AppStatus::UserInput
-    {
-        if app.state.current_cursor_position.is_some() {
-            let (x_pos, y_pos) = calculate_cursor_position(
-                wrapped_card_due_date_text,
-                app.state
-                    .current_cursor_position
-                    .unwrap_or_else(|| app.state.new_card_form[2].len()),
-                chunks[3],
-            );
-            rect.set_cursor(x_pos, y_pos);
-        } else {
-            rect.set_cursor(chunks[3].x + 1, chunks[3].y + 1);
-        }
-    }
-
     if app.config.enable_mouse_support {
         render_close_button(rect, app);
     }
@@ -2876,88 +2900,303 @@ where
     }
 }
 
-pub fn render_toast<B>(rect: &mut Frame<B>, app: &mut App)
+pub fn render_archive<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
 {
-    // get the latest MAX_TOASTS_TO_DISPLAY number of toasts from app.state.toasts
-    let all_toasts = app.state.toasts.clone();
-    let mut loading_toasts = all_toasts
-        .iter()
-        .filter(|x| x.toast_type == ToastType::Loading)
-        .collect::<Vec<&ToastWidget>>();
-    let app_toasts = app.state.toasts.clone();
-    let toasts = if !loading_toasts.is_empty() {
-        // if loading_toasts are > MAX_TOASTS_TO_DISPLAY then put the loading toasts in the order of start time where the oldest is at the top only put MAX_TOASTS_TO_DISPLAY - 1 loading toasts and put the latest regular toast at the bottom
-        let sorted_loading_toasts = if loading_toasts.len() > MAX_TOASTS_TO_DISPLAY - 1 {
-            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-            loading_toasts
-                .iter()
-                .copied()
-                .take(MAX_TOASTS_TO_DISPLAY - 1)
-                .rev()
-                .collect::<Vec<&ToastWidget>>()
-        } else {
-            loading_toasts
-        };
-        // append the latest regular toast to the loading toasts till length is MAX_TOASTS_TO_DISPLAY
-        let mut toasts = sorted_loading_toasts;
-        let mut regular_toasts = all_toasts
-            .iter()
-            .filter(|x| x.toast_type != ToastType::Loading)
-            .collect::<Vec<&ToastWidget>>();
-        regular_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-        while toasts.len() < MAX_TOASTS_TO_DISPLAY {
-            if let Some(toast) = regular_toasts.pop() {
-                toasts.push(toast);
-            } else {
-                break;
-            }
-        }
-        // check if any more loading toasts are there and if so then append them to the toasts if there is space
-        if toasts.len() < MAX_TOASTS_TO_DISPLAY {
-            let mut loading_toasts = all_toasts
-                .iter()
-                .filter(|x| x.toast_type == ToastType::Loading)
-                .collect::<Vec<&ToastWidget>>();
-            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
-            while toasts.len() < MAX_TOASTS_TO_DISPLAY {
-                if let Some(toast) = loading_toasts.pop() {
-                    // check if the toast is already present in toasts
-                    if !toasts.contains(&toast) {
-                        toasts.push(toast);
-                    }
-                } else {
-                    break;
-                }
-            }
-        }
-        toasts
+    let default_style = if app.state.popup_mode.is_some() {
+        app.theme.inactive_text_style
     } else {
-        app_toasts
-            .iter()
-            .rev()
-            .take(MAX_TOASTS_TO_DISPLAY)
-            .rev()
-            .collect::<Vec<&ToastWidget>>()
+        app.theme.general_style
     };
-
-    if toasts.is_empty() {
-        return;
-    }
-    let mut total_height_rendered = 1; // for messages indicator
-
-    // loop through the toasts and draw them
-    for toast in toasts.iter() {
-        let toast_style = app.theme.general_style.fg(ratatui::style::Color::Rgb(
-            toast.toast_color.0,
-            toast.toast_color.1,
-            toast.toast_color.2,
-        ));
-        let toast_title = match toast.toast_type {
-            ToastType::Error => "Error",
-            ToastType::Info => "Info",
-            ToastType::Warning => "Warning",
+    let help_key_style = if app.state.popup_mode.is_some() {
+        app.theme.inactive_text_style
+    } else {
+        app.theme.help_key_style
+    };
+    let main_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+        .split(rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(10),
+                Constraint::Length(5),
+            ]
+            .as_ref(),
+        )
+        .split(main_chunks[0]);
+    let details_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(1)].as_ref())
+        .split(main_chunks[1]);
+
+    let title_paragraph = Paragraph::new("Archive")
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    rect.render_widget(title_paragraph, chunks[0]);
+
+    let archived_items = app.archived_items();
+    let find_board = |board_id: u128| app.boards.iter().find(|b| b.id == board_id);
+    if archived_items.is_empty() {
+        let empty_paragraph = Paragraph::new("No archived boards or cards")
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .style(app.theme.error_text_style);
+        rect.render_widget(empty_paragraph, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = archived_items
+            .iter()
+            .filter_map(|item| match *item {
+                ArchivedItem::Board(board_id) => find_board(board_id)
+                    .map(|board| format!("[Board] {} ({} cards)", board.name, board.cards.len())),
+                ArchivedItem::Card(board_id, card_id) => find_board(board_id).and_then(|board| {
+                    board
+                        .get_card(card_id)
+                        .map(|card| format!("[Card] {} in {}", card.name, board.name))
+                }),
+            })
+            .map(ListItem::new)
+            .collect();
+        let archived_list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Archived Boards and Cards")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_style(app.theme.list_select_style)
+            .highlight_symbol(LIST_SELECTED_SYMBOL)
+            .style(default_style);
+
+        if app.state.popup_mode.is_none()
+            && check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[1])
+        {
+            app.state.mouse_focus = Some(Focus::ArchivedItems);
+            app.state.focus = Focus::ArchivedItems;
+            let top_of_list = chunks[1].y + 1;
+            let mut bottom_of_list = chunks[1].y + archived_items.len() as u16;
+            if bottom_of_list > chunks[1].bottom() {
+                bottom_of_list = chunks[1].bottom();
+            }
+            let mouse_y = app.state.current_mouse_coordinates.1;
+            if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+                app.state
+                    .archive_list_state
+                    .select(Some((mouse_y - top_of_list) as usize));
+            }
+        }
+        rect.render_stateful_widget(archived_list, chunks[1], &mut app.state.archive_list_state);
+    }
+
+    let up_key = app
+        .state
+        .keybind_store
+        .iter()
+        .find(|x| x[1] == "Go up")
+        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
+        .clone();
+    let down_key = app
+        .state
+        .keybind_store
+        .iter()
+        .find(|x| x[1] == "Go down")
+        .unwrap_or(&vec!["".to_string(), "".to_string()])[0]
+        .clone();
+    let help_text = Spans::from(vec![
+        Span::styled("Use ", default_style),
+        Span::styled(&up_key, help_key_style),
+        Span::styled(" and ", default_style),
+        Span::styled(&down_key, help_key_style),
+        Span::styled("to navigate", default_style),
+        Span::raw("; "),
+        Span::styled("<Enter>", help_key_style),
+        Span::styled(" to restore the selected item", default_style),
+        Span::raw("; "),
+        Span::styled("<Esc>", help_key_style),
+        Span::styled(" to go back", default_style),
+    ]);
+    let help_paragraph = Paragraph::new(help_text)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: true })
+        .style(default_style);
+    rect.render_widget(help_paragraph, chunks[2]);
+
+    let selected_item = app
+        .state
+        .archive_list_state
+        .selected()
+        .and_then(|index| archived_items.get(index).copied());
+    let details = match selected_item {
+        Some(ArchivedItem::Board(board_id)) => find_board(board_id).map(|board| {
+            vec![
+                format!("Board: {}", board.name),
+                format!("Description: {}", board.description),
+                format!("Cards: {}", board.cards.len()),
+                format!(
+                    "Workflow: {}",
+                    WorkflowStatus::workflow_to_string(&board.workflow())
+                ),
+            ]
+        }),
+        Some(ArchivedItem::Card(board_id, card_id)) => find_board(board_id).and_then(|board| {
+            board.get_card(card_id).map(|card| {
+                let format_date = |date: Option<CardDate>| {
+                    date.map(|date| date.to_string())
+                        .unwrap_or_else(|| FIELD_NOT_SET.to_string())
+                };
+                vec![
+                    format!("Card: {}", card.name),
+                    format!("Board: {}", board.name),
+                    format!("Description: {}", card.description),
+                    format!("Status: {}", card.status_name()),
+                    format!("Priority: {}", card.priority),
+                    format!("Due: {}", format_date(card.date_due)),
+                    format!("Completed: {}", format_date(card.date_completed)),
+                    format!("Tags: {}", card.tags.join(", ")),
+                ]
+            })
+        }),
+        None => None,
+    };
+    let details_paragraph = if let Some(details) = details {
+        Paragraph::new(details.into_iter().map(Spans::from).collect::<Vec<Spans>>())
+    } else {
+        Paragraph::new(format!(
+            "Select an archived board or card with {} or {} to see its details",
+            up_key, down_key
+        ))
+        .alignment(Alignment::Center)
+    }
+    .block(
+        Block::default()
+            .title("Details")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .style(default_style)
+    .wrap(Wrap { trim: true });
+    rect.render_widget(details_paragraph, details_chunks[1]);
+
+    let details_title_paragraph = Paragraph::new("Restored items return to their boards")
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .style(default_style);
+    if app.config.enable_mouse_support {
+        let title_bar_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(1), Constraint::Length(3)].as_ref())
+            .split(details_chunks[0]);
+        rect.render_widget(details_title_paragraph, title_bar_chunks[0]);
+        render_close_button(rect, app);
+    } else {
+        rect.render_widget(details_title_paragraph, details_chunks[0]);
+    }
+}
+
+pub fn render_toast<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    // get the latest MAX_TOASTS_TO_DISPLAY number of toasts from app.state.toasts
+    let all_toasts = app.state.toasts.clone();
+    let mut loading_toasts = all_toasts
+        .iter()
+        .filter(|x| x.toast_type == ToastType::Loading)
+        .collect::<Vec<&ToastWidget>>();
+    let app_toasts = app.state.toasts.clone();
+    let toasts = if !loading_toasts.is_empty() {
+        // if loading_toasts are > MAX_TOASTS_TO_DISPLAY then put the loading toasts in the order of start time where the oldest is at the top only put MAX_TOASTS_TO_DISPLAY - 1 loading toasts and put the latest regular toast at the bottom
+        let sorted_loading_toasts = if loading_toasts.len() > MAX_TOASTS_TO_DISPLAY - 1 {
+            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+            loading_toasts
+                .iter()
+                .copied()
+                .take(MAX_TOASTS_TO_DISPLAY - 1)
+                .rev()
+                .collect::<Vec<&ToastWidget>>()
+        } else {
+            loading_toasts
+        };
+        // append the latest regular toast to the loading toasts till length is MAX_TOASTS_TO_DISPLAY
+        let mut toasts = sorted_loading_toasts;
+        let mut regular_toasts = all_toasts
+            .iter()
+            .filter(|x| x.toast_type != ToastType::Loading)
+            .collect::<Vec<&ToastWidget>>();
+        regular_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+        while toasts.len() < MAX_TOASTS_TO_DISPLAY {
+            if let Some(toast) = regular_toasts.pop() {
+                toasts.push(toast);
+            } else {
+                break;
+            }
+        }
+        // check if any more loading toasts are there and if so then append them to the toasts if there is space
+        if toasts.len() < MAX_TOASTS_TO_DISPLAY {
+            let mut loading_toasts = all_toasts
+                .iter()
+                .filter(|x| x.toast_type == ToastType::Loading)
+                .collect::<Vec<&ToastWidget>>();
+            loading_toasts.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+            while toasts.len() < MAX_TOASTS_TO_DISPLAY {
+                if let Some(toast) = loading_toasts.pop() {
+                    // check if the toast is already present in toasts
+                    if !toasts.contains(&toast) {
+                        toasts.push(toast);
+                    }
+                } else {
+                    break;
+                }
+            }
+        }
+        toasts
+    } else {
+        app_toasts
+            .iter()
+            .rev()
+            .take(MAX_TOASTS_TO_DISPLAY)
+            .rev()
+            .collect::<Vec<&ToastWidget>>()
+    };
+
+    if toasts.is_empty() {
+        return;
+    }
+    let mut total_height_rendered = 1; // for messages indicator
+
+    // loop through the toasts and draw them
+    for toast in toasts.iter() {
+        let toast_style = app.theme.general_style.fg(ratatui::style::Color::Rgb(
+            toast.toast_color.0,
+            toast.toast_color.1,
+            toast.toast_color.2,
+        ));
+        let toast_title = match toast.toast_type {
+            ToastType::Error => "Error",
+            ToastType::Info => "Info",
+            ToastType::Warning => "Warning",
             ToastType::Loading => "Loading",
         };
         // if the toast type is loading display a spinner next to the title and use the duration.elapsed() to determine the current frame of the spinner
@@ -3065,6 +3304,12 @@ where
             .split(popup_area)
     };
 
+    // card info, tags and comments on the left, the activity log on the right
+    let info_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(card_chunks[1]);
+
     if app.state.focus == Focus::SubmitButton && app.card_being_edited.is_none() {
         app.state.focus = Focus::CardDescription;
     }
@@ -3129,12 +3374,6 @@ where
     let board_name = board.name.clone();
     let card_name = card.name.clone();
     let card_description = card.description.clone();
-    let wrapped_description =
-        textwrap::wrap(&card_description, (card_chunks[0].width - 2) as usize);
-    let wrapped_description_spans = wrapped_description
-        .iter()
-        .map(|x| Spans::from(Span::styled(&**x, app.theme.general_style)))
-        .collect::<Vec<Spans>>();
     let main_block = Block::default()
         .title(format!("{} >> Board({})", card_name, board_name))
         .borders(Borders::ALL)
@@ -3148,14 +3387,20 @@ where
         app.theme.general_style
     };
 
-    let description_paragraph = Paragraph::new(wrapped_description_spans).block(
-        Block::default()
-            .title("Description")
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(description_style),
+    let description_block = Block::default()
+        .title("Description")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(app.theme.general_style)
+        .border_style(description_style);
+    app.state.text_input.render(
+        rect,
+        &card_description,
+        description_block,
+        card_chunks[0],
+        app.theme.list_select_style,
+        app.state.focus == Focus::CardDescription && app.state.app_status == AppStatus::UserInput,
     );
-    rect.render_widget(description_paragraph, card_chunks[0]);
 
     let card_date_created = Span::styled(
         format!("Created: {}", card.date_created),
@@ -3165,46 +3410,53 @@ where
         format!("Modified: {}", card.date_modified),
         app.theme.general_style,
     );
-    let card_date_completed = Span::styled(
-        format!("Completed: {}", card.date_completed),
-        app.theme.general_style,
-    );
+    let card_date_completed = match (card.date_completed, card.time_to_complete()) {
+        (Some(date_completed), Some(time_to_complete)) => format!(
+            "Completed: {} (took {})",
+            date_completed,
+            format_duration(time_to_complete)
+        ),
+        _ => format!("Completed: {}", FIELD_NOT_SET),
+    };
+    let card_date_completed = Span::styled(card_date_completed, app.theme.general_style);
     let card_priority = format!("Priority: {}", card.priority);
-    let card_status = format!("Status: {}", card.card_status);
-    let parsed_due_date = NaiveDateTime::parse_from_str(&card.date_due, DEFAULT_DATE_FORMAT);
-    // card due date is in the format dd/mm/yyyy check if the due date is within WARNING_DUE_DATE_DAYS if so highlight it
-    let card_due_date_styled = if let Ok(parsed_due_date) = parsed_due_date {
+    let card_status = format!("Status: {}", card.status_name());
+    // while the card is being edited the due date is shown as typed
+    let card_due_date_text = if app.card_being_edited.is_some() {
+        app.card_due_date_being_edited.clone()
+    } else {
+        card.date_due
+            .map(|date| date.to_string())
+            .unwrap_or_else(|| FIELD_NOT_SET.to_string())
+    };
+    // check if the due date is within WARNING_DUE_DATE_DAYS if so highlight it
+    let card_due_date_styled = if app.state.focus == Focus::CardDueDate {
+        Span::styled(
+            format!("Due: {}", card_due_date_text),
+            app.theme.list_select_style,
+        )
+    } else if let Some(card_due_date) = card.date_due {
         let today = Local::now().naive_local();
-        let days_left = parsed_due_date.signed_duration_since(today).num_days();
-        if app.state.focus == Focus::CardDueDate {
+        let days_left = card_due_date.signed_duration_since(today).num_days();
+        if days_left <= app.config.warning_delta.into() && days_left >= 0 {
             Span::styled(
-                format!("Due: {}", card.date_due),
-                app.theme.list_select_style,
-            )
-        } else if days_left <= app.config.warning_delta.into() && days_left >= 0 {
-            Span::styled(
-                format!("Due: {}", card.date_due),
+                format!("Due: {}", card_due_date_text),
                 app.theme.card_due_warning_style,
             )
         } else if days_left < 0 {
             Span::styled(
-                format!("Due: {}", card.date_due),
+                format!("Due: {}", card_due_date_text),
                 app.theme.card_due_overdue_style,
             )
         } else {
             Span::styled(
-                format!("Due: {}", card.date_due),
+                format!("Due: {}", card_due_date_text),
                 app.theme.card_due_default_style,
             )
         }
-    } else if app.state.focus == Focus::CardDueDate {
-        Span::styled(
-            format!("Due: {}", card.date_due),
-            app.theme.list_select_style,
-        )
     } else {
         Span::styled(
-            format!("Due: {}", card.date_due),
+            format!("Due: {}", card_due_date_text),
             app.theme.card_due_default_style,
         )
     };
@@ -3221,14 +3473,17 @@ where
     };
     let card_status_styled = if app.state.focus == Focus::CardStatus {
         Span::styled(card_status, app.theme.list_select_style)
-    } else if card.card_status == CardStatus::Complete {
-        Span::styled(card_status, app.theme.card_status_completed_style)
-    } else if card.card_status == CardStatus::Active {
-        Span::styled(card_status, app.theme.card_status_active_style)
-    } else if card.card_status == CardStatus::Stale {
-        Span::styled(card_status, app.theme.card_status_stale_style)
     } else {
-        Span::styled(card_status, app.theme.general_style)
+        Span::styled(card_status, card_status_style(app, &card.card_status))
+    };
+    let card_recurrence = format!(
+        "Repeats: {}",
+        CardRecurrence::option_to_string(card.recurrence)
+    );
+    let card_recurrence_styled = if app.state.focus == Focus::CardRecurrence {
+        Span::styled(card_recurrence, app.theme.list_select_style)
+    } else {
+        Span::styled(card_recurrence, app.theme.general_style)
     };
     let card_extra_info_items = vec![
         ListItem::new(vec![Spans::from(card_date_created)]),
@@ -3237,12 +3492,13 @@ where
         ListItem::new(vec![Spans::from(card_date_completed)]),
         ListItem::new(vec![Spans::from(card_priority_styled)]),
         ListItem::new(vec![Spans::from(card_status_styled)]),
+        ListItem::new(vec![Spans::from(card_recurrence_styled)]),
     ];
-    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, card_chunks[1]) {
-        let top_of_list = card_chunks[1].y + 1;
-        let mut bottom_of_list = card_chunks[1].y + card_extra_info_items.len() as u16;
-        if bottom_of_list > card_chunks[1].bottom() {
-            bottom_of_list = card_chunks[1].bottom();
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, info_chunks[0]) {
+        let top_of_list = info_chunks[0].y + 1;
+        let mut bottom_of_list = info_chunks[0].y + card_extra_info_items.len() as u16;
+        if bottom_of_list > info_chunks[0].bottom() {
+            bottom_of_list = info_chunks[0].bottom();
         }
         let mouse_y = app.state.current_mouse_coordinates.1;
         if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
@@ -3252,21 +3508,36 @@ where
                     app.state.mouse_focus = Some(Focus::CardDueDate);
                     app.state.card_view_comment_list_state.select(None);
                     app.state.card_view_tag_list_state.select(None);
-                    app.state.current_cursor_position = None;
+                    app.state.card_view_checklist_list_state.select(None);
+                    app.state.card_view_link_list_state.select(None);
+                    app.state.text_input.reset();
                 }
                 4 => {
                     app.state.focus = Focus::CardPriority;
                     app.state.mouse_focus = Some(Focus::CardPriority);
                     app.state.card_view_comment_list_state.select(None);
                     app.state.card_view_tag_list_state.select(None);
-                    app.state.current_cursor_position = None;
+                    app.state.card_view_checklist_list_state.select(None);
+                    app.state.card_view_link_list_state.select(None);
+                    app.state.text_input.reset();
                 }
                 5 => {
                     app.state.focus = Focus::CardStatus;
                     app.state.mouse_focus = Some(Focus::CardStatus);
                     app.state.card_view_comment_list_state.select(None);
                     app.state.card_view_tag_list_state.select(None);
-                    app.state.current_cursor_position = None;
+                    app.state.card_view_checklist_list_state.select(None);
+                    app.state.card_view_link_list_state.select(None);
+                    app.state.text_input.reset();
+                }
+                6 => {
+                    app.state.focus = Focus::CardRecurrence;
+                    app.state.mouse_focus = Some(Focus::CardRecurrence);
+                    app.state.card_view_comment_list_state.select(None);
+                    app.state.card_view_tag_list_state.select(None);
+                    app.state.card_view_checklist_list_state.select(None);
+                    app.state.card_view_link_list_state.select(None);
+                    app.state.text_input.reset();
                 }
                 _ => {
                     app.state.focus = Focus::NoFocus;
@@ -3285,7 +3556,9 @@ where
         app.state.mouse_focus = Some(Focus::CardDescription);
         app.state.card_view_comment_list_state.select(None);
         app.state.card_view_tag_list_state.select(None);
-        app.state.current_cursor_position = None;
+        app.state.card_view_checklist_list_state.select(None);
+        app.state.card_view_link_list_state.select(None);
+        app.state.text_input.reset();
     }
     let card_tags_style = if app.state.focus == Focus::CardTags {
         app.theme.keyboard_focus_style
@@ -3305,183 +3578,52 @@ where
             .border_style(app.theme.general_style),
     );
 
-    let card_tags = if app.state.focus == Focus::CardTags {
-        let mut tags = vec![];
-        if app.state.card_view_tag_list_state.selected().is_none() {
-            for (index, tag) in card.tags.iter().enumerate() {
-                tags.push(Span::styled(
-                    format!("{}) {} ", index + 1, tag),
-                    app.theme.general_style,
-                ));
-            }
-        } else {
-            let selected_tag = app.state.card_view_tag_list_state.selected().unwrap();
-            for (index, tag) in card.tags.iter().enumerate() {
-                if index == selected_tag {
-                    tags.push(Span::styled(
-                        format!("{}) {} ", index + 1, tag),
-                        app.theme.keyboard_focus_style,
-                    ));
-                } else {
-                    tags.push(Span::styled(
-                        format!("{}) {} ", index + 1, tag),
-                        app.theme.general_style,
-                    ));
-                }
-            }
-        }
-        tags
-    } else {
-        let mut tags = vec![];
-        for (index, tag) in card.tags.iter().enumerate() {
-            tags.push(Span::styled(
-                format!("{}) {} ", index + 1, tag),
+    let card_tag_items = card
+        .tags
+        .iter()
+        .enumerate()
+        .map(|(index, tag)| {
+            (
+                format!("{}) ", index + 1),
+                tag.clone(),
                 app.theme.general_style,
-            ));
-        }
-        tags
-    };
-
-    // do the same for card.comments
-    let card_comments = if app.state.focus == Focus::CardComments {
-        let mut comments = vec![];
-        if app.state.card_view_comment_list_state.selected().is_none() {
-            for (index, comment) in card.comments.iter().enumerate() {
-                comments.push(Span::styled(
-                    format!("{}) {} ", index + 1, comment),
-                    app.theme.general_style,
-                ));
-            }
-        } else {
-            let selected_comment = app.state.card_view_comment_list_state.selected().unwrap();
-            for (index, comment) in card.comments.iter().enumerate() {
-                if index == selected_comment {
-                    comments.push(Span::styled(
-                        format!("{}) {} ", index + 1, comment),
-                        app.theme.keyboard_focus_style,
-                    ));
-                } else {
-                    comments.push(Span::styled(
-                        format!("{}) {} ", index + 1, comment),
-                        app.theme.general_style,
-                    ));
-                }
-            }
-        }
-        comments
-    } else {
-        let mut comments = vec![];
-        for (index, comment) in card.comments.iter().enumerate() {
-            comments.push(Span::styled(
-                format!("{}) {} ", index + 1, comment),
+            )
+        })
+        .collect::<Vec<(String, String, Style)>>();
+    let card_comment_items = card
+        .comments
+        .iter()
+        .enumerate()
+        .map(|(index, comment)| {
+            (
+                format!("{}) ", index + 1),
+                comment.clone(),
                 app.theme.general_style,
-            ));
-        }
-        comments
-    };
-
-    let mut card_tag_spans = vec![];
-    let mut collector = String::new();
-    let mut collector_start = 0;
-    let mut collector_end = 0;
-    for (i, tag) in card.tags.iter().enumerate() {
-        let tag_string = format!("{}) {} ", i + 1, tag);
-        if (collector.len() + tag_string.len()) < card_chunks[1].width as usize {
-            collector.push_str(&tag_string);
-            collector_end = i + 1;
-        } else {
-            card_tag_spans.push(Spans::from(
-                card_tags[collector_start..collector_end].to_vec(),
-            ));
-            collector = String::new();
-            collector.push_str(&tag_string);
-            collector_start = i;
-            collector_end = i + 1;
-        }
-    }
-    if !collector.is_empty() {
-        card_tag_spans.push(Spans::from(
-            card_tags[collector_start..collector_end].to_vec(),
-        ));
-    }
-
-    let mut card_comment_spans = vec![];
-    let mut collector = String::new();
-    let mut collector_start = 0;
-    let mut collector_end = 0;
-    for (i, comment) in card.comments.iter().enumerate() {
-        let comment_string = format!("{}) {} ", i + 1, comment);
-        if (collector.len() + comment_string.len()) < card_chunks[1].width as usize {
-            collector.push_str(&comment_string);
-            collector_end = i + 1;
-        } else {
-            card_comment_spans.push(Spans::from(
-                card_comments[collector_start..collector_end].to_vec(),
-            ));
-            collector = String::new();
-            collector.push_str(&comment_string);
-            collector_start = i;
-            collector_end = i + 1;
-        }
-    }
-    if !collector.is_empty() {
-        card_comment_spans.push(Spans::from(
-            card_comments[collector_start..collector_end].to_vec(),
-        ));
-    }
-
-    let card_tags_paragraph = Paragraph::new(card_tag_spans.clone())
-        .block(
-            Block::default()
-                .title("Tags")
-                .border_type(BorderType::Rounded)
-                .borders(Borders::ALL)
-                .border_style(card_tags_style),
-        )
-        .alignment(Alignment::Left);
-
-    let card_comments_paragraph = Paragraph::new(card_comment_spans.clone())
-        .block(
-            Block::default()
-                .title("Comments")
-                .border_type(BorderType::Rounded)
-                .borders(Borders::ALL)
-                .border_style(card_comments_style),
-        )
-        .alignment(Alignment::Left);
+            )
+        })
+        .collect::<Vec<(String, String, Style)>>();
+    let card_tags_block = Block::default()
+        .title("Tags")
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .border_style(card_tags_style);
+    let card_comments_block = Block::default()
+        .title("Comments")
+        .border_type(BorderType::Rounded)
+        .borders(Borders::ALL)
+        .border_style(card_comments_style);
 
     let extra_info_chunks = {
-        let card_tags = card_tags
-            .clone()
-            .iter()
-            .map(|span| span.content.to_string())
-            .collect::<String>();
-        let card_comments = card_comments
-            .clone()
-            .iter()
-            .map(|span| span.content.to_string())
-            .collect::<String>();
-
-        let available_height = card_chunks[1].height - 8;
-        let tags_height = if card_tags.is_empty() {
-            0
-        } else {
-            textwrap::wrap(&card_tags, card_chunks[1].width as usize).len() as u16
-        };
-        let comments_height = if card_comments.is_empty() {
-            0
-        } else {
-            textwrap::wrap(&card_comments, card_chunks[1].width as usize).len() as u16
-        };
-
-        let mut tags_height = tags_height + 2;
-        let mut comments_height = comments_height + 2;
+        let available_height = info_chunks[0].height - 9;
+        // one line per tag and comment plus the borders
+        let mut tags_height = card.tags.len() as u16 + 2;
+        let mut comments_height = card.comments.len() as u16 + 2;
 
         if tags_height + comments_height > available_height {
             if tags_height > comments_height {
-                tags_height = available_height - comments_height;
+                tags_height = available_height.saturating_sub(comments_height);
             } else {
-                comments_height = available_height - tags_height;
+                comments_height = available_height.saturating_sub(tags_height);
             }
         } else {
             comments_height = available_height - tags_height;
@@ -3490,126 +3632,283 @@ where
         Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(8),
+                Constraint::Length(9),
                 Constraint::Length(tags_height),
                 Constraint::Length(comments_height),
             ])
-            .split(card_chunks[1])
+            .split(info_chunks[0])
     };
 
     if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, extra_info_chunks[1]) {
         app.state.focus = Focus::CardTags;
         app.state.mouse_focus = Some(Focus::CardTags);
         app.state.card_view_comment_list_state.select(None);
+        app.state.card_view_checklist_list_state.select(None);
+        app.state.card_view_link_list_state.select(None);
     }
 
     if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, extra_info_chunks[2]) {
         app.state.focus = Focus::CardComments;
         app.state.mouse_focus = Some(Focus::CardComments);
         app.state.card_view_tag_list_state.select(None);
+        app.state.card_view_checklist_list_state.select(None);
+        app.state.card_view_link_list_state.select(None);
     }
 
-    rect.render_widget(card_extra_info, extra_info_chunks[0]);
-    rect.render_widget(card_tags_paragraph, extra_info_chunks[1]);
-    rect.render_widget(card_comments_paragraph, extra_info_chunks[2]);
+    // the checklist and the links sit above the activity log, one entry per line
+    let checklist_height = (card.checklist.len() as u16 + 2)
+        .max(3)
+        .min(info_chunks[1].height / 3);
+    // links to other cards followed by the cards this card blocks
+    let blocked_cards = app
+        .boards
+        .iter()
+        .flat_map(|board| board.cards.iter())
+        .filter(|other_card| {
+            other_card.links.contains(&CardLink {
+                kind: CardLinkKind::BlockedBy,
+                card_id: card.id,
+            })
+        })
+        .collect::<Vec<&Card>>();
+    let links_height = ((card.links.len() + blocked_cards.len()) as u16 + 2)
+        .max(3)
+        .min(info_chunks[1].height / 3);
+    let side_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(checklist_height),
+            Constraint::Length(links_height),
+            Constraint::Min(0),
+        ])
+        .split(info_chunks[1]);
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, side_chunks[0]) {
+        app.state.focus = Focus::CardChecklist;
+        app.state.mouse_focus = Some(Focus::CardChecklist);
+        app.state.card_view_comment_list_state.select(None);
+        app.state.card_view_tag_list_state.select(None);
+        app.state.card_view_link_list_state.select(None);
+    }
 
-    if app.state.app_status == AppStatus::UserInput {
-        match app.state.focus {
-            Focus::CardDescription => {
-                let (x_pos, y_pos) = calculate_cursor_position(
-                    wrapped_description,
-                    app.state.current_cursor_position.unwrap_or(0),
-                    card_chunks[0],
-                );
-                rect.set_cursor(x_pos, y_pos);
-            }
-            Focus::CardDueDate => {
-                let (x_pos, y_pos) = calculate_cursor_position(
-                    textwrap::wrap(&card.date_due, card_chunks[1].width as usize),
-                    app.state.current_cursor_position.unwrap_or(0),
-                    card_chunks[1],
-                );
-                rect.set_cursor(x_pos + 5, y_pos + 2); // +5 and +2 are to account for the "Due: " text and extra info position offset
-            }
-            Focus::CardTags => {
-                // TODO: Fix cursor position
-                // card_tag_spans.0 is a vector of spans check app.state.card_view_tag_list_state.selected() check i which span is selected and then calculate the cursor position
-                if app.state.card_view_tag_list_state.selected().is_some() {
-                    let selected_index = app.state.card_view_tag_list_state.selected().unwrap();
-                    let mut counter = 0;
-                    let mut y_index = 0;
-                    let mut length_before_selected_tag = 0;
-                    let mut prv_spans_length = 0;
-                    let tag_offset = 3;
-                    for spans in card_tag_spans.iter() {
-                        for _ in spans.0.iter() {
-                            if counter == selected_index {
-                                break;
-                            } else {
-                                let element = spans.0.get(counter - prv_spans_length);
-                                if let Some(element) = element {
-                                    length_before_selected_tag += element.content.len();
-                                }
-                                counter += 1;
-                            }
-                        }
-                        if counter == selected_index {
-                            break;
-                        }
-                        y_index += 1;
-                        prv_spans_length += spans.0.iter().len();
-                        length_before_selected_tag = 0;
-                    }
-                    let digits_in_counter = (counter + 1).to_string().len();
-                    let x_pos = extra_info_chunks[1].left()
-                        + length_before_selected_tag as u16
-                        + app.state.current_cursor_position.unwrap_or(0) as u16
-                        + tag_offset
-                        + digits_in_counter as u16;
-                    let y_pos = extra_info_chunks[1].top() + y_index as u16 + 1;
-                    rect.set_cursor(x_pos, y_pos);
-                }
-            }
-            Focus::CardComments => {
-                // do the same as tags
-                if app.state.card_view_comment_list_state.selected().is_some() {
-                    let selected_index = app.state.card_view_comment_list_state.selected().unwrap();
-                    let mut counter = 0;
-                    let mut y_index = 0;
-                    let mut length_before_selected_comment = 0;
-                    let mut prv_spans_length = 0;
-                    let comment_offset = 3;
-                    for spans in card_comment_spans.iter() {
-                        for _ in spans.0.iter() {
-                            if counter == selected_index {
-                                break;
-                            } else {
-                                let element = spans.0.get(counter - prv_spans_length);
-                                if let Some(element) = element {
-                                    length_before_selected_comment += element.content.len();
-                                }
-                                counter += 1;
-                            }
-                        }
-                        if counter == selected_index {
-                            break;
-                        }
-                        y_index += 1;
-                        prv_spans_length += spans.0.iter().len();
-                        length_before_selected_comment = 0;
-                    }
-                    let digits_in_counter = (counter + 1).to_string().len();
-                    let x_pos = extra_info_chunks[2].left()
-                        + length_before_selected_comment as u16
-                        + app.state.current_cursor_position.unwrap_or(0) as u16
-                        + comment_offset
-                        + digits_in_counter as u16;
-                    let y_pos = extra_info_chunks[2].top() + y_index as u16 + 1;
-                    rect.set_cursor(x_pos, y_pos);
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, side_chunks[1]) {
+        app.state.focus = Focus::CardLinks;
+        app.state.mouse_focus = Some(Focus::CardLinks);
+        app.state.card_view_comment_list_state.select(None);
+        app.state.card_view_tag_list_state.select(None);
+        app.state.card_view_checklist_list_state.select(None);
+    }
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, side_chunks[2]) {
+        app.state.focus = Focus::CardHistory;
+        app.state.mouse_focus = Some(Focus::CardHistory);
+        app.state.card_view_comment_list_state.select(None);
+        app.state.card_view_tag_list_state.select(None);
+        app.state.card_view_checklist_list_state.select(None);
+        app.state.card_view_link_list_state.select(None);
+    }
+    let card_checklist_items = card
+        .checklist
+        .iter()
+        .map(|item| {
+            let style = if item.done {
+                app.theme.inactive_text_style
+            } else {
+                app.theme.general_style
+            };
+            let checkbox = if item.done { "[x] " } else { "[ ] " };
+            (checkbox.to_string(), item.name.clone(), style)
+        })
+        .collect::<Vec<(String, String, Style)>>();
+    let card_checklist_style = if app.state.focus == Focus::CardChecklist {
+        app.theme.keyboard_focus_style
+    } else {
+        app.theme.general_style
+    };
+    let (checklist_done, checklist_total) = card.checklist_progress();
+    let card_checklist_title = if app.state.focus == Focus::CardChecklist
+        && app.state.app_status == AppStatus::UserInput
+    {
+        format!(
+            "Checklist ({}/{}) - <Ctrl+t> to toggle",
+            checklist_done, checklist_total
+        )
+    } else {
+        format!("Checklist ({}/{})", checklist_done, checklist_total)
+    };
+    let card_checklist_block = Block::default()
+        .title(card_checklist_title)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(card_checklist_style);
+
+    let selected_link = if app.state.focus == Focus::CardLinks {
+        app.state.card_view_link_list_state.selected()
+    } else {
+        None
+    };
+    let mut card_link_spans = card
+        .links
+        .iter()
+        .enumerate()
+        .map(|(index, link)| {
+            let linked_card = find_card(&app.boards, link.card_id);
+            let linked_card_text = match linked_card {
+                Some(linked_card) => {
+                    format!("{} ({})", linked_card.name, linked_card.status_name())
                 }
-            }
-            _ => {}
+                None => format!("Unknown card ({})", link.card_id),
+            };
+            let style = if selected_link == Some(index) {
+                app.theme.keyboard_focus_style
+            } else if link.kind == CardLinkKind::BlockedBy
+                && linked_card
+                    .is_some_and(|linked_card| linked_card.card_status != CardStatus::Complete)
+            {
+                app.theme.card_due_overdue_style
+            } else {
+                app.theme.general_style
+            };
+            Spans::from(Span::styled(
+                format!("{}: {}", link.kind, linked_card_text),
+                style,
+            ))
+        })
+        .collect::<Vec<Spans>>();
+    card_link_spans.extend(blocked_cards.iter().map(|blocked_card| {
+        Spans::from(Span::styled(
+            format!(
+                "Blocks: {} ({})",
+                blocked_card.name,
+                blocked_card.status_name()
+            ),
+            app.theme.inactive_text_style,
+        ))
+    }));
+    let card_links_style = if app.state.focus == Focus::CardLinks {
+        app.theme.keyboard_focus_style
+    } else {
+        app.theme.general_style
+    };
+    let card_links_paragraph = Paragraph::new(card_link_spans).block(
+        Block::default()
+            .title("Links")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(card_links_style),
+    );
+
+    // newest entries first
+    let card_history_items = card
+        .history
+        .iter()
+        .rev()
+        .map(|activity| {
+            ListItem::new(vec![
+                Spans::from(Span::styled(
+                    activity.date.to_string(),
+                    app.theme.inactive_text_style,
+                )),
+                Spans::from(Span::styled(
+                    activity.description.clone(),
+                    app.theme.general_style,
+                )),
+            ])
+        })
+        .collect::<Vec<ListItem>>();
+    if app
+        .state
+        .card_view_history_list_state
+        .selected()
+        .is_some_and(|selected| selected >= card_history_items.len())
+    {
+        app.state.card_view_history_list_state.select(None);
+    }
+    let card_history_style = if app.state.focus == Focus::CardHistory {
+        app.theme.keyboard_focus_style
+    } else {
+        app.theme.general_style
+    };
+    let card_history = List::new(card_history_items)
+        .block(
+            Block::default()
+                .title("History")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(card_history_style),
+        )
+        .highlight_style(app.theme.list_select_style);
+
+    let editing =
+        |focus: Focus| app.state.app_status == AppStatus::UserInput && app.state.focus == focus;
+    let selected_item = |focus: Focus, list_state: &ListState| {
+        if app.state.focus == focus {
+            list_state.selected()
+        } else {
+            None
         }
+    };
+    let card_tags_list = (
+        selected_item(Focus::CardTags, &app.state.card_view_tag_list_state),
+        editing(Focus::CardTags),
+    );
+    let card_comments_list = (
+        selected_item(Focus::CardComments, &app.state.card_view_comment_list_state),
+        editing(Focus::CardComments),
+    );
+    let card_checklist_list = (
+        selected_item(
+            Focus::CardChecklist,
+            &app.state.card_view_checklist_list_state,
+        ),
+        editing(Focus::CardChecklist),
+    );
+
+    rect.render_widget(card_extra_info, extra_info_chunks[0]);
+    for (items, (selected, editing), block, area) in [
+        (
+            &card_tag_items,
+            card_tags_list,
+            card_tags_block,
+            extra_info_chunks[1],
+        ),
+        (
+            &card_comment_items,
+            card_comments_list,
+            card_comments_block,
+            extra_info_chunks[2],
+        ),
+        (
+            &card_checklist_items,
+            card_checklist_list,
+            card_checklist_block,
+            side_chunks[0],
+        ),
+    ] {
+        let inner_area = block.inner(area);
+        rect.render_widget(block, area);
+        render_card_view_items(
+            rect,
+            &mut app.state.text_input,
+            &app.theme,
+            items,
+            selected,
+            editing,
+            inner_area,
+        );
+    }
+    rect.render_widget(card_links_paragraph, side_chunks[1]);
+    rect.render_stateful_widget(
+        card_history,
+        side_chunks[2],
+        &mut app.state.card_view_history_list_state,
+    );
+
+    if app.state.app_status == AppStatus::UserInput && app.state.focus == Focus::CardDueDate {
+        // +6 and +3 account for the border, the "Due: " text and the extra info position offset
+        let x_pos =
+            info_chunks[0].x + app.state.text_input.cursor_width(&card_due_date_text) as u16 + 6;
+        rect.set_cursor(x_pos, info_chunks[0].y + 3);
     }
 
     if app.card_being_edited.is_some() {
@@ -3618,7 +3917,9 @@ where
             app.state.mouse_focus = Some(Focus::SubmitButton);
             app.state.card_view_comment_list_state.select(None);
             app.state.card_view_tag_list_state.select(None);
-            app.state.current_cursor_position = None;
+            app.state.card_view_checklist_list_state.select(None);
+            app.state.card_view_link_list_state.select(None);
+            app.state.text_input.reset();
         }
         let save_changes_style = if app.state.focus == Focus::SubmitButton {
             app.theme.keyboard_focus_style
@@ -3642,6 +3943,62 @@ where
     }
 }
 
+/// Renders tags, comments or checklist items (prefix, text, style) one per line, the selected item
+/// is highlighted and while editing it is drawn by the text input so the cursor and selection
+/// line up with the text
+fn render_card_view_items<B>(
+    rect: &mut Frame<B>,
+    text_input: &mut TextInput,
+    theme: &Theme,
+    items: &[(String, String, Style)],
+    selected: Option<usize>,
+    editing: bool,
+    area: Rect,
+) where
+    B: Backend,
+{
+    // keep the selected item in view
+    let first_visible = selected.map_or(0, |selected| {
+        (selected + 1).saturating_sub(area.height as usize)
+    });
+    for (row, (index, (prefix, text, style))) in items
+        .iter()
+        .enumerate()
+        .skip(first_visible)
+        .take(area.height as usize)
+        .enumerate()
+    {
+        let line_area = Rect::new(area.x, area.y + row as u16, area.width, 1);
+        if selected != Some(index) {
+            let line = Span::styled(format!("{}{}", prefix, text), *style);
+            rect.render_widget(Paragraph::new(line), line_area);
+            continue;
+        }
+        if !editing {
+            let line = Span::styled(format!("{}{}", prefix, text), theme.keyboard_focus_style);
+            rect.render_widget(Paragraph::new(line), line_area);
+            continue;
+        }
+        let prefix_width = (prefix.width() as u16).min(line_area.width);
+        rect.render_widget(
+            Paragraph::new(Span::styled(prefix.clone(), theme.keyboard_focus_style)),
+            Rect::new(line_area.x, line_area.y, prefix_width, 1),
+        );
+        text_input.render(
+            rect,
+            text,
+            Block::default().style(theme.keyboard_focus_style),
+            Rect::new(
+                line_area.x + prefix_width,
+                line_area.y,
+                line_area.width - prefix_width,
+                1,
+            ),
+            theme.list_select_style,
+            true,
+        );
+    }
+}
 pub fn render_command_palette<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
@@ -3698,83 +4055,838 @@ where
         3
     };
 
-    let vertical_chunks = Layout::default()
+    let vertical_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(search_results_length),
+                Constraint::Length(2),
+            ]
+            .as_ref(),
+        )
+        .split(horizontal_chunks[1]);
+
+    let search_box_text = if app.state.current_user_input.is_empty() {
+        vec![Spans::from("Start typing to search")]
+    } else {
+        vec![Spans::from(app.state.current_user_input.clone())]
+    };
+
+    let current_cursor_position = app
+        .state
+        .text_input
+        .cursor_width(&app.state.current_user_input) as u16;
+    let x_offset = current_cursor_position % (vertical_chunks[1].width - 2);
+    let y_offset = current_cursor_position / (vertical_chunks[1].width - 2);
+    let x_cursor_position = vertical_chunks[1].x + x_offset + 1;
+    let y_cursor_position = vertical_chunks[1].y + y_offset + 1;
+    rect.set_cursor(x_cursor_position, y_cursor_position);
+
+    // make a search bar and display all the commands that match the search below it in a list
+    let search_bar = Paragraph::new(search_box_text)
+        .block(
+            Block::default()
+                .title("Command Palette")
+                .borders(Borders::ALL)
+                .style(app.theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: false });
+    rect.render_widget(Clear, vertical_chunks[1]);
+    render_blank_styled_canvas(rect, app, vertical_chunks[1], false);
+    rect.render_widget(search_bar, vertical_chunks[1]);
+
+    let search_results = List::new(search_results)
+        .block(
+            Block::default()
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    rect.render_widget(Clear, vertical_chunks[2]);
+    render_blank_styled_canvas(rect, app, vertical_chunks[2], false);
+    rect.render_stateful_widget(
+        search_results,
+        vertical_chunks[2],
+        &mut app.state.command_palette_list_state,
+    );
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, vertical_chunks[2]) {
+        app.state.mouse_focus = Some(Focus::CommandPalette);
+        app.state.focus = Focus::CommandPalette;
+        let top_of_list = vertical_chunks[2].y + 1;
+        let mut bottom_of_list = vertical_chunks[2].y + search_results_length;
+        if bottom_of_list > vertical_chunks[2].bottom() {
+            bottom_of_list = vertical_chunks[2].bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .command_palette_list_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_card_search<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let horizontal_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(10),
+                Constraint::Percentage(80),
+                Constraint::Percentage(10),
+            ]
+            .as_ref(),
+        )
+        .split(rect.size());
+
+    let search_results = app
+        .card_search
+        .search_results
+        .iter()
+        .filter_map(|(board_id, card_id)| {
+            let board = app.boards.iter().find(|b| b.id == *board_id)?;
+            let card = board.cards.iter().find(|c| c.id == *card_id)?;
+            let mut spans = vec![
+                Span::styled(card.name.clone(), app.theme.general_style),
+                Span::styled(format!(" ({})", board.name), app.theme.inactive_text_style),
+            ];
+            if !card.tags.is_empty() {
+                spans.push(Span::styled(
+                    format!(" [{}]", card.tags.join(", ")),
+                    app.theme.help_text_style,
+                ));
+            }
+            Some(ListItem::new(vec![Spans::from(spans)]))
+        })
+        .collect::<Vec<ListItem>>();
+
+    let search_results_length = if (search_results.len() + 2) > 3 {
+        if (search_results.len() + 2) > (rect.size().height - 7) as usize {
+            rect.size().height - 7
+        } else {
+            (search_results.len() + 2) as u16
+        }
+    } else {
+        3
+    };
+
+    let vertical_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(search_results_length),
+                Constraint::Length(2),
+            ]
+            .as_ref(),
+        )
+        .split(horizontal_chunks[1]);
+
+    let linking_card = app.state.popup_mode == Some(PopupMode::LinkCard);
+    let search_box_text = if app.state.current_user_input.is_empty() {
+        if linking_card {
+            vec![Spans::from("Start typing to search for the card to link")]
+        } else {
+            vec![Spans::from(
+                "Start typing to search card names, descriptions, tags and comments",
+            )]
+        }
+    } else {
+        vec![Spans::from(app.state.current_user_input.clone())]
+    };
+
+    let current_cursor_position = app
+        .state
+        .text_input
+        .cursor_width(&app.state.current_user_input) as u16;
+    let x_offset = current_cursor_position % (vertical_chunks[1].width - 2);
+    let y_offset = current_cursor_position / (vertical_chunks[1].width - 2);
+    let x_cursor_position = vertical_chunks[1].x + x_offset + 1;
+    let y_cursor_position = vertical_chunks[1].y + y_offset + 1;
+    rect.set_cursor(x_cursor_position, y_cursor_position);
+
+    let search_bar_title = if linking_card {
+        format!(
+            "Link Card: {} (<Ctrl+t> to switch)",
+            app.state.card_link_kind
+        )
+    } else {
+        "Search Cards".to_string()
+    };
+    let search_bar = Paragraph::new(search_box_text)
+        .block(
+            Block::default()
+                .title(search_bar_title)
+                .borders(Borders::ALL)
+                .style(app.theme.general_style)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: false });
+    rect.render_widget(Clear, vertical_chunks[1]);
+    render_blank_styled_canvas(rect, app, vertical_chunks[1], false);
+    rect.render_widget(search_bar, vertical_chunks[1]);
+
+    let results_title = if app.state.current_user_input.is_empty() {
+        String::new()
+    } else {
+        format!("{} cards found", search_results.len())
+    };
+    let search_results = List::new(search_results)
+        .block(
+            Block::default()
+                .title(results_title)
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    rect.render_widget(Clear, vertical_chunks[2]);
+    render_blank_styled_canvas(rect, app, vertical_chunks[2], false);
+    rect.render_stateful_widget(
+        search_results,
+        vertical_chunks[2],
+        &mut app.state.card_search_list_state,
+    );
+
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, vertical_chunks[2]) {
+        app.state.mouse_focus = Some(Focus::CardSearch);
+        app.state.focus = Focus::CardSearch;
+        let top_of_list = vertical_chunks[2].y + 1;
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        let hovered_index = mouse_y.saturating_sub(top_of_list) as usize;
+        if mouse_y >= top_of_list && hovered_index < app.card_search.search_results.len() {
+            app.state.card_search_list_state.select(Some(hovered_index));
+        }
+    }
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_filter_cards<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(70, 60, rect.size());
+    let saved_filters = app
+        .config
+        .saved_filters
+        .iter()
+        .map(|(name, expression)| {
+            ListItem::new(vec![Spans::from(vec![
+                Span::styled(name.clone(), app.theme.general_style),
+                Span::styled(format!(" ({})", expression), app.theme.inactive_text_style),
+            ])])
+        })
+        .collect::<Vec<ListItem>>();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(4),
+            ]
+            .as_ref(),
+        )
+        .margin(1)
+        .split(popup_area);
+
+    let filter_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .title("Filter Expression")
+                .borders(Borders::ALL)
+                .border_style(app.theme.keyboard_focus_style)
+                .border_type(BorderType::Rounded),
+        );
+    let saved_filters_border_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[1]) {
+            app.state.mouse_focus = Some(Focus::SavedFilters);
+            app.state.focus = Focus::SavedFilters;
+            let top_of_list = chunks[1].y + 1;
+            let mouse_y = app.state.current_mouse_coordinates.1;
+            let hovered_index = mouse_y.saturating_sub(top_of_list) as usize;
+            if mouse_y >= top_of_list
+                && hovered_index < saved_filters.len()
+                && app.state.saved_filters_list_state.selected() != Some(hovered_index)
+            {
+                app.select_saved_filter(hovered_index);
+            }
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let saved_filters_title = if saved_filters.is_empty() {
+        "No Saved Filters"
+    } else {
+        "Saved Filters"
+    };
+    let saved_filters = List::new(saved_filters)
+        .block(
+            Block::default()
+                .title(saved_filters_title)
+                .borders(Borders::ALL)
+                .border_style(saved_filters_border_style)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+    let help_text = Paragraph::new(vec![
+        Spans::from("Keys: tag, priority, status, due (<7d, >2w, overdue, none) and plain words"),
+        Spans::from("e.g. tag:backend priority:high status:active due:<7d, leave empty to clear"),
+    ])
+    .style(app.theme.help_text_style)
+    .wrap(Wrap { trim: true });
+    let border_block = Block::default()
+        .title("Filter Cards")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(filter_input, chunks[0]);
+    rect.render_stateful_widget(
+        saved_filters,
+        chunks[1],
+        &mut app.state.saved_filters_list_state,
+    );
+    rect.render_widget(help_text, chunks[2]);
+    rect.render_widget(border_block, popup_area);
+
+    let current_cursor_position = app
+        .state
+        .text_input
+        .cursor_width(&app.state.current_user_input) as u16;
+    let x_offset = current_cursor_position % (chunks[0].width - 2);
+    let y_offset = current_cursor_position / (chunks[0].width - 2);
+    rect.set_cursor(chunks[0].x + x_offset + 1, chunks[0].y + y_offset + 1);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_workspace_switcher<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(50, 50, rect.size());
+    let current_workspace = app.config.workspace_name().to_string();
+    let workspaces = app
+        .state
+        .workspaces
+        .iter()
+        .map(|workspace| {
+            if *workspace == current_workspace {
+                ListItem::new(vec![Spans::from(vec![
+                    Span::styled(workspace.clone(), app.theme.general_style),
+                    Span::styled(" (current)", app.theme.inactive_text_style),
+                ])])
+            } else {
+                ListItem::new(workspace.clone()).style(app.theme.general_style)
+            }
+        })
+        .collect::<Vec<ListItem>>();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(2),
+            ]
+            .as_ref(),
+        )
+        .margin(1)
+        .split(popup_area);
+
+    let workspace_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .title("Workspace Name")
+                .borders(Borders::ALL)
+                .border_style(app.theme.keyboard_focus_style)
+                .border_type(BorderType::Rounded),
+        );
+    let workspaces_border_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[1]) {
+            app.state.mouse_focus = Some(Focus::WorkspaceList);
+            app.state.focus = Focus::WorkspaceList;
+            let top_of_list = chunks[1].y + 1;
+            let mouse_y = app.state.current_mouse_coordinates.1;
+            let hovered_index = mouse_y.saturating_sub(top_of_list) as usize;
+            if mouse_y >= top_of_list
+                && hovered_index < workspaces.len()
+                && app.state.workspace_list_state.selected() != Some(hovered_index)
+            {
+                app.select_workspace(hovered_index);
+            }
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let workspaces = List::new(workspaces)
+        .block(
+            Block::default()
+                .title("Workspaces")
+                .borders(Borders::ALL)
+                .border_style(workspaces_border_style)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+    let help_text = Paragraph::new(
+        "Pick a workspace or type a new name to create one, every workspace has its own saves",
+    )
+    .style(app.theme.help_text_style)
+    .wrap(Wrap { trim: true });
+    let border_block = Block::default()
+        .title("Switch Workspace")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(workspace_input, chunks[0]);
+    rect.render_stateful_widget(workspaces, chunks[1], &mut app.state.workspace_list_state);
+    rect.render_widget(help_text, chunks[2]);
+    rect.render_widget(border_block, popup_area);
+
+    let current_cursor_position = app
+        .state
+        .text_input
+        .cursor_width(&app.state.current_user_input) as u16;
+    let x_offset = current_cursor_position % (chunks[0].width - 2);
+    let y_offset = current_cursor_position / (chunks[0].width - 2);
+    rect.set_cursor(chunks[0].x + x_offset + 1, chunks[0].y + y_offset + 1);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_save_filter_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(50, 40, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .margin(2)
+        .split(popup_area);
+    let submit_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let prompt_text = Paragraph::new(format!(
+        "Save '{}' as",
+        app.state
+            .active_filter
+            .as_ref()
+            .map(|filter| filter.to_string())
+            .unwrap_or_default()
+    ))
+    .style(app.theme.general_style)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    let name_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .title("Filter Name")
+                .borders(Borders::ALL)
+                .border_style(app.theme.keyboard_focus_style),
+        );
+    let submit_button = Paragraph::new("Save")
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(submit_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Save Filter")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(prompt_text, chunks[0]);
+    rect.render_widget(name_input, chunks[1]);
+    rect.render_widget(submit_button, chunks[2]);
+    rect.render_widget(border_block, popup_area);
+
+    let current_cursor_position = app
+        .state
+        .text_input
+        .cursor_width(&app.state.current_user_input) as u16;
+    let x_offset = current_cursor_position % (chunks[1].width - 2);
+    let y_offset = current_cursor_position / (chunks[1].width - 2);
+    rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + y_offset + 1);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_set_wip_limit_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(50, 40, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .margin(2)
+        .split(popup_area);
+    let submit_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    let prompt_text = Paragraph::new(format!(
+        "Maximum number of cards on \"{}\", leave empty or 0 to remove the limit",
+        board_name
+    ))
+    .style(app.theme.general_style)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    let name_input = Paragraph::new(app.state.current_user_input.clone())
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .title("WIP Limit")
+                .borders(Borders::ALL)
+                .border_style(app.theme.keyboard_focus_style),
+        );
+    let submit_button = Paragraph::new("Set")
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(submit_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Set WIP Limit")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(prompt_text, chunks[0]);
+    rect.render_widget(name_input, chunks[1]);
+    rect.render_widget(submit_button, chunks[2]);
+    rect.render_widget(border_block, popup_area);
+
+    let current_cursor_position = app
+        .state
+        .text_input
+        .cursor_width(&app.state.current_user_input) as u16;
+    let x_offset = current_cursor_position % (chunks[1].width - 2);
+    let y_offset = current_cursor_position / (chunks[1].width - 2);
+    rect.set_cursor(chunks[1].x + x_offset + 1, chunks[1].y + y_offset + 1);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_set_workflow_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(60, 40, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(4),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .margin(2)
+        .split(popup_area);
+    let submit_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    let prompt_text = Paragraph::new(vec![
+        Spans::from(format!("Workflow of \"{}\"", board_name)),
+        Spans::from(vec![
+            Span::styled("Name", app.theme.help_key_style),
+            Span::styled(" or ", app.theme.general_style),
+            Span::styled("Name:Kind", app.theme.help_key_style),
+            Span::styled(" separated by commas, kinds are ", app.theme.general_style),
+            Span::styled("Active Stale Complete", app.theme.help_key_style),
+        ]),
+    ])
+    .style(app.theme.general_style)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    let input_block = Block::default()
+        .title("Todo:Active, Review:Stale, Done:Complete")
+        .borders(Borders::ALL)
+        .style(app.theme.general_style)
+        .border_style(app.theme.keyboard_focus_style);
+    let submit_button = Paragraph::new("Set")
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(submit_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Set Workflow")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(prompt_text, chunks[0]);
+    let workflow_text = app.state.current_user_input.clone();
+    app.state.text_input.render(
+        rect,
+        &workflow_text,
+        input_block,
+        chunks[1],
+        app.theme.list_select_style,
+        app.state.app_status == AppStatus::UserInput,
+    );
+    rect.render_widget(submit_button, chunks[2]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_import_from_json_prompt<B>(rect: &mut Frame<B>, app: &mut App, replace: bool)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(60, 40, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Min(4),
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ]
+            .as_ref(),
+        )
+        .margin(2)
+        .split(popup_area);
+    let submit_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let action = if replace {
+        "replace all boards with"
+    } else {
+        "merge into the boards"
+    };
+    let prompt_text = Paragraph::new(vec![
+        Spans::from(format!("Path of the JSON export to {}", action)),
+        Spans::from(Span::styled(
+            "The latest export in the save directory is filled in",
+            app.theme.inactive_text_style,
+        )),
+    ])
+    .style(app.theme.general_style)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    let input_block = Block::default()
+        .title("Export File")
+        .borders(Borders::ALL)
+        .style(app.theme.general_style)
+        .border_style(app.theme.keyboard_focus_style);
+    let submit_button = Paragraph::new("Import")
+        .style(app.theme.general_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(submit_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title(if replace {
+            "Import from JSON (Replace)"
+        } else {
+            "Import from JSON (Merge)"
+        })
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(prompt_text, chunks[0]);
+    let export_file = app.state.current_user_input.clone();
+    app.state.text_input.render(
+        rect,
+        &export_file,
+        input_block,
+        chunks[1],
+        app.theme.list_select_style,
+        app.state.app_status == AppStatus::UserInput,
+    );
+    rect.render_widget(submit_button, chunks[2]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_quick_add_card_prompt<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let popup_area = centered_rect(60, 40, rect.size());
+    let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints(
             [
-                Constraint::Length(2),
+                Constraint::Min(4),
+                Constraint::Length(3),
                 Constraint::Length(3),
-                Constraint::Length(search_results_length),
-                Constraint::Length(2),
             ]
             .as_ref(),
         )
-        .split(horizontal_chunks[1]);
-
-    let search_box_text = if app.state.current_user_input.is_empty() {
-        vec![Spans::from("Start typing to search")]
-    } else {
-        vec![Spans::from(app.state.current_user_input.clone())]
-    };
-
-    let current_cursor_position = if app.state.current_cursor_position.is_some() {
-        app.state.current_cursor_position.unwrap() as u16
-    } else {
-        app.state.current_user_input.len() as u16
-    };
-    let x_offset = current_cursor_position % (vertical_chunks[1].width - 2);
-    let y_offset = current_cursor_position / (vertical_chunks[1].width - 2);
-    let x_cursor_position = vertical_chunks[1].x + x_offset + 1;
-    let y_cursor_position = vertical_chunks[1].y + y_offset + 1;
-    rect.set_cursor(x_cursor_position, y_cursor_position);
-
-    // make a search bar and display all the commands that match the search below it in a list
-    let search_bar = Paragraph::new(search_box_text)
-        .block(
-            Block::default()
-                .title("Command Palette")
-                .borders(Borders::ALL)
-                .style(app.theme.general_style)
-                .border_type(BorderType::Rounded),
-        )
-        .wrap(Wrap { trim: false });
-    rect.render_widget(Clear, vertical_chunks[1]);
-    render_blank_styled_canvas(rect, app, vertical_chunks[1], false);
-    rect.render_widget(search_bar, vertical_chunks[1]);
-
-    let search_results = List::new(search_results)
+        .margin(2)
+        .split(popup_area);
+    let submit_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, chunks[2]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.theme.mouse_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|board| board.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    let prompt_text = Paragraph::new(vec![
+        Spans::from(format!("New card in \"{}\"", board_name)),
+        Spans::from(vec![
+            Span::styled("#tag", app.theme.help_key_style),
+            Span::styled(" adds a tag, ", app.theme.general_style),
+            Span::styled("!low !medium !high", app.theme.help_key_style),
+            Span::styled(" set the priority, ", app.theme.general_style),
+            Span::styled("@today @fri @3d @DD/MM/YYYY", app.theme.help_key_style),
+            Span::styled(" set the due date, ", app.theme.general_style),
+            Span::styled("@alice", app.theme.help_key_style),
+            Span::styled(" adds a tag", app.theme.general_style),
+        ]),
+    ])
+    .style(app.theme.general_style)
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+    let input_block = Block::default()
+        .title("Fix login bug #backend !high @fri")
+        .borders(Borders::ALL)
+        .style(app.theme.general_style)
+        .border_style(app.theme.keyboard_focus_style);
+    let submit_button = Paragraph::new("Add")
+        .style(app.theme.general_style)
         .block(
             Block::default()
-                .style(app.theme.general_style)
                 .borders(Borders::ALL)
-                .border_type(BorderType::Rounded),
+                .border_style(submit_button_style),
         )
-        .highlight_style(app.theme.list_select_style)
-        .highlight_symbol(LIST_SELECTED_SYMBOL);
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title("Quick Add Card")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
 
-    rect.render_widget(Clear, vertical_chunks[2]);
-    render_blank_styled_canvas(rect, app, vertical_chunks[2], false);
-    rect.render_stateful_widget(
-        search_results,
-        vertical_chunks[2],
-        &mut app.state.command_palette_list_state,
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, true);
+    rect.render_widget(prompt_text, chunks[0]);
+    let quick_add_text = app.state.current_user_input.clone();
+    app.state.text_input.render(
+        rect,
+        &quick_add_text,
+        input_block,
+        chunks[1],
+        app.theme.list_select_style,
+        app.state.app_status == AppStatus::UserInput,
     );
-
-    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, vertical_chunks[2]) {
-        app.state.mouse_focus = Some(Focus::CommandPalette);
-        app.state.focus = Focus::CommandPalette;
-        let top_of_list = vertical_chunks[2].y + 1;
-        let mut bottom_of_list = vertical_chunks[2].y + search_results_length;
-        if bottom_of_list > vertical_chunks[2].bottom() {
-            bottom_of_list = vertical_chunks[2].bottom();
-        }
-        let mouse_y = app.state.current_mouse_coordinates.1;
-        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
-            app.state
-                .command_palette_list_state
-                .select(Some((mouse_y - top_of_list) as usize));
-        }
-    }
+    rect.render_widget(submit_button, chunks[2]);
+    rect.render_widget(border_block, popup_area);
 
     if app.config.enable_mouse_support {
         render_close_button(rect, app);
@@ -3848,9 +4960,15 @@ where
             }
         }
     }
-    let all_statuses = CardStatus::all()
+    let all_statuses = app
+        .current_board_workflow()
         .iter()
-        .map(|s| ListItem::new(vec![Spans::from(s.to_string())]))
+        .map(|s| {
+            ListItem::new(vec![Spans::from(Span::styled(
+                s.to_string(),
+                card_status_style(app, &s.kind),
+            ))])
+        })
         .collect::<Vec<ListItem>>();
     let percent_height =
         (((all_statuses.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
@@ -3964,6 +5082,177 @@ where
     }
 }
 
+pub fn render_card_recurrence_selector<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let card_name = app
+        .card_being_edited
+        .as_ref()
+        .map(|(_, card)| card.name.clone())
+        .unwrap_or_default();
+    let all_options = CardRecurrence::options()
+        .into_iter()
+        .map(|recurrence| {
+            ListItem::new(vec![Spans::from(CardRecurrence::option_to_string(
+                recurrence,
+            ))])
+        })
+        .collect::<Vec<ListItem>>();
+    let percent_height =
+        (((all_options.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+    let popup_area = centered_rect(50, percent_height, rect.size());
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::ChangeCardRecurrencePopup);
+        app.state.focus = Focus::ChangeCardRecurrencePopup;
+        let top_of_list = popup_area.y + 1;
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        let hovered_index = mouse_y.saturating_sub(top_of_list) as usize;
+        if mouse_y >= top_of_list && hovered_index < all_options.len() {
+            app.state
+                .card_recurrence_selector_state
+                .select(Some(hovered_index));
+        }
+    }
+    let options = List::new(all_options)
+        .block(
+            Block::default()
+                .title(format!("Repeat \"{}\"", card_name))
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        options,
+        popup_area,
+        &mut app.state.card_recurrence_selector_state,
+    );
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_sort_cards<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let board_name = app
+        .state
+        .current_board_id
+        .and_then(|board_id| app.boards.iter().find(|b| b.id == board_id))
+        .map(|board| board.name.clone())
+        .unwrap_or_default();
+    let sort_options = CardSortKey::sort_options()
+        .into_iter()
+        .map(|(key, descending)| {
+            ListItem::new(vec![Spans::from(CardSortKey::sort_option_to_string(
+                key, descending,
+            ))])
+        })
+        .collect::<Vec<ListItem>>();
+    let percent_height =
+        (((sort_options.len() + 3) as f32 / rect.size().height as f32) * 100.0) as u16;
+    let popup_area = centered_rect(50, percent_height, rect.size());
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::SortCardsPopup);
+        app.state.focus = Focus::SortCardsPopup;
+        let top_of_list = popup_area.y + 1;
+        let mut bottom_of_list = popup_area.y + sort_options.len() as u16;
+        if bottom_of_list > popup_area.bottom() {
+            bottom_of_list = popup_area.bottom();
+        }
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y <= bottom_of_list {
+            app.state
+                .sort_cards_list_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    }
+    let sort_options = List::new(sort_options)
+        .block(
+            Block::default()
+                .title(format!("Sort Cards of {}", board_name))
+                .style(app.theme.general_style)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(app.theme.list_select_style)
+        .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(
+        sort_options,
+        popup_area,
+        &mut app.state.sort_cards_list_state,
+    );
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app);
+    }
+}
+
+pub fn render_context_menu<B>(rect: &mut Frame<B>, app: &mut App)
+where
+    B: Backend,
+{
+    let items = app
+        .state
+        .context_menu_items
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<String>>();
+    let width = items.iter().map(|item| item.len()).max().unwrap_or(0) as u16
+        + LIST_SELECTED_SYMBOL.len() as u16
+        + 2;
+    let width = width.min(rect.size().width);
+    let height = (items.len() as u16 + 2).min(rect.size().height);
+    let (x, y) = app.state.context_menu_position;
+    // open towards the top left when there is no room to the bottom right of the cursor
+    let popup_area = Rect::new(
+        x.min(rect.size().width - width),
+        y.min(rect.size().height - height),
+        width,
+        height,
+    );
+    if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, popup_area) {
+        app.state.mouse_focus = Some(Focus::ContextMenu);
+        let top_of_list = popup_area.y + 1;
+        let mouse_y = app.state.current_mouse_coordinates.1;
+        if mouse_y >= top_of_list && mouse_y < top_of_list + items.len() as u16 {
+            app.state
+                .context_menu_state
+                .select(Some((mouse_y - top_of_list) as usize));
+        }
+    } else {
+        app.state.mouse_focus = None;
+    }
+    let items = List::new(
+        items
+            .into_iter()
+            .map(|item| ListItem::new(vec![Spans::from(item)]))
+            .collect::<Vec<ListItem>>(),
+    )
+    .block(
+        Block::default()
+            .style(app.theme.general_style)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    )
+    .highlight_style(app.theme.list_select_style)
+    .highlight_symbol(LIST_SELECTED_SYMBOL);
+
+    rect.render_widget(Clear, popup_area);
+    render_blank_styled_canvas(rect, app, popup_area, false);
+    rect.render_stateful_widget(items, popup_area, &mut app.state.context_menu_state);
+}
+
 pub fn render_debug_panel<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
@@ -4522,6 +5811,120 @@ where
     }
 }
 
+pub fn render_confirm_destructive_action<B>(
+    rect: &mut Frame<B>,
+    app: &mut App,
+    action: DestructiveAction,
+) where
+    B: Backend,
+{
+    let popup_area = centered_rect(40, 30, rect.size());
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(3)].as_ref())
+        .margin(2)
+        .split(popup_area);
+    let button_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(chunks[1]);
+    let yes_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[0]) {
+            app.state.mouse_focus = Some(Focus::SubmitButton);
+            app.state.focus = Focus::SubmitButton;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::SubmitButton {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let no_button_style =
+        if check_if_mouse_is_in_area(app.state.current_mouse_coordinates, button_chunks[1]) {
+            app.state.mouse_focus = Some(Focus::ExtraFocus);
+            app.state.focus = Focus::ExtraFocus;
+            app.theme.mouse_focus_style
+        } else if app.state.focus == Focus::ExtraFocus {
+            app.theme.keyboard_focus_style
+        } else {
+            app.theme.general_style
+        };
+    let target = match action {
+        DestructiveAction::DeleteCard => app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.iter().find(|b| b.id == board_id))
+            .and_then(|board| {
+                app.state
+                    .current_card_id
+                    .and_then(|card_id| board.cards.iter().find(|c| c.id == card_id))
+            })
+            .map(|card| format!("card '{}'", card.name)),
+        DestructiveAction::DeleteBoard => app
+            .state
+            .current_board_id
+            .and_then(|board_id| app.boards.iter().find(|b| b.id == board_id))
+            .map(|board| format!("board '{}'", board.name)),
+        DestructiveAction::DeleteSave => app.state.load_save_state.selected().and_then(|index| {
+            get_available_local_savefiles()
+                .and_then(|save_files| save_files.get(index).cloned())
+                .map(|save_file| format!("save file '{}'", save_file))
+        }),
+        DestructiveAction::ResetConfig => Some("the config to its defaults".to_string()),
+        DestructiveAction::ResetConfigAndKeybindings => {
+            Some("the config and keybindings to their defaults".to_string())
+        }
+        DestructiveAction::ReplaceBoardsWithImport => {
+            Some("all boards with a JSON export".to_string())
+        }
+    };
+    let verb = match action {
+        DestructiveAction::DeleteCard
+        | DestructiveAction::DeleteBoard
+        | DestructiveAction::DeleteSave => "delete",
+        DestructiveAction::ResetConfig | DestructiveAction::ResetConfigAndKeybindings => "reset",
+        DestructiveAction::ReplaceBoardsWithImport => "replace",
+    };
+    let message = format!(
+        "Are you sure you want to {} {}?",
+        verb,
+        target.unwrap_or_else(|| "the selected item".to_string())
+    );
+    let message_paragraph = Paragraph::new(message)
+        .style(app.theme.general_style)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    let yes_button = Paragraph::new("Yes")
+        .style(yes_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(yes_button_style),
+        )
+        .alignment(Alignment::Center);
+    let no_button = Paragraph::new("No")
+        .style(no_button_style)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(no_button_style),
+        )
+        .alignment(Alignment::Center);
+    let border_block = Block::default()
+        .title(format!("{}?", action))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(app.theme.general_style);
+    rect.render_widget(Clear, popup_area);
+    rect.render_widget(message_paragraph, chunks[0]);
+    rect.render_widget(yes_button, button_chunks[0]);
+    rect.render_widget(no_button, button_chunks[1]);
+    rect.render_widget(border_block, popup_area);
+
+    if app.config.enable_mouse_support {
+        render_close_button(rect, app)
+    }
+}
+
 pub fn render_custom_rgb_color_prompt<B>(rect: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
@@ -4597,11 +6000,10 @@ where
     rect.render_widget(border_block, popup_area);
 
     if app.state.app_status == AppStatus::UserInput {
-        let current_cursor_position = if app.state.current_cursor_position.is_some() {
-            app.state.current_cursor_position.unwrap() as u16
-        } else {
-            app.state.current_user_input.len() as u16
-        };
+        let current_cursor_position =
+            app.state
+                .text_input
+                .cursor_width(&app.state.current_user_input) as u16;
         let x_offset = current_cursor_position % (chunks[1].width - 2);
         let y_offset = current_cursor_position / (chunks[1].width - 2);
         let x_cursor_position = chunks[1].x + x_offset + 1;
@@ -4641,3 +6043,27 @@ pub fn render_blank_styled_canvas<B>(
     };
     rect.render_widget(styled_text, render_area);
 }
+
+/// Theme style for a workflow status, picked by the kind of the status
+fn card_status_style(app: &App, kind: &CardStatus) -> Style {
+    match kind {
+        CardStatus::Active => app.theme.card_status_active_style,
+        CardStatus::Complete => app.theme.card_status_completed_style,
+        CardStatus::Stale => app.theme.card_status_stale_style,
+    }
+}
+
+/// Formats a duration as days, hours and minutes, e.g. "2d 3h 15m"
+fn format_duration(duration: Duration) -> String {
+    let duration = duration.max(Duration::zero());
+    let days = duration.num_days();
+    let hours = duration.num_hours() % 24;
+    let minutes = duration.num_minutes() % 60;
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
@@ -68,6 +68,7 @@ where
             ui_helper::render_load_save(rect, app);
         }
         UiMode::CreateTheme => ui_helper::render_create_theme(rect, app),
+        UiMode::Archive => ui_helper::render_archive(rect, app),
     }
 
     // Popups are rendered above ui_mode
@@ -112,6 +113,45 @@ where
             PopupMode::CardPrioritySelector => {
                 ui_helper::render_card_priority_selector(rect, app);
             }
+            PopupMode::CardRecurrenceSelector => {
+                ui_helper::render_card_recurrence_selector(rect, app);
+            }
+            PopupMode::ConfirmDestructiveAction(action) => {
+                ui_helper::render_confirm_destructive_action(rect, app, action);
+            }
+            PopupMode::CardSearch => {
+                ui_helper::render_card_search(rect, app);
+            }
+            PopupMode::FilterCards => {
+                ui_helper::render_filter_cards(rect, app);
+            }
+            PopupMode::SaveFilterPrompt => {
+                ui_helper::render_save_filter_prompt(rect, app);
+            }
+            PopupMode::SortCards => {
+                ui_helper::render_sort_cards(rect, app);
+            }
+            PopupMode::LinkCard => {
+                ui_helper::render_card_search(rect, app);
+            }
+            PopupMode::SetWipLimitPrompt => {
+                ui_helper::render_set_wip_limit_prompt(rect, app);
+            }
+            PopupMode::SetWorkflowPrompt => {
+                ui_helper::render_set_workflow_prompt(rect, app);
+            }
+            PopupMode::ImportFromJSONPrompt(replace) => {
+                ui_helper::render_import_from_json_prompt(rect, app, replace);
+            }
+            PopupMode::QuickAddCard => {
+                ui_helper::render_quick_add_card_prompt(rect, app);
+            }
+            PopupMode::SwitchWorkspace => {
+                ui_helper::render_workspace_switcher(rect, app);
+            }
+            PopupMode::ContextMenu => {
+                ui_helper::render_context_menu(rect, app);
+            }
         }
     }
 
@@ -1,7 +1,9 @@
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use ngrammatic::{Corpus, CorpusBuilder, Pad};
 use std::{
+    collections::HashMap,
     fmt::{self, Display},
+    path::Path,
     sync::Arc,
     time::Duration,
 };
@@ -9,11 +11,25 @@ use tokio::{sync::MutexGuard, time::Instant};
 
 use crate::{
     app::{
+        app_helper::{
+            clear_filter, handle_redo, handle_undo, open_archive, open_card_search,
+            open_filter_cards, open_import_prompt, open_link_card, open_quick_add_card,
+            open_save_filter_prompt, open_sort_cards, open_wip_limit_prompt, open_workflow_prompt,
+            open_workspace_switcher, request_destructive_action,
+        },
+        kanban::Board,
         state::{AppStatus, Focus, UiMode},
-        App, AppReturn, PopupMode,
+        App, AppReturn, DestructiveAction, PopupMode,
+    },
+    constants::{CARD_SEARCH_THRESHOLD, FIELD_NOT_SET, TOAST_FADE_IN_TIME, TOAST_FADE_OUT_TIME},
+    io::{
+        data_handler::{
+            export_kanban_to_csv, export_kanban_to_json, export_kanban_to_markdown,
+            import_kanban_from_json, merge_imported_boards,
+        },
+        handler::refresh_visible_boards_and_cards,
+        IoEvent,
     },
-    constants::{TOAST_FADE_IN_TIME, TOAST_FADE_OUT_TIME},
-    io::{data_handler::export_kanban_to_json, IoEvent},
     lerp_between,
 };
 
@@ -164,6 +180,7 @@ impl WidgetManager {
     pub async fn update(&mut self) {
         ToastWidget::update(self.app.lock().await);
         CommandPaletteWidget::update(self.app.lock().await);
+        CardSearchWidget::update(self.app.lock().await);
     }
 }
 
@@ -224,6 +241,45 @@ impl CommandPaletteWidget {
                         }
                         app.state.popup_mode = None;
                     }
+                    CommandPaletteActions::ExportToMarkdown => {
+                        match export_kanban_to_markdown(&app.boards) {
+                            Ok(file_path) => {
+                                let msg = format!("Exported Markdown to {}", file_path);
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!("Failed to export Markdown: {}", e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ExportToCSV => {
+                        match export_kanban_to_csv(&app.boards) {
+                            Ok(file_path) => {
+                                let msg = format!("Exported CSV to {}", file_path);
+                                app.send_info_toast(&msg, None);
+                                info!("{}", msg);
+                            }
+                            Err(e) => {
+                                let msg = format!("Failed to export CSV: {}", e);
+                                app.send_error_toast(&msg, None);
+                                error!("{}", msg);
+                            }
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::ImportFromJSONMerge => {
+                        open_import_prompt(app, false);
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::ImportFromJSONReplace => {
+                        app.state.popup_mode = None;
+                        request_destructive_action(app, DestructiveAction::ReplaceBoardsWithImport)
+                            .await;
+                    }
                     CommandPaletteActions::Quit => {
                         info!("Quitting");
                         return AppReturn::Exit;
@@ -281,6 +337,17 @@ impl CommandPaletteWidget {
                             app.send_error_toast("Cannot create a new card in this view", None);
                         }
                     }
+                    CommandPaletteActions::QuickAddCard => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_quick_add_card(app);
+                            if app.state.popup_mode == Some(PopupMode::QuickAddCard) {
+                                return AppReturn::Continue;
+                            }
+                        } else {
+                            app.send_error_toast("Cannot create a new card in this view", None);
+                        }
+                        app.state.popup_mode = None;
+                    }
                     CommandPaletteActions::ResetUI => {
                         app.state.popup_mode = None;
                         app.state.ui_mode = app.config.default_view;
@@ -333,6 +400,89 @@ impl CommandPaletteWidget {
                         app.state.ui_mode = UiMode::CreateTheme;
                         app.state.popup_mode = None;
                     }
+                    CommandPaletteActions::SearchCards => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_card_search(app);
+                            return AppReturn::Continue;
+                        }
+                        app.state.popup_mode = None;
+                        app.send_error_toast("Cannot search cards in this view", None);
+                    }
+                    CommandPaletteActions::FilterCards => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_filter_cards(app);
+                            return AppReturn::Continue;
+                        }
+                        app.state.popup_mode = None;
+                        app.send_error_toast("Cannot filter cards in this view", None);
+                    }
+                    CommandPaletteActions::SaveCurrentFilter => {
+                        open_save_filter_prompt(app);
+                        if app.state.popup_mode.is_some() {
+                            return AppReturn::Continue;
+                        }
+                    }
+                    CommandPaletteActions::ClearFilter => {
+                        app.state.popup_mode = None;
+                        clear_filter(app);
+                    }
+                    CommandPaletteActions::SortCards => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_sort_cards(app);
+                        } else {
+                            app.state.popup_mode = None;
+                            app.send_error_toast("Cannot sort cards in this view", None);
+                        }
+                    }
+                    CommandPaletteActions::LinkCard => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_link_card(app);
+                            if app.state.popup_mode == Some(PopupMode::LinkCard) {
+                                return AppReturn::Continue;
+                            }
+                        } else {
+                            app.send_error_toast("Cannot link cards in this view", None);
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::SetWipLimit => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_wip_limit_prompt(app);
+                            if app.state.popup_mode == Some(PopupMode::SetWipLimitPrompt) {
+                                return AppReturn::Continue;
+                            }
+                        } else {
+                            app.send_error_toast("Cannot set a WIP limit in this view", None);
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::SetWorkflow => {
+                        if UiMode::view_modes().contains(&app.state.ui_mode) {
+                            open_workflow_prompt(app);
+                            if app.state.popup_mode == Some(PopupMode::SetWorkflowPrompt) {
+                                return AppReturn::Continue;
+                            }
+                        } else {
+                            app.send_error_toast("Cannot set a workflow in this view", None);
+                        }
+                        app.state.popup_mode = None;
+                    }
+                    CommandPaletteActions::SwitchWorkspace => {
+                        open_workspace_switcher(app);
+                        return AppReturn::Continue;
+                    }
+                    CommandPaletteActions::OpenArchive => {
+                        app.state.popup_mode = None;
+                        open_archive(app);
+                    }
+                    CommandPaletteActions::Undo => {
+                        app.state.popup_mode = None;
+                        handle_undo(app);
+                    }
+                    CommandPaletteActions::Redo => {
+                        app.state.popup_mode = None;
+                        handle_redo(app);
+                    }
                 }
                 app.state.current_user_input = "".to_string();
             } else {
@@ -343,7 +493,7 @@ impl CommandPaletteWidget {
         }
         app.state.app_status = AppStatus::Initialized;
         app.state.current_user_input = String::new();
-        app.state.current_cursor_position = None;
+        app.state.text_input.reset();
         AppReturn::Continue
     }
 
@@ -391,14 +541,148 @@ impl CommandPaletteWidget {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct CardSearchWidget {
+    /// (board_id, card_id) of the matching cards, best matches first
+    pub search_results: Vec<(u128, u128)>,
+    pub last_search_string: Option<String>,
+}
+
+impl CardSearchWidget {
+    /// Matches card names, descriptions, tags and comments across all boards, cards containing the
+    /// search string come first followed by fuzzy matches
+    pub fn search(boards: &[Board], search_string: &str) -> Vec<(u128, u128)> {
+        let search_string = search_string.trim().to_lowercase();
+        let mut search_results = vec![];
+        if search_string.is_empty() {
+            return search_results;
+        }
+        let mut corpus = CorpusBuilder::new().arity(2).pad_full(Pad::Auto).finish();
+        let mut cards_by_text: HashMap<String, Vec<(u128, u128)>> = HashMap::new();
+        // archived boards and cards are left out, they are browsed in the archive view
+        for board in boards.iter().filter(|board| !board.archived) {
+            for card in board.cards.iter().filter(|card| !card.archived) {
+                let ids = (board.id, card.id);
+                let card_texts = [&card.name, &card.description]
+                    .into_iter()
+                    .chain(card.tags.iter())
+                    .chain(card.comments.iter());
+                for text in card_texts.filter(|text| *text != FIELD_NOT_SET) {
+                    let text = text.to_lowercase();
+                    if text.is_empty() {
+                        continue;
+                    }
+                    if text.contains(&search_string) && !search_results.contains(&ids) {
+                        search_results.push(ids);
+                    }
+                    corpus.add_text(&text);
+                    cards_by_text.entry(text).or_default().push(ids);
+                }
+            }
+        }
+        for item in corpus.search(&search_string, CARD_SEARCH_THRESHOLD) {
+            if let Some(matching_cards) = cards_by_text.get(&item.text) {
+                for ids in matching_cards {
+                    if !search_results.contains(ids) {
+                        search_results.push(*ids);
+                    }
+                }
+            }
+        }
+        search_results
+    }
+
+    fn update(mut app: MutexGuard<App>) {
+        if !matches!(
+            app.state.popup_mode,
+            Some(PopupMode::CardSearch) | Some(PopupMode::LinkCard)
+        ) {
+            return;
+        }
+        let current_search_string = app.state.current_user_input.to_lowercase();
+        if app.card_search.last_search_string.as_ref() == Some(&current_search_string) {
+            return;
+        }
+        let mut search_results = Self::search(&app.boards, &current_search_string);
+        // a card can not be linked to itself
+        if app.state.popup_mode == Some(PopupMode::LinkCard) {
+            let current_card_id = app.state.current_card_id;
+            search_results.retain(|(_, card_id)| Some(*card_id) != current_card_id);
+        }
+        if search_results.is_empty() {
+            app.state.card_search_list_state.select(None);
+        } else {
+            app.state.card_search_list_state.select(Some(0));
+        }
+        app.card_search.search_results = search_results;
+        app.card_search.last_search_string = Some(current_search_string);
+    }
+}
+
+// imports a file written by Export to JSON, returns whether the boards were imported
+pub fn import_kanban_export(app: &mut App, export_file: &Path, replace: bool) -> bool {
+    match import_kanban_from_json(export_file) {
+        Ok(export) => {
+            let msg = if replace {
+                let no_of_boards = export.boards.len();
+                app.boards = export.boards;
+                app.state.current_board_id = None;
+                app.state.current_card_id = None;
+                format!(
+                    "Replaced boards with {} boards from {} (exported from v{} on {})",
+                    no_of_boards,
+                    export_file.display(),
+                    export.kanban_version,
+                    export.export_date
+                )
+            } else {
+                let (boards_added, cards_added) =
+                    merge_imported_boards(&mut app.boards, export.boards);
+                format!(
+                    "Merged {} boards and {} cards from {} (exported from v{} on {})",
+                    boards_added,
+                    cards_added,
+                    export_file.display(),
+                    export.kanban_version,
+                    export.export_date
+                )
+            };
+            refresh_visible_boards_and_cards(app);
+            info!("{}", msg);
+            app.send_info_toast(&msg, None);
+            if export.kanban_version != env!("CARGO_PKG_VERSION") {
+                let msg = format!(
+                    "Export was made with v{}, current version is v{}",
+                    export.kanban_version,
+                    env!("CARGO_PKG_VERSION")
+                );
+                warn!("{}", msg);
+                app.send_warning_toast(&msg, None);
+            }
+            true
+        }
+        Err(e) => {
+            let msg = format!("Failed to import JSON: {}", e);
+            error!("{}", msg);
+            app.send_error_toast(&msg, None);
+            false
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum CommandPaletteActions {
     ExportToJSON,
+    ExportToMarkdown,
+    ExportToCSV,
+    ImportFromJSONMerge,
+    ImportFromJSONReplace,
     OpenConfigMenu,
     SaveKanbanState,
     LoadASave,
     NewBoard,
     NewCard,
+    QuickAddCard,
     ResetUI,
     OpenMainMenu,
     OpenHelpMenu,
@@ -407,6 +691,18 @@ pub enum CommandPaletteActions {
     DebugMenu,
     ChangeTheme,
     CreateATheme,
+    SearchCards,
+    FilterCards,
+    SaveCurrentFilter,
+    ClearFilter,
+    SortCards,
+    LinkCard,
+    SetWipLimit,
+    SetWorkflow,
+    SwitchWorkspace,
+    OpenArchive,
+    Undo,
+    Redo,
     Quit,
 }
 
@@ -414,11 +710,16 @@ impl Display for CommandPaletteActions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::ExportToJSON => write!(f, "Export to JSON"),
+            Self::ExportToMarkdown => write!(f, "Export to Markdown"),
+            Self::ExportToCSV => write!(f, "Export to CSV"),
+            Self::ImportFromJSONMerge => write!(f, "Import from JSON (Merge)"),
+            Self::ImportFromJSONReplace => write!(f, "Import from JSON (Replace)"),
             Self::OpenConfigMenu => write!(f, "Open Config Menu"),
             Self::SaveKanbanState => write!(f, "Save Kanban State"),
             Self::LoadASave => write!(f, "Load a Save"),
             Self::NewBoard => write!(f, "New Board"),
             Self::NewCard => write!(f, "New Card"),
+            Self::QuickAddCard => write!(f, "Quick Add Card"),
             Self::ResetUI => write!(f, "Reset UI"),
             Self::OpenMainMenu => write!(f, "Open Main Menu"),
             Self::OpenHelpMenu => write!(f, "Open Help Menu"),
@@ -427,6 +728,18 @@ impl Display for CommandPaletteActions {
             Self::DebugMenu => write!(f, "Toggle Debug Panel"),
             Self::ChangeTheme => write!(f, "Change Theme"),
             Self::CreateATheme => write!(f, "Create a Theme"),
+            Self::SearchCards => write!(f, "Search Cards"),
+            Self::FilterCards => write!(f, "Filter Cards"),
+            Self::SaveCurrentFilter => write!(f, "Save Current Filter"),
+            Self::ClearFilter => write!(f, "Clear Filter"),
+            Self::SortCards => write!(f, "Sort Cards"),
+            Self::LinkCard => write!(f, "Link Card"),
+            Self::SetWipLimit => write!(f, "Set WIP Limit"),
+            Self::SetWorkflow => write!(f, "Set Workflow"),
+            Self::SwitchWorkspace => write!(f, "Switch Workspace"),
+            Self::OpenArchive => write!(f, "Open Archive"),
+            Self::Undo => write!(f, "Undo"),
+            Self::Redo => write!(f, "Redo"),
             Self::Quit => write!(f, "Quit"),
         }
     }
@@ -436,11 +749,16 @@ impl CommandPaletteActions {
     pub fn all() -> Vec<Self> {
         let all = vec![
             Self::ExportToJSON,
+            Self::ExportToMarkdown,
+            Self::ExportToCSV,
+            Self::ImportFromJSONMerge,
+            Self::ImportFromJSONReplace,
             Self::OpenConfigMenu,
             Self::SaveKanbanState,
             Self::LoadASave,
             Self::NewBoard,
             Self::NewCard,
+            Self::QuickAddCard,
             Self::ResetUI,
             Self::OpenMainMenu,
             Self::OpenHelpMenu,
@@ -448,6 +766,18 @@ impl CommandPaletteActions {
             Self::ChangeCurrentCardStatus,
             Self::ChangeTheme,
             Self::CreateATheme,
+            Self::SearchCards,
+            Self::FilterCards,
+            Self::SaveCurrentFilter,
+            Self::ClearFilter,
+            Self::SortCards,
+            Self::LinkCard,
+            Self::SetWipLimit,
+            Self::SetWorkflow,
+            Self::SwitchWorkspace,
+            Self::OpenArchive,
+            Self::Undo,
+            Self::Redo,
             Self::Quit,
         ];
 
@@ -464,11 +794,16 @@ impl CommandPaletteActions {
         if lowercase_match {
             match s.to_lowercase().as_str() {
                 "export to json" => Some(Self::ExportToJSON),
+                "export to markdown" => Some(Self::ExportToMarkdown),
+                "export to csv" => Some(Self::ExportToCSV),
+                "import from json (merge)" => Some(Self::ImportFromJSONMerge),
+                "import from json (replace)" => Some(Self::ImportFromJSONReplace),
                 "open config menu" => Some(Self::OpenConfigMenu),
                 "save kanban state" => Some(Self::SaveKanbanState),
                 "load a save" => Some(Self::LoadASave),
                 "new board" => Some(Self::NewBoard),
                 "new card" => Some(Self::NewCard),
+                "quick add card" => Some(Self::QuickAddCard),
                 "reset ui" => Some(Self::ResetUI),
                 "open main menu" => Some(Self::OpenMainMenu),
                 "open help menu" => Some(Self::OpenHelpMenu),
@@ -477,17 +812,34 @@ impl CommandPaletteActions {
                 "toggle debug panel" => Some(Self::DebugMenu),
                 "change theme" => Some(Self::ChangeTheme),
                 "create a theme" => Some(Self::CreateATheme),
+                "search cards" => Some(Self::SearchCards),
+                "filter cards" => Some(Self::FilterCards),
+                "save current filter" => Some(Self::SaveCurrentFilter),
+                "clear filter" => Some(Self::ClearFilter),
+                "sort cards" => Some(Self::SortCards),
+                "link card" => Some(Self::LinkCard),
+                "set wip limit" => Some(Self::SetWipLimit),
+                "set workflow" => Some(Self::SetWorkflow),
+                "switch workspace" => Some(Self::SwitchWorkspace),
+                "open archive" => Some(Self::OpenArchive),
+                "undo" => Some(Self::Undo),
+                "redo" => Some(Self::Redo),
                 "quit" => Some(Self::Quit),
                 _ => None,
             }
         } else {
             match s {
                 "Export to JSON" => Some(Self::ExportToJSON),
+                "Export to Markdown" => Some(Self::ExportToMarkdown),
+                "Export to CSV" => Some(Self::ExportToCSV),
+                "Import from JSON (Merge)" => Some(Self::ImportFromJSONMerge),
+                "Import from JSON (Replace)" => Some(Self::ImportFromJSONReplace),
                 "Open Config Menu" => Some(Self::OpenConfigMenu),
                 "Save Kanban State" => Some(Self::SaveKanbanState),
                 "Load a Save" => Some(Self::LoadASave),
                 "New Board" => Some(Self::NewBoard),
                 "New Card" => Some(Self::NewCard),
+                "Quick Add Card" => Some(Self::QuickAddCard),
                 "Reset UI" => Some(Self::ResetUI),
                 "Open Main Menu" => Some(Self::OpenMainMenu),
                 "Open Help Menu" => Some(Self::OpenHelpMenu),
@@ -496,9 +848,77 @@ impl CommandPaletteActions {
                 "Toggle Debug Panel" => Some(Self::DebugMenu),
                 "Change Theme" => Some(Self::ChangeTheme),
                 "Create a Theme" => Some(Self::CreateATheme),
+                "Search Cards" => Some(Self::SearchCards),
+                "Filter Cards" => Some(Self::FilterCards),
+                "Save Current Filter" => Some(Self::SaveCurrentFilter),
+                "Clear Filter" => Some(Self::ClearFilter),
+                "Sort Cards" => Some(Self::SortCards),
+                "Link Card" => Some(Self::LinkCard),
+                "Set WIP Limit" => Some(Self::SetWipLimit),
+                "Set Workflow" => Some(Self::SetWorkflow),
+                "Switch Workspace" => Some(Self::SwitchWorkspace),
+                "Open Archive" => Some(Self::OpenArchive),
+                "Undo" => Some(Self::Undo),
+                "Redo" => Some(Self::Redo),
                 "Quit" => Some(Self::Quit),
                 _ => None,
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::kanban::{Card, CardPriority};
+
+    #[test]
+    fn should_search_cards_across_boards() {
+        let mut backend = Board::new("Backend".to_string(), String::new());
+        let login = Card::new(
+            "Fix login".to_string(),
+            "Users get logged out".to_string(),
+            None,
+            CardPriority::High,
+            vec!["auth".to_string()],
+            vec![],
+        );
+        let cache = Card::new(
+            "Add cache".to_string(),
+            String::new(),
+            None,
+            CardPriority::Low,
+            vec![],
+            vec!["needs a redis instance".to_string()],
+        );
+        let (login_id, cache_id) = (login.id, cache.id);
+        backend.cards = vec![login, cache];
+        let mut frontend = Board::new("Frontend".to_string(), String::new());
+        let theme = Card::new(
+            "Dark theme".to_string(),
+            String::new(),
+            None,
+            CardPriority::Medium,
+            vec!["ui".to_string(), "auth".to_string()],
+            vec![],
+        );
+        let theme_id = theme.id;
+        frontend.cards = vec![theme];
+        let boards = vec![backend, frontend];
+
+        assert_eq!(
+            CardSearchWidget::search(&boards, "AUTH"),
+            vec![(boards[0].id, login_id), (boards[1].id, theme_id)]
+        );
+        assert_eq!(
+            CardSearchWidget::search(&boards, "redis"),
+            vec![(boards[0].id, cache_id)]
+        );
+        assert_eq!(
+            CardSearchWidget::search(&boards, "fix logn")[0],
+            (boards[0].id, login_id)
+        );
+        assert!(CardSearchWidget::search(&boards, "not set").is_empty());
+        assert!(CardSearchWidget::search(&boards, "  ").is_empty());
+    }
+}